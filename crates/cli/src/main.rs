@@ -1,8 +1,33 @@
-use libtomatillo::{run, countdown::AsyncCountdown};
+use std::{process, time::Duration};
+
+use libtomatillo::{countdown::{AsyncCountdown, UserFacing}, run, TomatilloError};
 
 #[tokio::main(flavor = "multi_thread", worker_threads = 2)]
 async fn main() {
-    let timer = AsyncCountdown::try_new(25000).expect("failed to create timer");
+    let timer = AsyncCountdown::try_new(Duration::from_secs(1)).unwrap_or_else(|err| report(&err));
+    let guard = run(timer, Duration::from_secs(25)).await.unwrap_or_else(|err| report(&err));
+
+    match guard.wait().await {
+        Err(TomatilloError::Panicked(message)) => report_bug(&message),
+        Err(err) => report(&err),
+        Ok(outcome) => {
+            if let Some(warning) = outcome.lag_warning {
+                eprintln!("warning: {warning}");
+            }
+            if outcome.output_stalled {
+                eprintln!("warning: the output was blocked for over a few seconds, and the updates meanwhile were dropped");
+            }
+        },
+    }
+}
+
+fn report(error: &impl UserFacing) -> ! {
+    eprintln!("error: {}", error.user_message());
+    process::exit(1);
+}
 
-    run(timer, 1000).await;
+fn report_bug(message: &str) -> ! {
+    eprintln!("error: {message}");
+    eprintln!("this is a bug in tomatillo, please report it at {}/issues", env!("CARGO_PKG_REPOSITORY"));
+    process::exit(101);
 }
@@ -0,0 +1,26 @@
+use std::{env, path::Path, process::Command};
+
+fn main() {
+    let target = env::var("TARGET").expect("cargo should set TARGET for build scripts");
+    println!("cargo:rustc-env=TOMATILLO_TARGET={target}");
+    println!("cargo:rerun-if-changed=build.rs");
+
+    // Only a git checkout has a hash to report: packaged sources build without one.
+    let git_dir = Path::new("../../.git");
+    if !git_dir.exists() {
+        return;
+    }
+    println!("cargo:rerun-if-changed=../../.git/HEAD");
+    println!("cargo:rerun-if-changed=../../.git/refs/heads");
+
+    let hash = Command::new("git")
+        .args(["rev-parse", "--short", "HEAD"])
+        .output()
+        .ok()
+        .filter(|output| output.status.success())
+        .and_then(|output| String::from_utf8(output.stdout).ok());
+
+    if let Some(hash) = hash {
+        println!("cargo:rustc-env=TOMATILLO_GIT_HASH={}", hash.trim());
+    }
+}
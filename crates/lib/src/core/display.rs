@@ -0,0 +1,382 @@
+use std::{fmt::{self, Display}, time::Duration};
+
+/// The remaining time of a countdown as it is shown to a person, in whole seconds.
+///
+/// Every consumer converts the time left through [`DisplaySeconds::from`], or [`DisplaySeconds::from_millis`], so they
+/// all agree on the second shown: partial seconds round up, so zero is only shown once the countdown has completed.
+///
+/// It displays as `MM:SS`, or `H:MM:SS` from one hour up.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+///
+/// use libtomatillo::countdown::DisplaySeconds;
+///
+/// assert_eq!(DisplaySeconds::from(Duration::from_millis(1)).to_string(), "00:01");
+/// assert_eq!(DisplaySeconds::from_millis(90_000).to_string(), "01:30");
+/// assert_eq!(DisplaySeconds::from_millis(3_600_000).to_string(), "1:00:00");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct DisplaySeconds(u64);
+
+impl DisplaySeconds {
+    /// Converts the milliseconds left on a countdown to the seconds to show, rounding any partial second up.
+    #[must_use]
+    pub const fn from_millis(millis_left: u64) -> Self {
+        Self(millis_left.div_ceil(1000))
+    }
+
+    /// Returns the number of whole seconds shown.
+    #[must_use]
+    pub const fn as_secs(self) -> u64 {
+        self.0
+    }
+}
+
+impl From<Duration> for DisplaySeconds {
+    /// Converts the time left on a countdown to the seconds to show, rounding any partial second up.
+    fn from(left: Duration) -> Self {
+        Self(u64::try_from(left.as_nanos().div_ceil(1_000_000_000)).unwrap_or(u64::MAX))
+    }
+}
+
+impl Display for DisplaySeconds {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (hours, minutes, seconds) = (self.0 / 3600, self.0 / 60 % 60, self.0 % 60);
+
+        if hours > 0 {
+            write!(f, "{hours}:{minutes:02}:{seconds:02}")
+        } else {
+            write!(f, "{minutes:02}:{seconds:02}")
+        }
+    }
+}
+
+/// The remaining time of a countdown as it is shown to a person, in tenths of a second, for a countdown that ticks
+/// more often than once a second.
+///
+/// Like [`DisplaySeconds`], partial tenths round up, so that zero is only shown once the countdown has completed, and
+/// the whole seconds shown always agree with [`DisplaySeconds`] whenever the tenths are zero.
+///
+/// It displays as `MM:SS.T`, or `H:MM:SS.T` from one hour up.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+///
+/// use libtomatillo::countdown::DisplayTenths;
+///
+/// assert_eq!(DisplayTenths::from(Duration::from_millis(299_301)).to_string(), "04:59.4");
+/// assert_eq!(DisplayTenths::from_millis(1).to_string(), "00:00.1");
+/// assert_eq!(DisplayTenths::from_millis(3_600_000).to_string(), "1:00:00.0");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct DisplayTenths(u64);
+
+impl DisplayTenths {
+    /// Converts the milliseconds left on a countdown to the tenths of a second to show, rounding any partial tenth up.
+    #[must_use]
+    pub const fn from_millis(millis_left: u64) -> Self {
+        Self(millis_left.div_ceil(100))
+    }
+
+    /// Returns the number of whole tenths of a second shown.
+    #[must_use]
+    pub const fn as_tenths(self) -> u64 {
+        self.0
+    }
+}
+
+impl From<Duration> for DisplayTenths {
+    /// Converts the time left on a countdown to the tenths of a second to show, rounding any partial tenth up.
+    fn from(left: Duration) -> Self {
+        Self(u64::try_from(left.as_nanos().div_ceil(100_000_000)).unwrap_or(u64::MAX))
+    }
+}
+
+impl Display for DisplayTenths {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}", DisplaySeconds(self.0 / 10), self.0 % 10)
+    }
+}
+
+/// The most zero-width characters, such as combining marks, kept after each character that takes up columns. Text
+/// rarely stacks more than a few, while a flood of them draws far above and below the line.
+const MAX_ZERO_WIDTH_RUN: usize = 4;
+/// What text cut short ends with.
+const ELLIPSIS: char = '…';
+
+/// Makes text from an untrusted source, such as the label of a segment, safe to show on a terminal in at most
+/// `max_columns` columns.
+///
+/// * Escape sequences are removed whole, along with every other C0 and C1 control character, so that the text cannot
+///   move the cursor, recolour the terminal or set its title. Tabs and line breaks become spaces, keeping it on one line.
+/// * Bidirectional overrides, embeddings, isolates and marks are removed, so that the text cannot reorder what is shown
+///   after it.
+/// * At most four zero-width characters, such as combining marks and joiners, are kept after each visible one.
+/// * Text wider than `max_columns` is cut short with an ellipsis, counting columns as a terminal does: two for wide
+///   characters such as CJK and most emoji, none for combining marks and joiners. The characters of an emoji joined
+///   from several count separately, so such text may be cut shorter than it needs to be.
+///
+/// Only text shown to a person should go through it. Anything a program reads back, such as a label looked up to
+/// match a segment, or written out as data, should keep the text as it was given, since it is escaped there already
+/// and sanitizing it would change what it matches.
+///
+/// # Examples
+///
+/// ```
+/// use libtomatillo::countdown::sanitize_display_text;
+///
+/// assert_eq!(sanitize_display_text("\u{1b}[2J\u{1b}]0;pwned\u{7}deep work", 20), "deep work");
+/// assert_eq!(sanitize_display_text("🍅🍅🍅 focus", 6), "🍅🍅…");
+/// ```
+#[must_use]
+pub fn sanitize_display_text(text: &str, max_columns: usize) -> String {
+    let mut kept = Vec::new();
+    let mut zero_width_run = 0;
+    let mut chars = text.chars();
+    while let Some(character) = chars.next() {
+        let character = match character {
+            '\u{1b}' => {
+                skip_escape_sequence(&mut chars);
+                continue;
+            },
+            '\u{9b}' => {
+                skip_control_sequence(&mut chars);
+                continue;
+            },
+            '\u{90}' | '\u{98}' | '\u{9d}' | '\u{9e}' | '\u{9f}' => {
+                skip_control_string(&mut chars);
+                continue;
+            },
+            '\t' | '\n' | '\r' => ' ',
+            character if character.is_control() || is_bidi_control(character) => continue,
+            character => character,
+        };
+
+        let columns = columns(character);
+        if columns > 0 {
+            zero_width_run = 0;
+        } else if zero_width_run < MAX_ZERO_WIDTH_RUN {
+            zero_width_run += 1;
+        } else {
+            continue;
+        }
+        kept.push((character, columns));
+    }
+
+    if kept.iter().map(|(_, columns)| columns).sum::<usize>() <= max_columns {
+        return kept.into_iter().map(|(character, _)| character).collect();
+    }
+
+    let budget = max_columns.saturating_sub(columns(ELLIPSIS));
+    let mut shown = String::new();
+    let mut used = 0;
+    for (character, columns) in kept {
+        if used + columns > budget {
+            break;
+        }
+        used += columns;
+        shown.push(character);
+    }
+    // A joiner left at the end would join the ellipsis to whatever it was joining.
+    while shown.ends_with('\u{200d}') {
+        shown.pop();
+    }
+    if max_columns > 0 {
+        shown.push(ELLIPSIS);
+    }
+    shown
+}
+
+/// Skips the rest of an escape sequence started by ESC: a control sequence, a control string, or an escape with its
+/// intermediate bytes and final byte.
+fn skip_escape_sequence(chars: &mut impl Iterator<Item = char>) {
+    let mut chars = chars.by_ref().skip_while(|character| ('\u{20}'..='\u{2f}').contains(character));
+    match chars.next() {
+        Some('[') => skip_control_sequence(&mut chars),
+        Some(']' | 'P' | 'X' | '^' | '_') => skip_control_string(&mut chars),
+        _ => {},
+    }
+}
+
+/// Skips the parameters and final byte of a control sequence, such as the `31m` of a colour change.
+fn skip_control_sequence(chars: &mut impl Iterator<Item = char>) {
+    // Any character that is not a parameter or an intermediate byte ends the sequence, so that it cannot swallow the rest.
+    let _ = chars.find(|character| !('\u{20}'..='\u{3f}').contains(character));
+}
+
+/// Skips a control string, such as a title set with OSC, up to and including the BEL or string terminator ending it.
+fn skip_control_string(chars: &mut impl Iterator<Item = char>) {
+    let mut escaped = false;
+    for character in chars {
+        match character {
+            '\u{7}' | '\u{9c}' => return,
+            '\\' if escaped => return,
+            _ => escaped = character == '\u{1b}',
+        }
+    }
+}
+
+/// Returns whether `character` changes the direction text is shown in.
+fn is_bidi_control(character: char) -> bool {
+    matches!(character, '\u{61c}' | '\u{200e}' | '\u{200f}' | '\u{202a}'..='\u{202e}' | '\u{2066}'..='\u{2069}')
+}
+
+/// Returns how many columns a terminal gives `character`.
+fn columns(character: char) -> usize {
+    match u32::from(character) {
+        // Combining marks, zero-width spaces and joiners, variation selectors, emoji skin tones and tags.
+        0x0300..=0x036F
+        | 0x0483..=0x0489
+        | 0x0591..=0x05BD
+        | 0x0610..=0x061A
+        | 0x064B..=0x065F
+        | 0x1AB0..=0x1AFF
+        | 0x1DC0..=0x1DFF
+        | 0x200B..=0x200D
+        | 0x2060..=0x2064
+        | 0x20D0..=0x20FF
+        | 0xFE00..=0xFE0F
+        | 0xFE20..=0xFE2F
+        | 0xFEFF
+        | 0x1F3FB..=0x1F3FF
+        | 0xE0000..=0xE0FFF => 0,
+        // Hangul, CJK, fullwidth forms and emoji.
+        0x1100..=0x115F
+        | 0x231A..=0x231B
+        | 0x23E9..=0x23EC
+        | 0x23F0..=0x23F3
+        | 0x25FD..=0x25FE
+        | 0x2614..=0x2615
+        | 0x2648..=0x2653
+        | 0x26A1
+        | 0x26AA..=0x26AB
+        | 0x26BD..=0x26BE
+        | 0x26C4..=0x26C5
+        | 0x26D4
+        | 0x26EA
+        | 0x26F2..=0x26F5
+        | 0x26FA..=0x26FD
+        | 0x2705
+        | 0x270A..=0x270B
+        | 0x2728
+        | 0x274C
+        | 0x2753..=0x2757
+        | 0x2795..=0x2797
+        | 0x2B1B..=0x2B1C
+        | 0x2B50
+        | 0x2B55
+        | 0x2E80..=0x303E
+        | 0x3041..=0x33FF
+        | 0x3400..=0x4DBF
+        | 0x4E00..=0x9FFF
+        | 0xA000..=0xA4CF
+        | 0xAC00..=0xD7A3
+        | 0xF900..=0xFAFF
+        | 0xFE30..=0xFE4F
+        | 0xFF00..=0xFF60
+        | 0xFFE0..=0xFFE6
+        | 0x1F004
+        | 0x1F18E
+        | 0x1F191..=0x1F19A
+        | 0x1F200..=0x1F251
+        | 0x1F300..=0x1F64F
+        | 0x1F680..=0x1F6FF
+        | 0x1F7E0..=0x1F7EB
+        | 0x1F90C..=0x1F9FF
+        | 0x1FA70..=0x1FAFF
+        | 0x20000..=0x3FFFD => 2,
+        _ => 1,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case::completed(0, 0, "00:00")]
+    #[case::last_millisecond(1, 1, "00:01")]
+    #[case::just_under_a_second(999, 1, "00:01")]
+    #[case::one_second(1000, 1, "00:01")]
+    #[case::just_over_a_second(1001, 2, "00:02")]
+    #[case::just_under_a_minute(59_001, 60, "01:00")]
+    #[case::one_minute(60_000, 60, "01:00")]
+    #[case::just_over_a_minute(60_001, 61, "01:01")]
+    #[case::just_under_an_hour(3_599_001, 3600, "1:00:00")]
+    #[case::one_hour(3_600_000, 3600, "1:00:00")]
+    #[case::just_over_an_hour(3_600_001, 3601, "1:00:01")]
+    #[case::one_day(86_400_000, 86_400, "24:00:00")]
+    fn should_round_partial_seconds_up(#[case] millis_left: u64, #[case] secs: u64, #[case] expected: &str) {
+        let shown = DisplaySeconds::from_millis(millis_left);
+
+        assert_eq!(shown.as_secs(), secs);
+        assert_eq!(shown.to_string(), expected);
+        assert_eq!(DisplaySeconds::from(Duration::from_millis(millis_left)), shown);
+    }
+
+    #[test]
+    fn should_round_a_partial_millisecond_up_to_a_second() {
+        assert_eq!(DisplaySeconds::from(Duration::from_micros(1)).as_secs(), 1);
+        assert_eq!(DisplayTenths::from(Duration::from_micros(1)).as_tenths(), 1);
+    }
+
+    #[rstest]
+    #[case::completed(0, 0, "00:00.0")]
+    #[case::last_millisecond(1, 1, "00:00.1")]
+    #[case::one_tenth(100, 1, "00:00.1")]
+    #[case::just_over_a_tenth(101, 2, "00:00.2")]
+    #[case::just_under_a_second(999, 10, "00:01.0")]
+    #[case::just_under_five_minutes(299_301, 2994, "04:59.4")]
+    #[case::just_under_an_hour(3_599_901, 36_000, "1:00:00.0")]
+    #[case::just_over_an_hour(3_600_001, 36_001, "1:00:00.1")]
+    fn should_round_partial_tenths_up(#[case] millis_left: u64, #[case] tenths: u64, #[case] expected: &str) {
+        let shown = DisplayTenths::from_millis(millis_left);
+
+        assert_eq!(shown.as_tenths(), tenths);
+        assert_eq!(shown.to_string(), expected);
+        assert_eq!(DisplayTenths::from(Duration::from_millis(millis_left)), shown);
+    }
+
+    #[rstest]
+    #[case::plain("focus", "focus")]
+    #[case::colour("\u{1b}[1;31mred\u{1b}[0m alert", "red alert")]
+    #[case::cursor_movement("done\u{1b}[2K\u{1b}[1Gspoofed", "donespoofed")]
+    #[case::title("\u{1b}]0;pwned\u{7}focus", "focus")]
+    #[case::title_with_a_string_terminator("\u{1b}]2;pwned\u{1b}\\focus", "focus")]
+    #[case::unterminated_sequence("focus\u{1b}[", "focus")]
+    #[case::c1_control_sequence("a\u{9b}2Jb", "ab")]
+    #[case::controls("be\u{7}ll\u{0}\u{7f}\u{85}", "bell")]
+    #[case::line_breaks("deep\twork\r\nnow", "deep work  now")]
+    #[case::bidi_override("invoice\u{202e}fdp.exe", "invoicefdp.exe")]
+    #[case::bidi_isolate("\u{2067}abc\u{2069}", "abc")]
+    #[case::zero_width_joiner("👩\u{200d}💻", "👩\u{200d}💻")]
+    #[case::combining_flood(&format!("e{}", "\u{301}".repeat(100)), &format!("e{}", "\u{301}".repeat(4)))]
+    #[case::combining_flood_on_each_letter(&"a\u{300}\u{301}\u{302}\u{303}\u{304}\u{305}".repeat(2), &"a\u{300}\u{301}\u{302}\u{303}".repeat(2))]
+    fn should_strip_what_could_corrupt_the_terminal(#[case] text: &str, #[case] expected: &str) {
+        assert_eq!(sanitize_display_text(text, 80), expected);
+    }
+
+    #[rstest]
+    #[case::fits("focus", 5, "focus")]
+    #[case::too_wide("focus time", 5, "focu…")]
+    #[case::no_columns("focus", 0, "")]
+    #[case::one_column("focus", 1, "…")]
+    #[case::emoji_fit("🍅🍅🍅", 6, "🍅🍅🍅")]
+    #[case::emoji_too_wide("🍅🍅🍅🍅🍅", 6, "🍅🍅…")]
+    #[case::emoji_cut_on_an_odd_column("🍅🍅🍅🍅🍅", 7, "🍅🍅🍅…")]
+    #[case::cjk("番茄工作法", 5, "番茄…")]
+    #[case::combining_marks_take_no_columns("e\u{301}e\u{301}e\u{301}", 3, "e\u{301}e\u{301}e\u{301}")]
+    #[case::joiner_at_the_cut("👩\u{200d}💻👩\u{200d}💻", 5, "👩\u{200d}💻…")]
+    #[case::joiner_left_at_the_end("👩\u{200d}💻", 3, "👩…")]
+    #[case::width_after_stripping("\u{1b}[31mred\u{1b}[0m", 3, "red")]
+    fn should_cut_text_short_by_the_columns_it_takes_up(#[case] text: &str, #[case] max_columns: usize, #[case] expected: &str) {
+        assert_eq!(sanitize_display_text(text, max_columns), expected);
+    }
+}
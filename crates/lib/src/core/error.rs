@@ -0,0 +1,171 @@
+use std::time::Duration;
+
+use thiserror::Error;
+
+use super::{protocol::CloseReason, validation::{InvalidDuration, TimerError}};
+
+/// The result of a countdown operation.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+///
+/// use libtomatillo::countdown::{ChannelError, Result};
+///
+/// fn next_update() -> Result<u64> {
+///     Err(ChannelError::Timeout(Duration::from_secs(1)).into())
+/// }
+///
+/// assert!(next_update().is_err());
+/// ```
+pub type Result<T> = std::result::Result<T, CountdownError>;
+
+/// An error raised while creating, receiving from or adding time to a countdown.
+///
+/// Starting a countdown fails with a [`StartError`](super::validation::StartError) instead.
+///
+/// # Examples
+///
+/// ```
+/// use libtomatillo::countdown::{CountdownError, InvalidCountdown, TimerError};
+///
+/// let error = CountdownError::from(TimerError::from(InvalidCountdown::ZeroInterval));
+///
+/// assert_eq!(error, CountdownError::TimerError(TimerError::InvalidCountdown(InvalidCountdown::ZeroInterval)));
+/// ```
+#[derive(Debug, Error, Clone, PartialEq)]
+pub enum CountdownError {
+    #[error(transparent)]
+    TimerError(#[from] TimerError),
+    #[error(transparent)]
+    ChannelError(#[from] ChannelError),
+    #[error(transparent)]
+    InvalidDuration(#[from] InvalidDuration),
+}
+
+/// An error raised while receiving from, or closing, a channel.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+///
+/// use libtomatillo::countdown::ChannelError;
+///
+/// let error = ChannelError::Timeout(Duration::from_secs(1));
+///
+/// assert_eq!(error.to_string(), "timed out after 1s waiting for update");
+/// ```
+#[derive(Debug, Error, Clone, PartialEq)]
+pub enum ChannelError {
+    #[error("timed out after {0:?} waiting for update")] 
+    Timeout(Duration),
+    #[error("countdown protocol violated: {0}")]
+    ProtocolViolation(Violation),
+    #[error("the countdown has already {0}")]
+    Closed(CloseReason),
+}
+
+/// A way in which a value received by a `StrictReceiver` broke the countdown protocol.
+///
+/// # Examples
+///
+/// ```
+/// use libtomatillo::countdown::Violation;
+///
+/// assert_eq!(Violation::Increased.to_string(), "value is greater than the previous one");
+/// ```
+#[derive(Debug, Error, Clone, Copy, PartialEq, Eq)]
+pub enum Violation {
+    #[error("value is greater than the previous one")]
+    Increased,
+    #[error("value is the same as the previous one")]
+    Repeated,
+    #[error("value arrived after the channel closed")]
+    AfterClosed,
+}
+
+/// Why the task running a countdown did not run to the end.
+///
+/// # Examples
+///
+/// ```
+/// use libtomatillo::countdown::TaskError;
+///
+/// let error = TaskError::Panicked("index out of bounds".to_string());
+///
+/// assert_eq!(error.to_string(), "the countdown task panicked: index out of bounds");
+/// ```
+#[derive(Debug, Error, Clone, PartialEq)]
+pub enum TaskError {
+    /// The task could not send a value or close its channel.
+    #[error("the countdown task failed: {0}")]
+    Failed(#[source] CountdownError),
+    /// The task panicked, with the panic's message.
+    #[error("the countdown task panicked: {0}")]
+    Panicked(String),
+    /// The task was aborted before it finished, e.g. because the countdown was cancelled.
+    #[error("the countdown task was aborted")]
+    Aborted,
+}
+
+#[cfg(test)]
+mod tests {
+    use std::error::Error as _;
+
+    use rstest::rstest;
+
+    use crate::core::{protocol::FailureCode, validation::{InvalidCountdown, StartError}};
+
+    use super::*;
+
+    #[rstest]
+    #[case::zero_interval(TimerError::from(InvalidCountdown::ZeroInterval).into())]
+    #[case::interval_below_the_minimum(TimerError::from(InvalidCountdown::IntervalSmallerThanMinimum(Duration::from_millis(9))).into())]
+    #[case::interval_over_one_hour(TimerError::from(InvalidCountdown::IntervalGreaterThanOneHour(Duration::from_secs(3601))).into())]
+    #[case::timeout_within_the_interval(TimerError::from(InvalidCountdown::TimeoutNotLongerThanInterval { timeout: Duration::from_millis(100), period: Duration::from_millis(100) }).into())]
+    #[case::invalid_max_duration(TimerError::InvalidMaxDuration(InvalidDuration::ZeroDuration).into())]
+    #[case::incompatible_options(TimerError::IncompatibleOptions { option: "align to the wall clock", conflicts_with: "delay missed ticks" }.into())]
+    #[case::timeout(ChannelError::Timeout(Duration::from_secs(1)).into())]
+    #[case::protocol_violation(ChannelError::ProtocolViolation(Violation::Increased).into())]
+    #[case::closed(ChannelError::Closed(CloseReason::Failed(FailureCode::Unacknowledged)).into())]
+    #[case::zero_duration(InvalidDuration::ZeroDuration.into())]
+    #[case::duration_over_one_day(InvalidDuration::DurationGreaterThanOneDay(Duration::from_secs(86_401)).into())]
+    #[case::duration_within_the_period(InvalidDuration::DurationSmallerThanPeriod { duration: Duration::from_millis(50), period: Duration::from_millis(100) }.into())]
+    #[case::duration_over_the_maximum(InvalidDuration::DurationGreaterThanMaximum { duration: Duration::from_secs(61), maximum: Duration::from_secs(60) }.into())]
+    #[case::remaining_over_the_duration(InvalidDuration::RemainingGreaterThanDuration { remaining: Duration::from_secs(2), duration: Duration::from_secs(1) }.into())]
+    #[case::uncapped_repeat(InvalidDuration::UncappedRepeat.into())]
+    #[case::deadline_in_past(InvalidDuration::DeadlineInPast(Duration::from_secs(90)).into())]
+    fn should_clone_a_countdown_error_that_describes_itself_the_same(#[case] error: CountdownError) {
+        let clone = error.clone();
+
+        assert_eq!(clone, error);
+        assert_eq!(clone.to_string(), error.to_string());
+        assert_eq!(clone.source().map(ToString::to_string), error.source().map(ToString::to_string));
+    }
+
+    #[rstest]
+    #[case::invalid_duration(StartError::from(InvalidDuration::ZeroDuration))]
+    #[case::already_running(StartError::AlreadyRunning { key: "focus".to_owned(), remaining: Duration::from_secs(90) })]
+    #[case::empty_sequence(StartError::EmptySequence)]
+    #[case::invalid_segment(StartError::InvalidSegment { index: 1, label: "break".to_owned(), source: InvalidDuration::ZeroDuration })]
+    fn should_clone_a_start_error_that_describes_itself_the_same(#[case] error: StartError) {
+        let clone = error.clone();
+
+        assert_eq!(clone, error);
+        assert_eq!(clone.to_string(), error.to_string());
+    }
+
+    #[rstest]
+    #[case::failed(TaskError::Failed(ChannelError::Timeout(Duration::from_secs(1)).into()))]
+    #[case::panicked(TaskError::Panicked("injected panic".to_owned()))]
+    #[case::aborted(TaskError::Aborted)]
+    fn should_clone_a_task_error_that_describes_itself_the_same(#[case] error: TaskError) {
+        let clone = error.clone();
+
+        assert_eq!(clone, error);
+        assert_eq!(clone.to_string(), error.to_string());
+        assert_eq!(clone.source().map(ToString::to_string), error.source().map(ToString::to_string));
+    }
+}
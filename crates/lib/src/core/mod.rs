@@ -0,0 +1,13 @@
+//! The parts of a countdown that need no async runtime: validation, the schedule arithmetic, how the time left and
+//! labels are shown, the channel protocol and the errors it raises, and how they are described to a person.
+//! Everything public here is re-exported from [`crate::countdown`].
+
+pub(crate) mod display;
+pub(crate) mod error;
+pub(crate) mod protocol;
+// Only the runtime aligns a countdown to the wall clock or validates every option it has.
+#[cfg_attr(not(feature = "runtime"), allow(dead_code))]
+pub(crate) mod schedule;
+#[cfg_attr(not(feature = "runtime"), allow(dead_code))]
+pub(crate) mod validation;
+pub(crate) mod user_facing;
@@ -0,0 +1,273 @@
+use std::time::Duration;
+
+use thiserror::Error;
+
+use super::{display::{DisplaySeconds, DisplayTenths}, error::Result};
+
+/// A response from a [`Receiver`]: either the latest value sent, a notice that a repeating countdown has started its
+/// next cycle, or a notice that no more values will follow.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+///
+/// use libtomatillo::countdown::{CloseReason, Response};
+///
+/// let describe = |response: Response<Duration>| match response {
+///     Response::Value(left) => format!("{left:?} left"),
+///     Response::CycleCompleted(cycles) => format!("{cycles} done, starting again"),
+///     Response::Closed(CloseReason::Completed) => "done".to_string(),
+///     Response::Closed(reason) => format!("stopped: {reason}"),
+/// };
+///
+/// assert_eq!(describe(Response::Value(Duration::from_millis(1500))), "1.5s left");
+/// assert_eq!(describe(Response::CycleCompleted(2)), "2 done, starting again");
+/// assert_eq!(describe(Response::Closed(CloseReason::Completed)), "done");
+/// assert_eq!(describe(Response::Closed(CloseReason::Cancelled)), "stopped: cancelled");
+/// ```
+#[derive(Debug, PartialEq)]
+pub enum Response<T: PartialEq + Copy> {
+    Value(T),
+    /// How many cycles a repeating countdown has completed, received before the first value of the next cycle. See
+    /// `AsyncCountdownBuilder::repeat`.
+    CycleCompleted(u32),
+    Closed(CloseReason),
+}
+
+impl Response<Duration> {
+    /// Returns the seconds to show for a [`Response::Value`] of the time left, or `None` for any other response.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use libtomatillo::countdown::{CloseReason, DisplaySeconds, Response};
+    ///
+    /// assert_eq!(Response::Value(Duration::from_millis(1500)).display_seconds(), Some(DisplaySeconds::from_millis(1500)));
+    /// assert_eq!(Response::Closed(CloseReason::Completed).display_seconds(), None);
+    /// ```
+    #[must_use]
+    pub fn display_seconds(&self) -> Option<DisplaySeconds> {
+        match self {
+            Self::Value(left) => Some(DisplaySeconds::from(*left)),
+            Self::CycleCompleted(_) | Self::Closed(_) => None,
+        }
+    }
+
+    /// Returns the tenths of a second to show for a [`Response::Value`] of the time left, or `None` for any other
+    /// response, for a countdown that ticks more often than once a second.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use libtomatillo::countdown::{CloseReason, DisplayTenths, Response};
+    ///
+    /// let left = Response::Value(Duration::from_millis(299_700));
+    ///
+    /// assert_eq!(left.display_tenths(), Some(DisplayTenths::from_millis(299_700)));
+    /// assert_eq!(left.display_tenths().map(|shown| shown.to_string()), Some("04:59.7".to_string()));
+    /// assert_eq!(Response::Closed(CloseReason::Completed).display_tenths(), None);
+    /// ```
+    #[must_use]
+    pub fn display_tenths(&self) -> Option<DisplayTenths> {
+        match self {
+            Self::Value(left) => Some(DisplayTenths::from(*left)),
+            Self::CycleCompleted(_) | Self::Closed(_) => None,
+        }
+    }
+}
+
+/// How far a countdown has got, as received by `ChannelReceiver::recv_progress`.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+///
+/// use libtomatillo::countdown::Progress;
+///
+/// let progress = Progress { remaining: Duration::from_secs(15), total: Duration::from_secs(60), tick_index: 45 };
+///
+/// assert_eq!(progress.percent_complete(), 75);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Progress {
+    /// The time left.
+    pub remaining: Duration,
+    /// The duration of the countdown, including any time added to it since it started.
+    pub total: Duration,
+    /// How many values were sent before this one: the full duration the countdown starts with is value 0.
+    pub tick_index: u64,
+}
+
+impl Progress {
+    /// Returns how much of the countdown has elapsed, from 0 when it starts to 100 once it completes, rounded down.
+    #[must_use]
+    pub fn percent_complete(&self) -> u8 {
+        if self.total.is_zero() {
+            return 100;
+        }
+
+        let elapsed = self.total.saturating_sub(self.remaining);
+        u8::try_from(elapsed.as_nanos() * 100 / self.total.as_nanos()).unwrap_or(100)
+    }
+}
+
+/// Why a countdown ended, as delivered by [`Response::Closed`].
+///
+/// The reason is set by whoever closes the channel; if several parties race to close it, the first reason wins.
+///
+/// # Examples
+///
+/// ```
+/// use libtomatillo::countdown::{CloseReason, FailureCode};
+///
+/// assert_eq!(CloseReason::Completed.to_string(), "completed");
+/// assert_eq!(
+///     CloseReason::Failed(FailureCode::Unacknowledged).to_string(),
+///     "failed: the receiver did not acknowledge the last value",
+/// );
+/// ```
+#[derive(Debug, Error, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum CloseReason {
+    /// The countdown ran to zero.
+    #[error("completed")]
+    Completed,
+    /// The countdown was stopped before it reached zero, for example by dropping the `AsyncCountdown` that started it.
+    #[error("cancelled")]
+    Cancelled,
+    /// The countdown was skipped to its end, for example with `CountdownHandle::skip`, before it got there itself.
+    #[error("skipped to the end")]
+    Skipped,
+    /// The sender could not close the channel cleanly.
+    #[error("failed: {0}")]
+    Failed(FailureCode),
+}
+
+/// What went wrong when a countdown ended with [`CloseReason::Failed`].
+#[derive(Debug, Error, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum FailureCode {
+    /// The receiver stopped acknowledging values, so the sender gave up waiting for it before closing.
+    #[error("the receiver did not acknowledge the last value")]
+    Unacknowledged,
+    /// The task sending the values panicked, so the countdown stopped before it reached zero.
+    #[error("the countdown task panicked")]
+    Panicked,
+}
+
+/// A sender that sends countdown updates to a [`Receiver`].
+///
+/// Only the latest value is kept: a value the receiver has not read yet is replaced by the next one sent.
+///
+/// # Examples
+///
+/// ```
+/// use std::{cell::RefCell, future::Future};
+///
+/// use libtomatillo::countdown::{Result, Sender};
+///
+/// #[derive(Default)]
+/// struct Recorder(RefCell<Vec<u64>>, RefCell<bool>);
+///
+/// impl Sender<u64> for Recorder {
+///     fn send(&self, value: u64) -> impl Future<Output = Result<()>> {
+///         self.0.borrow_mut().push(value);
+///         async { Ok(()) }
+///     }
+///
+///     fn close(&self) -> impl Future<Output = Result<()>> {
+///         *self.1.borrow_mut() = true;
+///         async { Ok(()) }
+///     }
+/// }
+///
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// let recorder = Recorder::default();
+/// recorder.send(100).await.expect("should have sent");
+/// recorder.close().await.expect("should have closed");
+///
+/// assert_eq!(*recorder.0.borrow(), [100]);
+/// assert!(*recorder.1.borrow());
+/// # }
+/// ```
+pub trait Sender<T> {
+    /// Sends a value to the [`Receiver`], replacing any value it has not read yet.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `value` - The value to send to the receiver
+    /// 
+    /// # Returns
+    /// 
+    /// A [`Result`] that is:
+    /// 
+    /// * `Ok(())` - The value has been sent successfully.
+    /// * `Err(err)` - The value could not be sent.
+    fn send(&self, value: T) -> impl std::future::Future<Output = Result<()>>;
+
+    /// Closes the sender, indicating that no more values will be sent. 
+    /// 
+    /// Implementations of this function should wait for the receiver to acknowledge the last value, then alert it
+    /// that the sender is closed with [`CloseReason::Completed`] to indicate no more values will be sent. If the
+    /// receiver never acknowledges it, they should close with [`CloseReason::Failed`] instead and return the error.
+    /// 
+    /// # Returns
+    /// 
+    /// A [`Result`] that is:
+    /// 
+    /// * `Ok(())` - The sender has been closed successfully.
+    /// * `Err(err)` - The sender could not be closed.
+    fn close(&self) -> impl std::future::Future<Output = Result<()>>;
+}
+
+/// Receives updates from a sender and acknowledges receipt.
+///
+/// # Examples
+///
+/// ```
+/// use std::{cell::Cell, future::Future};
+///
+/// use libtomatillo::countdown::{CloseReason, Receiver, Response, Result};
+///
+/// struct Replay(Cell<Option<u64>>);
+///
+/// impl Receiver<u64> for Replay {
+///     fn recv(&self) -> impl Future<Output = Result<Response<u64>>> {
+///         let response = match self.0.take() {
+///             Some(millis_left) => Response::Value(millis_left),
+///             None => Response::Closed(CloseReason::Completed),
+///         };
+///         async { Ok(response) }
+///     }
+/// }
+///
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// let rx = Replay(Cell::new(Some(100)));
+///
+/// assert_eq!(rx.recv().await, Ok(Response::Value(100)));
+/// assert_eq!(rx.recv().await, Ok(Response::Closed(CloseReason::Completed)));
+/// # }
+/// ```
+pub trait Receiver<T: PartialEq + Copy> {
+    /// Receives a value from the sender and acknowledges receipt.
+    /// 
+    /// # Returns
+    /// 
+    /// A [`Result`] that is:
+    /// 
+    /// * `Ok(Response::Value(value))` - A new value has been received.
+    /// * `Ok(Response::CycleCompleted(cycles))` - A repeating countdown has completed `cycles` cycles, and the next
+    ///   receive gets the first value of the next one.
+    /// * `Ok(Response::Closed(reason))` - The sender is closed for `reason` and no more values will be sent.
+    /// * `Err(err)` - The value could not be received.
+    fn recv(&self) -> impl std::future::Future<Output = Result<Response<T>>>;
+}
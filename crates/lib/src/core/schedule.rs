@@ -0,0 +1,125 @@
+use std::time::Duration;
+
+/// Returns how long it is from `since_epoch` until the next whole multiple of `period` since the epoch, or a whole
+/// `period` if `since_epoch` is already one.
+pub(crate) fn until_boundary(since_epoch: Duration, period: Duration) -> Duration {
+    let into_period = since_epoch.as_millis() % period.as_millis();
+
+    period - Duration::from_millis(into_period as u64)
+}
+
+/// The ticks of a countdown that is never late, each paired with the time left when it is due: the full
+/// duration straight away, then what is left of it every period, then zero as soon as it runs out, even between two
+/// periods. Returned by `PollCountdown::schedule`.
+///
+/// Every countdown in the library follows it while its ticks are on time, so that they all agree on the values sent.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+///
+/// use libtomatillo::countdown::poll::PollCountdown;
+///
+/// let countdown = PollCountdown::try_new(Duration::from_millis(250), Duration::from_millis(100))
+///     .expect("should have created the countdown");
+/// let due = countdown.schedule().map(|(after, left)| (after.as_millis(), left.as_millis())).collect::<Vec<_>>();
+///
+/// assert_eq!(due, [(0, 250), (100, 150), (200, 50), (250, 0)]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Schedule {
+    duration_millis: u64,
+    period_millis: u64,
+    next: u64,
+}
+
+impl Schedule {
+    /// Returns the schedule of a countdown of `duration` ticking every `period`, which must not be zero.
+    pub(crate) fn new(duration: Duration, period: Duration) -> Self {
+        Self { duration_millis: duration.as_millis() as u64, period_millis: period.as_millis() as u64, next: 0 }
+    }
+
+    /// Returns the index of the last tick, the one due when the countdown runs out.
+    pub(crate) fn last_index(&self) -> u64 {
+        self.duration_millis.div_ceil(self.period_millis)
+    }
+
+    /// Returns the index of the latest tick due `elapsed_millis` after the countdown started.
+    pub(crate) fn latest_due(&self, elapsed_millis: u64) -> u64 {
+        if elapsed_millis >= self.duration_millis {
+            return self.last_index();
+        }
+
+        elapsed_millis / self.period_millis
+    }
+
+    /// Returns how many milliseconds after the countdown started the tick at `index` is due, and the milliseconds left
+    /// then.
+    pub(crate) fn tick(&self, index: u64) -> (u64, u64) {
+        let due = index.saturating_mul(self.period_millis).min(self.duration_millis);
+
+        (due, self.duration_millis - due)
+    }
+}
+
+impl Iterator for Schedule {
+    type Item = (Duration, Duration);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.next > self.last_index() {
+            return None;
+        }
+
+        let (due, millis_left) = self.tick(self.next);
+        self.next += 1;
+        Some((Duration::from_millis(due), Duration::from_millis(millis_left)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case::on_a_boundary(5000, 1000, 1000)]
+    #[case::just_past_a_boundary(5001, 1000, 999)]
+    #[case::just_before_a_boundary(5999, 1000, 1)]
+    #[case::minute_period(50_607_000, 60_000, 33_000)]
+    fn should_measure_the_time_until_the_next_boundary(#[case] since_epoch_millis: u64, #[case] period_millis: u64, #[case] expected_millis: u64) {
+        let until = until_boundary(Duration::from_millis(since_epoch_millis), Duration::from_millis(period_millis));
+
+        assert_eq!(until, Duration::from_millis(expected_millis));
+    }
+
+    #[rstest]
+    #[case::whole_periods(300, 100, &[(0, 300), (100, 200), (200, 100), (300, 0)])]
+    #[case::a_partial_period(250, 100, &[(0, 250), (100, 150), (200, 50), (250, 0)])]
+    #[case::one_period(100, 100, &[(0, 100), (100, 0)])]
+    fn should_tick_every_period_then_when_the_countdown_runs_out(
+        #[case] duration_millis: u64,
+        #[case] period_millis: u64,
+        #[case] expected: &[(u64, u64)],
+    ) {
+        let schedule = Schedule::new(Duration::from_millis(duration_millis), Duration::from_millis(period_millis));
+
+        let ticks = schedule.map(|(due, left)| (due.as_millis() as u64, left.as_millis() as u64)).collect::<Vec<_>>();
+
+        assert_eq!(ticks, expected);
+    }
+
+    #[rstest]
+    #[case::started(0, 0)]
+    #[case::just_before_a_tick(99, 0)]
+    #[case::on_a_tick(100, 1)]
+    #[case::just_before_running_out(249, 2)]
+    #[case::run_out(250, 3)]
+    #[case::long_after(1_000_000, 3)]
+    fn should_find_the_latest_tick_due(#[case] elapsed_millis: u64, #[case] expected: u64) {
+        let schedule = Schedule::new(Duration::from_millis(250), Duration::from_millis(100));
+
+        assert_eq!(schedule.latest_due(elapsed_millis), expected);
+    }
+}
@@ -0,0 +1,353 @@
+use std::{fmt::Write as _, time::Duration};
+
+use super::{
+    display::sanitize_display_text,
+    error::{ChannelError, CountdownError, TaskError},
+    validation::{InvalidCountdown, InvalidDuration, StartError, TimerError, MAX_DURATION, MAX_PERIOD, MIN_PERIOD},
+};
+
+/// The most columns of a label or key shown in a message.
+const LABEL_COLUMNS: usize = 32;
+
+/// An error that can be described to the person running a countdown rather than to its developer.
+///
+/// [`UserFacing::user_message`] names the value that was rejected, the limit it broke and, where there is an obvious
+/// one, a fix, while [`std::fmt::Display`] stays terse and stable for logs.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+///
+/// use libtomatillo::countdown::{InvalidDuration, UserFacing};
+///
+/// let error = InvalidDuration::DurationSmallerThanPeriod { duration: Duration::from_millis(25), period: Duration::from_secs(1) };
+///
+/// assert_eq!(error.to_string(), "Duration 25ms cannot be smaller than period 1s");
+/// assert_eq!(error.user_message(), "the duration 25ms is shorter than the period 1s; did you mean 25m instead of 25ms?");
+/// ```
+pub trait UserFacing {
+    /// Returns a description of the error for the person running the countdown, in lower case with no full stop, to
+    /// be shown after `error: ` or the like.
+    fn user_message(&self) -> String;
+}
+
+impl UserFacing for InvalidCountdown {
+    fn user_message(&self) -> String {
+        match self {
+            Self::ZeroInterval => {
+                format!("the period cannot be zero; it must be from {} to {}", spelled(MIN_PERIOD), spelled(MAX_PERIOD))
+            },
+            Self::IntervalSmallerThanMinimum(period) => format!(
+                "the period {} is shorter than the minimum of {}; {}",
+                spelled(*period),
+                spelled(MIN_PERIOD),
+                did_you_mean(in_seconds(*period), *period),
+            ),
+            Self::IntervalGreaterThanOneHour(period) => {
+                format!("the period {} is longer than the maximum of {}", spelled(*period), spelled(MAX_PERIOD))
+            },
+            Self::TimeoutNotLongerThanInterval { timeout, period } => format!(
+                "the channel timeout {} must be longer than the period {}; try {}, three periods",
+                spelled(*timeout),
+                spelled(*period),
+                spelled(period.saturating_mul(3)),
+            ),
+        }
+    }
+}
+
+impl UserFacing for TimerError {
+    fn user_message(&self) -> String {
+        match self {
+            Self::InvalidCountdown(err) => err.user_message(),
+            Self::InvalidMaxDuration(err) => format!("invalid maximum duration: {}", err.user_message()),
+            Self::InvalidTimeScale(scale) => format!("the time scale {scale} must be a finite number greater than zero"),
+            Self::InvalidScaledPeriod {
+                scale,
+                source: InvalidCountdown::IntervalSmallerThanMinimum(period) | InvalidCountdown::IntervalGreaterThanOneHour(period),
+            } => format!(
+                "the time scale {scale} makes the timer tick every {}, but it must tick every {} to {}",
+                spelled(*period),
+                spelled(MIN_PERIOD),
+                spelled(MAX_PERIOD),
+            ),
+            Self::InvalidScaledPeriod { scale, source } => format!("at a time scale of {scale}, {}", source.user_message()),
+            Self::IncompatibleOptions { option, conflicts_with } => {
+                format!("the timer cannot {option} and {conflicts_with} at the same time; drop one of them")
+            },
+        }
+    }
+}
+
+impl UserFacing for InvalidDuration {
+    fn user_message(&self) -> String {
+        match self {
+            Self::ZeroDuration => format!("the duration cannot be zero; it must be from one period to {}", spelled(MAX_DURATION)),
+            Self::DurationGreaterThanOneDay(duration) => {
+                format!("the duration {} is longer than the maximum of {}", spelled(*duration), spelled(MAX_DURATION))
+            },
+            Self::DurationSmallerThanPeriod { duration, period } => {
+                let mut message = format!("the duration {} is shorter than the period {}", spelled(*duration), spelled(*period));
+                // Minutes are the usual unit of a countdown, so a duration in milliseconds was most likely meant as them.
+                let minutes = in_minutes(*duration);
+                if *duration < Duration::from_secs(1) && (*period..=MAX_DURATION).contains(&minutes) {
+                    let _ = write!(message, "; {}", did_you_mean(minutes, *duration));
+                }
+                message
+            },
+            Self::DurationGreaterThanMaximum { duration, maximum } => format!(
+                "the duration {} is longer than the configured maximum of {}",
+                spelled(*duration),
+                spelled(*maximum),
+            ),
+            Self::RemainingGreaterThanDuration { remaining, duration } => format!(
+                "the time left {} is longer than the whole duration {}",
+                spelled(*remaining),
+                spelled(*duration),
+            ),
+            Self::UncappedRepeat => "a count-up with no duration to count up to cannot repeat; give it a duration".to_owned(),
+            Self::DeadlineInPast(passed) => {
+                format!("the deadline passed {} ago; give a deadline in the future", spelled(*passed))
+            },
+        }
+    }
+}
+
+impl UserFacing for StartError {
+    fn user_message(&self) -> String {
+        match self {
+            Self::InvalidDuration(err) => err.user_message(),
+            Self::AlreadyRunning { key, remaining } => format!(
+                "the countdown \"{}\" is already running with {} left; wait for it to end or cancel it first",
+                sanitize_display_text(key, LABEL_COLUMNS),
+                spelled(*remaining),
+            ),
+            Self::EmptySequence => "a sequence needs at least one segment".to_owned(),
+            Self::InvalidSegment { index, label, source } => {
+                let label = sanitize_display_text(label, LABEL_COLUMNS);
+                format!("segment {} of the sequence, \"{label}\", is invalid: {}", index.saturating_add(1), source.user_message())
+            },
+        }
+    }
+}
+
+impl UserFacing for ChannelError {
+    fn user_message(&self) -> String {
+        match self {
+            Self::Timeout(timeout) => format!(
+                "no value arrived from the countdown within {}; it may be stuck, or the host too busy to run it",
+                spelled(*timeout),
+            ),
+            Self::ProtocolViolation(violation) => {
+                format!("the countdown sent a value out of order: {violation}; this is a bug in tomatillo")
+            },
+            Self::Closed(reason) => format!("the countdown has already {reason}; start a new one"),
+        }
+    }
+}
+
+impl UserFacing for CountdownError {
+    fn user_message(&self) -> String {
+        match self {
+            Self::TimerError(err) => err.user_message(),
+            Self::ChannelError(err) => err.user_message(),
+            Self::InvalidDuration(err) => err.user_message(),
+        }
+    }
+}
+
+impl UserFacing for TaskError {
+    fn user_message(&self) -> String {
+        match self {
+            // The task only times out waiting for the receiver to acknowledge a value.
+            Self::Failed(CountdownError::ChannelError(ChannelError::Timeout(timeout))) => format!(
+                "the countdown stopped: its output did not take the last value within {}; it may be stuck",
+                spelled(*timeout),
+            ),
+            Self::Failed(err) => format!("the countdown stopped: {}", err.user_message()),
+            Self::Panicked(message) => format!("the countdown panicked: {message}; this is a bug in tomatillo"),
+            Self::Aborted => "the countdown was cancelled before it finished".to_owned(),
+        }
+    }
+}
+
+/// Returns `duration` as a person would write it, in units from hours down to milliseconds, e.g. `1h 30m` or `250ms`.
+fn spelled(duration: Duration) -> String {
+    let millis = duration.as_millis();
+    let parts = [(millis / 3_600_000, "h"), (millis / 60_000 % 60, "m"), (millis / 1000 % 60, "s"), (millis % 1000, "ms")];
+
+    let spelled = parts.iter()
+        .filter(|(count, _)| *count > 0)
+        .map(|(count, unit)| format!("{count}{unit}"))
+        .collect::<Vec<_>>();
+    if spelled.is_empty() { "0ms".to_owned() } else { spelled.join(" ") }
+}
+
+/// Returns as many seconds as `duration` has milliseconds.
+fn in_seconds(duration: Duration) -> Duration {
+    Duration::from_secs(u64::try_from(duration.as_millis()).unwrap_or(u64::MAX))
+}
+
+/// Returns as many minutes as `duration` has milliseconds.
+fn in_minutes(duration: Duration) -> Duration {
+    in_seconds(duration).saturating_mul(60)
+}
+
+/// Suggests `meant` in place of `given`, a value that was most likely given in the wrong unit.
+fn did_you_mean(meant: Duration, given: Duration) -> String {
+    format!("did you mean {} instead of {}?", spelled(meant), spelled(given))
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use crate::core::protocol::{CloseReason, FailureCode};
+    use crate::core::error::Violation;
+
+    use super::*;
+
+    #[rstest]
+    #[case::zero(Duration::ZERO, "0ms")]
+    #[case::milliseconds(Duration::from_millis(250), "250ms")]
+    #[case::minutes(Duration::from_secs(25 * 60), "25m")]
+    #[case::hours_and_minutes(Duration::from_secs(90 * 60), "1h 30m")]
+    #[case::every_unit(Duration::from_millis(24 * 60 * 60 * 1000 + 61_001), "24h 1m 1s 1ms")]
+    fn should_spell_a_duration_in_every_unit_it_has(#[case] duration: Duration, #[case] expected: &str) {
+        assert_eq!(spelled(duration), expected);
+    }
+
+    #[rstest]
+    #[case::zero_interval(
+        TimerError::from(InvalidCountdown::ZeroInterval).into(),
+        "the period cannot be zero; it must be from 10ms to 1h",
+    )]
+    #[case::interval_below_the_minimum(
+        TimerError::from(InvalidCountdown::IntervalSmallerThanMinimum(Duration::from_millis(5))).into(),
+        "the period 5ms is shorter than the minimum of 10ms; did you mean 5s instead of 5ms?",
+    )]
+    #[case::interval_over_one_hour(
+        TimerError::from(InvalidCountdown::IntervalGreaterThanOneHour(Duration::from_secs(2 * 60 * 60))).into(),
+        "the period 2h is longer than the maximum of 1h",
+    )]
+    #[case::timeout_within_the_interval(
+        TimerError::from(InvalidCountdown::TimeoutNotLongerThanInterval { timeout: Duration::from_millis(500), period: Duration::from_secs(1) }).into(),
+        "the channel timeout 500ms must be longer than the period 1s; try 3s, three periods",
+    )]
+    #[case::invalid_max_duration(
+        TimerError::InvalidMaxDuration(InvalidDuration::ZeroDuration).into(),
+        "invalid maximum duration: the duration cannot be zero; it must be from one period to 24h",
+    )]
+    #[case::invalid_time_scale(
+        TimerError::InvalidTimeScale(-2.0).into(),
+        "the time scale -2 must be a finite number greater than zero",
+    )]
+    #[case::scaled_interval_below_the_minimum(
+        TimerError::InvalidScaledPeriod { scale: 200.0, source: InvalidCountdown::IntervalSmallerThanMinimum(Duration::from_millis(5)) }.into(),
+        "the time scale 200 makes the timer tick every 5ms, but it must tick every 10ms to 1h",
+    )]
+    #[case::incompatible_options(
+        TimerError::IncompatibleOptions { option: "align to the wall clock", conflicts_with: "run at a time scale" }.into(),
+        "the timer cannot align to the wall clock and run at a time scale at the same time; drop one of them",
+    )]
+    #[case::timeout(
+        ChannelError::Timeout(Duration::from_secs(3)).into(),
+        "no value arrived from the countdown within 3s; it may be stuck, or the host too busy to run it",
+    )]
+    #[case::protocol_violation(
+        ChannelError::ProtocolViolation(Violation::Increased).into(),
+        "the countdown sent a value out of order: value is greater than the previous one; this is a bug in tomatillo",
+    )]
+    #[case::closed(
+        ChannelError::Closed(CloseReason::Completed).into(),
+        "the countdown has already completed; start a new one",
+    )]
+    #[case::skipped(
+        ChannelError::Closed(CloseReason::Skipped).into(),
+        "the countdown has already skipped to the end; start a new one",
+    )]
+    #[case::zero_duration(
+        InvalidDuration::ZeroDuration.into(),
+        "the duration cannot be zero; it must be from one period to 24h",
+    )]
+    #[case::duration_over_one_day(
+        InvalidDuration::DurationGreaterThanOneDay(Duration::from_secs(24 * 60 * 60 + 1)).into(),
+        "the duration 24h 1s is longer than the maximum of 24h",
+    )]
+    #[case::suspiciously_small_duration(
+        InvalidDuration::DurationSmallerThanPeriod { duration: Duration::from_millis(25), period: Duration::from_secs(1) }.into(),
+        "the duration 25ms is shorter than the period 1s; did you mean 25m instead of 25ms?",
+    )]
+    #[case::small_duration_within_a_long_period(
+        InvalidDuration::DurationSmallerThanPeriod { duration: Duration::from_millis(25), period: Duration::from_secs(30 * 60) }.into(),
+        "the duration 25ms is shorter than the period 30m",
+    )]
+    #[case::duration_within_the_period(
+        InvalidDuration::DurationSmallerThanPeriod { duration: Duration::from_secs(30), period: Duration::from_secs(60) }.into(),
+        "the duration 30s is shorter than the period 1m",
+    )]
+    #[case::duration_over_the_maximum(
+        InvalidDuration::DurationGreaterThanMaximum { duration: Duration::from_secs(61 * 60), maximum: Duration::from_secs(60 * 60) }.into(),
+        "the duration 1h 1m is longer than the configured maximum of 1h",
+    )]
+    #[case::remaining_over_the_duration(
+        InvalidDuration::RemainingGreaterThanDuration { remaining: Duration::from_secs(30 * 60), duration: Duration::from_secs(25 * 60) }.into(),
+        "the time left 30m is longer than the whole duration 25m",
+    )]
+    #[case::uncapped_repeat(
+        InvalidDuration::UncappedRepeat.into(),
+        "a count-up with no duration to count up to cannot repeat; give it a duration",
+    )]
+    #[case::deadline_in_past(
+        InvalidDuration::DeadlineInPast(Duration::from_secs(90)).into(),
+        "the deadline passed 1m 30s ago; give a deadline in the future",
+    )]
+    fn should_describe_a_countdown_error_to_a_person(#[case] error: CountdownError, #[case] expected: &str) {
+        assert_eq!(error.user_message(), expected);
+    }
+
+    #[rstest]
+    #[case::invalid_duration(
+        StartError::from(InvalidDuration::ZeroDuration),
+        "the duration cannot be zero; it must be from one period to 24h",
+    )]
+    #[case::already_running(
+        StartError::AlreadyRunning { key: "focus".to_owned(), remaining: Duration::from_secs(90) },
+        "the countdown \"focus\" is already running with 1m 30s left; wait for it to end or cancel it first",
+    )]
+    #[case::empty_sequence(StartError::EmptySequence, "a sequence needs at least one segment")]
+    #[case::invalid_segment(
+        StartError::InvalidSegment { index: 1, label: "break".to_owned(), source: InvalidDuration::ZeroDuration },
+        "segment 2 of the sequence, \"break\", is invalid: the duration cannot be zero; it must be from one period to 24h",
+    )]
+    #[case::invalid_segment_with_an_injected_label(
+        StartError::InvalidSegment { index: 0, label: "\u{1b}[2Jbreak\u{202e}".to_owned(), source: InvalidDuration::ZeroDuration },
+        "segment 1 of the sequence, \"break\", is invalid: the duration cannot be zero; it must be from one period to 24h",
+    )]
+    #[case::already_running_with_a_long_key(
+        StartError::AlreadyRunning { key: "x".repeat(100), remaining: Duration::from_secs(90) },
+        &format!("the countdown \"{}…\" is already running with 1m 30s left; wait for it to end or cancel it first", "x".repeat(31)),
+    )]
+    fn should_describe_a_start_error_to_a_person(#[case] error: StartError, #[case] expected: &str) {
+        assert_eq!(error.user_message(), expected);
+    }
+
+    #[rstest]
+    #[case::unacknowledged(
+        TaskError::Failed(ChannelError::Timeout(Duration::from_secs(1)).into()),
+        "the countdown stopped: its output did not take the last value within 1s; it may be stuck",
+    )]
+    #[case::failed(
+        TaskError::Failed(ChannelError::Closed(CloseReason::Failed(FailureCode::Unacknowledged)).into()),
+        "the countdown stopped: the countdown has already failed: the receiver did not acknowledge the last value; start a new one",
+    )]
+    #[case::panicked(
+        TaskError::Panicked("index out of bounds".to_owned()),
+        "the countdown panicked: index out of bounds; this is a bug in tomatillo",
+    )]
+    #[case::aborted(TaskError::Aborted, "the countdown was cancelled before it finished")]
+    fn should_describe_a_task_error_to_a_person(#[case] error: TaskError, #[case] expected: &str) {
+        assert_eq!(error.user_message(), expected);
+    }
+}
@@ -0,0 +1,283 @@
+use std::time::Duration;
+
+use thiserror::Error;
+
+use super::display::DisplaySeconds;
+
+/// The shortest supported period. Below it, ticks are lost to scheduling jitter faster than a consumer can keep up.
+pub(crate) const MIN_PERIOD: Duration = Duration::from_millis(10);
+/// The longest supported period.
+pub(crate) const MAX_PERIOD: Duration = Duration::from_secs(60 * 60);
+/// The longest supported countdown, including any time added to it once running.
+pub(crate) const MAX_DURATION: Duration = Duration::from_secs(24 * 60 * 60);
+
+/// An error raised when a countdown cannot be created.
+///
+/// # Examples
+///
+/// ```
+/// use libtomatillo::countdown::{InvalidCountdown, TimerError};
+///
+/// let error = TimerError::from(InvalidCountdown::ZeroInterval);
+///
+/// assert_eq!(error.to_string(), "Interval cannot be zero");
+/// ```
+#[derive(Debug, Error, Clone, PartialEq)]
+pub enum TimerError {
+    #[error(transparent)]
+    InvalidCountdown(#[from] InvalidCountdown),
+    #[error("Invalid maximum duration: {0}")]
+    InvalidMaxDuration(#[source] InvalidDuration),
+    #[error("Time scale {0} must be finite and greater than zero")]
+    InvalidTimeScale(f64),
+    #[error("Invalid period at time scale {scale}: {source}")]
+    InvalidScaledPeriod { scale: f64, #[source] source: InvalidCountdown },
+    #[error("Option {option} cannot be combined with {conflicts_with}")]
+    IncompatibleOptions { option: &'static str, conflicts_with: &'static str },
+}
+
+/// An error raised when a countdown cannot be started.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+///
+/// use libtomatillo::countdown::StartError;
+///
+/// let error = StartError::AlreadyRunning { key: "focus".to_owned(), remaining: Duration::from_secs(90) };
+///
+/// assert_eq!(error.to_string(), "Countdown \"focus\" is already running with 90s left");
+/// ```
+#[derive(Debug, Error, Clone, PartialEq)]
+pub enum StartError {
+    #[error(transparent)]
+    InvalidDuration(#[from] InvalidDuration),
+    #[error("Countdown {key:?} is already running with {remaining:?} left")]
+    AlreadyRunning { key: String, remaining: Duration },
+    #[error("A sequence needs at least one segment")]
+    EmptySequence,
+    #[error("Segment {index} ({label:?}) is invalid: {source}")]
+    InvalidSegment { index: usize, label: String, #[source] source: InvalidDuration },
+}
+
+/// The reason a countdown period was rejected.
+///
+/// # Examples
+///
+/// ```
+/// use libtomatillo::countdown::InvalidCountdown;
+///
+/// assert_eq!(InvalidCountdown::ZeroInterval.to_string(), "Interval cannot be zero");
+/// ```
+#[derive(Debug, Error, Clone, PartialEq)]
+pub enum InvalidCountdown {
+    #[error("Interval cannot be zero")]
+    ZeroInterval,
+    #[error("Interval {0:?} cannot be smaller than 10ms")]
+    IntervalSmallerThanMinimum(Duration),
+    #[error("Interval {0:?} cannot be greater than one hour")]
+    IntervalGreaterThanOneHour(Duration),
+    #[error("Channel timeout {timeout:?} must be longer than interval {period:?}")]
+    TimeoutNotLongerThanInterval { timeout: Duration, period: Duration },
+}
+
+/// The reason a countdown duration was rejected.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+///
+/// use libtomatillo::countdown::InvalidDuration;
+///
+/// assert_eq!(InvalidDuration::ZeroDuration.to_string(), "Duration cannot be zero");
+/// ```
+#[derive(Debug, Error, Clone, PartialEq)]
+pub enum InvalidDuration {
+    #[error("Duration cannot be zero")]
+    ZeroDuration,
+    #[error("Duration {} cannot be greater than one day", shown(.0))]
+    DurationGreaterThanOneDay(Duration),
+    #[error("Duration {duration:?} cannot be smaller than period {period:?}")]
+    DurationSmallerThanPeriod{duration: Duration, period: Duration},
+    #[error("Duration {} cannot be greater than the maximum {}", shown(duration), shown(maximum))]
+    DurationGreaterThanMaximum{duration: Duration, maximum: Duration},
+    #[error("Remaining time {remaining:?} cannot be greater than duration {duration:?}")]
+    RemainingGreaterThanDuration{remaining: Duration, duration: Duration},
+    #[error("A count-up without a cap cannot repeat")]
+    UncappedRepeat,
+    #[error("Deadline passed {} ago", shown(.0))]
+    DeadlineInPast(Duration),
+}
+
+/// Checks that a countdown can tick every `period`.
+pub(crate) fn validate_period(period: Duration) -> Result<(), InvalidCountdown> {
+    if period.is_zero() {
+        return Err(InvalidCountdown::ZeroInterval);
+    }
+
+    if period < MIN_PERIOD {
+        return Err(InvalidCountdown::IntervalSmallerThanMinimum(period));
+    }
+
+    if period > MAX_PERIOD {
+        return Err(InvalidCountdown::IntervalGreaterThanOneHour(period));
+    }
+
+    Ok(())
+}
+
+/// Checks that a countdown ticking every `period` can count down from `duration`.
+pub(crate) fn validate_duration(duration: Duration, period: Duration) -> Result<(), InvalidDuration> {
+    if duration.is_zero() {
+        return Err(InvalidDuration::ZeroDuration);
+    }
+
+    validate_within_one_day(duration)?;
+
+    if period > duration {
+        return Err(InvalidDuration::DurationSmallerThanPeriod { duration, period });
+    }
+
+    Ok(())
+}
+
+/// Checks that a countdown can be resumed with `remaining` of its `duration` left.
+pub(crate) fn validate_remaining(remaining: Duration, duration: Duration) -> Result<(), InvalidDuration> {
+    if remaining.is_zero() {
+        return Err(InvalidDuration::ZeroDuration);
+    }
+
+    if remaining > duration {
+        return Err(InvalidDuration::RemainingGreaterThanDuration { remaining, duration });
+    }
+
+    Ok(())
+}
+
+/// Checks that `scale` is finite and greater than zero, and that `period` scaled by it is still a valid period.
+///
+/// # Returns
+///
+/// The period a timer running `scale` times faster than real time really ticks at: `period` divided by `scale`.
+pub(crate) fn validate_time_scale(scale: f64, period: Duration) -> Result<Duration, TimerError> {
+    if !scale.is_finite() || scale <= 0.0 {
+        return Err(TimerError::InvalidTimeScale(scale));
+    }
+
+    let scaled = Duration::try_from_secs_f64(period.as_secs_f64() / scale).unwrap_or(Duration::MAX);
+    validate_period(scaled).map_err(|source| TimerError::InvalidScaledPeriod { scale, source })?;
+    Ok(scaled)
+}
+
+/// Checks that a receiver waiting on a countdown ticking every `period` would not time out before the next tick.
+pub(crate) fn validate_timeout(timeout: Duration, period: Duration) -> Result<(), InvalidCountdown> {
+    if timeout <= period {
+        return Err(InvalidCountdown::TimeoutNotLongerThanInterval { timeout, period });
+    }
+
+    Ok(())
+}
+
+/// Checks that `duration` is no longer than `maximum`, if one was set.
+pub(crate) fn validate_within_maximum(duration: Duration, maximum: Option<Duration>) -> Result<(), InvalidDuration> {
+    match maximum {
+        Some(maximum) if duration > maximum => Err(InvalidDuration::DurationGreaterThanMaximum { duration, maximum }),
+        _ => Ok(()),
+    }
+}
+
+/// Checks that `duration` is no longer than the longest countdown supported, one day.
+pub(crate) fn validate_within_one_day(duration: Duration) -> Result<(), InvalidDuration> {
+    if duration > MAX_DURATION {
+        return Err(InvalidDuration::DurationGreaterThanOneDay(duration));
+    }
+
+    Ok(())
+}
+
+/// Returns `duration` as a countdown would show it.
+fn shown(duration: &Duration) -> DisplaySeconds {
+    DisplaySeconds::from_millis(u64::try_from(duration.as_millis()).unwrap_or(u64::MAX))
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case::zero(0, Err(InvalidCountdown::ZeroInterval))]
+    #[case::below_the_minimum(9, Err(InvalidCountdown::IntervalSmallerThanMinimum(Duration::from_millis(9))))]
+    #[case::the_minimum(10, Ok(()))]
+    #[case::one_hour(3_600_000, Ok(()))]
+    #[case::over_one_hour(3_600_001, Err(InvalidCountdown::IntervalGreaterThanOneHour(Duration::from_millis(3_600_001))))]
+    fn should_validate_the_period(#[case] period_millis: u64, #[case] expected: Result<(), InvalidCountdown>) {
+        assert_eq!(validate_period(Duration::from_millis(period_millis)), expected);
+    }
+
+    #[rstest]
+    #[case::zero(Duration::ZERO, Err(InvalidDuration::ZeroDuration))]
+    #[case::smaller_than_the_period(Duration::from_millis(99), Err(InvalidDuration::DurationSmallerThanPeriod { duration: Duration::from_millis(99), period: Duration::from_millis(100) }))]
+    #[case::one_period(Duration::from_millis(100), Ok(()))]
+    #[case::several_periods(Duration::from_millis(1000), Ok(()))]
+    fn should_validate_the_duration_against_the_period(#[case] duration: Duration, #[case] expected: Result<(), InvalidDuration>) {
+        assert_eq!(validate_duration(duration, Duration::from_millis(100)), expected);
+    }
+
+    #[rstest]
+    #[case::two_minutes(Duration::from_secs(2 * 60), Ok(()))]
+    #[case::twenty_five_minutes(Duration::from_secs(25 * 60), Ok(()))]
+    #[case::one_minute_short_of_one_day(Duration::from_secs(23 * 60 * 60 + 59 * 60), Ok(()))]
+    #[case::one_day(Duration::from_secs(24 * 60 * 60), Ok(()))]
+    #[case::one_day_and_one_millisecond(Duration::from_millis(24 * 60 * 60 * 1000 + 1), Err(InvalidDuration::DurationGreaterThanOneDay(Duration::from_millis(24 * 60 * 60 * 1000 + 1))))]
+    fn should_validate_the_duration_within_one_day(#[case] duration: Duration, #[case] expected: Result<(), InvalidDuration>) {
+        assert_eq!(validate_duration(duration, Duration::from_secs(1)), expected);
+    }
+
+    #[rstest]
+    #[case::over_one_day(InvalidDuration::DurationGreaterThanOneDay(Duration::from_millis(24 * 60 * 60 * 1000 + 1)), "Duration 24:00:01 cannot be greater than one day")]
+    #[case::over_the_maximum(InvalidDuration::DurationGreaterThanMaximum { duration: Duration::from_secs(61), maximum: Duration::from_secs(60) }, "Duration 01:01 cannot be greater than the maximum 01:00")]
+    fn should_describe_a_duration_that_is_too_long_as_a_countdown_shows_it(#[case] error: InvalidDuration, #[case] expected: &str) {
+        assert_eq!(error.to_string(), expected);
+    }
+
+    #[rstest]
+    #[case::zero(Duration::ZERO, Err(InvalidDuration::ZeroDuration))]
+    #[case::less_than_a_period(Duration::from_millis(50), Ok(()))]
+    #[case::the_whole_duration(Duration::from_millis(1000), Ok(()))]
+    #[case::more_than_the_duration(Duration::from_millis(1001), Err(InvalidDuration::RemainingGreaterThanDuration { remaining: Duration::from_millis(1001), duration: Duration::from_millis(1000) }))]
+    fn should_validate_the_remaining_time_against_the_duration(#[case] remaining: Duration, #[case] expected: Result<(), InvalidDuration>) {
+        assert_eq!(validate_remaining(remaining, Duration::from_millis(1000)), expected);
+    }
+
+    #[rstest]
+    #[case::shorter_than_the_period(Duration::from_millis(50), Err(InvalidCountdown::TimeoutNotLongerThanInterval { timeout: Duration::from_millis(50), period: Duration::from_millis(100) }))]
+    #[case::one_period(Duration::from_millis(100), Err(InvalidCountdown::TimeoutNotLongerThanInterval { timeout: Duration::from_millis(100), period: Duration::from_millis(100) }))]
+    #[case::longer_than_the_period(Duration::from_millis(101), Ok(()))]
+    fn should_validate_the_timeout_against_the_period(#[case] timeout: Duration, #[case] expected: Result<(), InvalidCountdown>) {
+        assert_eq!(validate_timeout(timeout, Duration::from_millis(100)), expected);
+    }
+
+    #[rstest]
+    #[case::no_maximum(Duration::from_millis(1001), None, Ok(()))]
+    #[case::the_maximum(Duration::from_millis(1000), Some(Duration::from_millis(1000)), Ok(()))]
+    #[case::over_the_maximum(Duration::from_millis(1001), Some(Duration::from_millis(1000)), Err(InvalidDuration::DurationGreaterThanMaximum { duration: Duration::from_millis(1001), maximum: Duration::from_millis(1000) }))]
+    fn should_validate_the_duration_against_the_maximum(#[case] duration: Duration, #[case] maximum: Option<Duration>, #[case] expected: Result<(), InvalidDuration>) {
+        assert_eq!(validate_within_maximum(duration, maximum), expected);
+    }
+
+    #[rstest]
+    #[case::faster(10.0, Ok(Duration::from_millis(100)))]
+    #[case::slower(0.5, Ok(Duration::from_secs(2)))]
+    #[case::zero(0.0, Err(TimerError::InvalidTimeScale(0.0)))]
+    #[case::negative(-1.0, Err(TimerError::InvalidTimeScale(-1.0)))]
+    #[case::infinite(f64::INFINITY, Err(TimerError::InvalidTimeScale(f64::INFINITY)))]
+    #[case::too_fast(1000.0, Err(TimerError::InvalidScaledPeriod { scale: 1000.0, source: InvalidCountdown::IntervalSmallerThanMinimum(Duration::from_millis(1)) }))]
+    #[case::too_slow(1.0 / 4096.0, Err(TimerError::InvalidScaledPeriod { scale: 1.0 / 4096.0, source: InvalidCountdown::IntervalGreaterThanOneHour(Duration::from_secs(4096)) }))]
+    fn should_validate_the_time_scale_against_the_period(#[case] scale: f64, #[case] expected: Result<Duration, TimerError>) {
+        assert_eq!(validate_time_scale(scale, Duration::from_secs(1)), expected);
+    }
+}
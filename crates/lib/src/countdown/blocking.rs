@@ -0,0 +1,367 @@
+//! A countdown for consumers without an async runtime, which counts down on its own thread and delivers its values
+//! through a blocking receive.
+
+use std::{
+    cell::Cell,
+    sync::mpsc::{self, RecvTimeoutError},
+    thread,
+    time::{Duration, Instant},
+};
+
+use crate::core::validation;
+
+use super::{ChannelError, CloseReason, FailureCode, InvalidDuration, Response, Result, StartError, TimerError};
+
+/// How late a thread can wake up for a tick and still count as on time: sleeping overshoots by a little every time.
+const WAKE_RESOLUTION: Duration = Duration::from_millis(1);
+
+/// A countdown that counts down from a specified duration on a thread of its own, with no async runtime.
+///
+/// It follows the same protocol as `AsyncCountdown`: the receiver gets the full duration straight away, then what is
+/// left of it each period, then zero when it runs out, then [`Response::Closed`]. It accepts the same periods and
+/// durations, but has none of `AsyncCountdown`'s options.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+///
+/// use libtomatillo::countdown::{blocking::BlockingCountdown, CloseReason, Response};
+///
+/// let timer = BlockingCountdown::try_new(Duration::from_millis(10)).expect("should have created the timer");
+/// let rx = timer.start(Duration::from_millis(30)).expect("should have started the countdown");
+///
+/// let mut values = Vec::new();
+/// while let Response::Value(left) = rx.recv() {
+///     values.push(left);
+/// }
+///
+/// assert_eq!(values.last(), Some(&Duration::ZERO));
+/// assert_eq!(rx.recv(), Response::Closed(CloseReason::Completed));
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct BlockingCountdown {
+    period: Duration,
+}
+
+/// Receives the values of a countdown started by [`BlockingCountdown::start`], blocking until each arrives.
+///
+/// Every value is delivered, in the order it was sent. Dropping the receiver stops the countdown at its next tick.
+#[derive(Debug)]
+pub struct BlockingReceiver {
+    rx: mpsc::Receiver<Response<Duration>>,
+    closed: Cell<Option<CloseReason>>,
+}
+
+impl BlockingCountdown {
+    /// Creates a new blocking countdown timer.
+    ///
+    /// # Arguments
+    ///
+    /// * `period` - The interval at which the timer should be updated.
+    ///
+    /// # Returns
+    ///
+    /// A [`Result`] that is:
+    ///
+    /// * `Ok(timer)` - The countdown timer has been created.
+    /// * `Err(err)` - The countdown timer could not be created, for the same reasons as `AsyncCountdown::try_new`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use libtomatillo::countdown::blocking::BlockingCountdown;
+    ///
+    /// assert!(BlockingCountdown::try_new(Duration::from_secs(1)).is_ok());
+    /// assert!(BlockingCountdown::try_new(Duration::ZERO).is_err());
+    /// ```
+    pub fn try_new(period: Duration) -> Result<Self> {
+        validation::validate_period(period).map_err(TimerError::from)?;
+
+        Ok(Self { period })
+    }
+
+    /// Creates a new blocking countdown timer ticking every `period_millis` milliseconds, like
+    /// [`BlockingCountdown::try_new`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use libtomatillo::countdown::blocking::BlockingCountdown;
+    ///
+    /// let timer = BlockingCountdown::try_from_millis(250).expect("should have created the timer");
+    ///
+    /// assert!(timer.validate(Duration::from_millis(200)).is_err());
+    /// ```
+    pub fn try_from_millis(period_millis: u64) -> Result<Self> {
+        Self::try_new(Duration::from_millis(period_millis))
+    }
+
+    /// Checks that a countdown of `duration` could be started, without starting it.
+    ///
+    /// # Returns
+    ///
+    /// A [`std::result::Result`] that is:
+    ///
+    /// * `Ok(())` - [`BlockingCountdown::start`] would accept `duration`.
+    /// * `Err(err)` - The reason [`BlockingCountdown::start`] would reject `duration`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use libtomatillo::countdown::{blocking::BlockingCountdown, InvalidDuration};
+    ///
+    /// let timer = BlockingCountdown::try_new(Duration::from_secs(1)).expect("should have created the timer");
+    ///
+    /// assert_eq!(timer.validate(Duration::from_secs(5)), Ok(()));
+    /// assert_eq!(timer.validate(Duration::ZERO), Err(InvalidDuration::ZeroDuration));
+    /// ```
+    pub fn validate(&self, duration: Duration) -> std::result::Result<(), InvalidDuration> {
+        validation::validate_duration(duration, self.period)
+    }
+
+    /// Starts the countdown on a thread of its own.
+    ///
+    /// # Arguments
+    ///
+    /// * `duration` - The duration of the countdown, to the millisecond.
+    ///
+    /// # Returns
+    ///
+    /// A [`std::result::Result`] that is:
+    ///
+    /// * `Ok(receiver)` - The countdown has started: a [`BlockingReceiver`] of the time remaining.
+    /// * `Err(err)` - The countdown could not be started, see [`BlockingCountdown::validate`].
+    pub fn start(&self, duration: Duration) -> std::result::Result<BlockingReceiver, StartError> {
+        self.validate(duration)?;
+
+        let (tx, rx) = mpsc::channel();
+        let period = self.period;
+        // A valid duration is at most a day, so its milliseconds always fit.
+        let duration_millis = duration.as_millis() as u64;
+        thread::spawn(move || count_down(&tx, duration_millis, period, &SystemClock));
+
+        Ok(BlockingReceiver { rx, closed: Cell::new(None) })
+    }
+}
+
+impl BlockingReceiver {
+    /// Blocks until the next value arrives.
+    ///
+    /// # Returns
+    ///
+    /// * `Response::Value(left)` - The next value sent: the time left.
+    /// * `Response::Closed(reason)` - The countdown has ended, and no more values will follow: every receive from then
+    ///   on returns it again. A countdown whose thread panicked closes with [`FailureCode::Panicked`].
+    pub fn recv(&self) -> Response<Duration> {
+        if let Some(reason) = self.closed.get() {
+            return Response::Closed(reason);
+        }
+
+        self.received(self.rx.recv().ok())
+    }
+
+    /// Blocks until the next value arrives, like [`BlockingReceiver::recv`], for at most `timeout`.
+    ///
+    /// # Returns
+    ///
+    /// A [`Result`] that is:
+    ///
+    /// * `Ok(response)` - The response [`BlockingReceiver::recv`] would have returned.
+    /// * `Err(err)` - Nothing arrived within `timeout`.
+    pub fn recv_timeout(&self, timeout: Duration) -> Result<Response<Duration>> {
+        if let Some(reason) = self.closed.get() {
+            return Ok(Response::Closed(reason));
+        }
+
+        match self.rx.recv_timeout(timeout) {
+            Ok(response) => Ok(self.received(Some(response))),
+            Err(RecvTimeoutError::Disconnected) => Ok(self.received(None)),
+            Err(RecvTimeoutError::Timeout) => Err(ChannelError::Timeout(timeout).into()),
+        }
+    }
+
+    fn received(&self, response: Option<Response<Duration>>) -> Response<Duration> {
+        // The thread only hangs up without closing when it panics.
+        let response = response.unwrap_or(Response::Closed(CloseReason::Failed(FailureCode::Panicked)));
+        if let Response::Closed(reason) = response {
+            self.closed.set(Some(reason));
+        }
+
+        response
+    }
+}
+
+/// Where [`count_down`] reads the time and sleeps, so its schedule can be tested without time passing.
+trait Clock {
+    fn now(&self) -> Instant;
+    fn sleep(&self, duration: Duration);
+}
+
+/// The clock of the system, that a countdown thread really sleeps on.
+struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+
+    fn sleep(&self, duration: Duration) {
+        thread::sleep(duration);
+    }
+}
+
+/// Sends the full `duration`, in milliseconds, straight away, what is left of it every `period`, then zero when it runs
+/// out, skipping any ticks missed while the thread was not running. Stops if the receiver is dropped.
+fn count_down(tx: &mpsc::Sender<Response<Duration>>, duration: u64, period: Duration, clock: &impl Clock) {
+    let started = clock.now();
+    let end = started + Duration::from_millis(duration);
+    let mut value = duration;
+    let mut due = started;
+
+    while value > 0 {
+        if tx.send(Response::Value(Duration::from_millis(value))).is_err() {
+            return;
+        }
+
+        due = (due + period).min(end);
+        clock.sleep(due.saturating_duration_since(clock.now()));
+
+        // Send the time really left after waking up late, and carry on from the next tick due.
+        let now = clock.now();
+        let at = if now.saturating_duration_since(due) > WAKE_RESOLUTION { now } else { due };
+        value = duration.saturating_sub(at.saturating_duration_since(started).as_millis() as u64);
+        while due + period <= at {
+            due += period;
+        }
+    }
+
+    if tx.send(Response::Value(Duration::ZERO)).is_ok() {
+        let _ = tx.send(Response::Closed(CloseReason::Completed));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::RefCell, collections::VecDeque};
+
+    use rstest::rstest;
+
+    use crate::countdown::InvalidCountdown;
+
+    use super::*;
+
+    /// A clock that sleeps without time passing, waking up late by each of the scripted delays in turn.
+    struct FakeClock {
+        now: Cell<Instant>,
+        late_by: RefCell<VecDeque<Duration>>,
+    }
+
+    impl FakeClock {
+        fn new(late_by: impl IntoIterator<Item = u64>) -> Self {
+            Self { now: Cell::new(Instant::now()), late_by: RefCell::new(late_by.into_iter().map(Duration::from_millis).collect()) }
+        }
+    }
+
+    impl Clock for FakeClock {
+        fn now(&self) -> Instant {
+            self.now.get()
+        }
+
+        fn sleep(&self, duration: Duration) {
+            let late_by = self.late_by.borrow_mut().pop_front().unwrap_or_default();
+            self.now.set(self.now.get() + duration + late_by);
+        }
+    }
+
+    #[rstest]
+    #[case::on_time([], &[450, 350, 250, 150, 50, 0])]
+    #[case::woken_up_within_the_resolution([1, 1], &[450, 350, 250, 150, 50, 0])]
+    #[case::woken_up_late([0, 30], &[450, 350, 220, 150, 50, 0])]
+    #[case::missing_ticks([250], &[450, 100, 50, 0])]
+    fn should_send_the_time_really_left_at_each_tick<const N: usize>(#[case] late_by: [u64; N], #[case] expected: &[u64]) {
+        let (tx, rx) = mpsc::channel();
+
+        count_down(&tx, 450, Duration::from_millis(100), &FakeClock::new(late_by));
+
+        let mut expected = expected.iter().copied().map(|millis_left| Response::Value(Duration::from_millis(millis_left))).collect::<Vec<_>>();
+        expected.push(Response::Closed(CloseReason::Completed));
+        assert_eq!(rx.try_iter().collect::<Vec<_>>(), expected);
+    }
+
+    #[test]
+    fn should_deliver_every_value_of_a_countdown_to_a_background_thread() {
+        let timer = BlockingCountdown::try_new(Duration::from_millis(100)).expect("should have created countdown");
+        let rx = timer.start(Duration::from_millis(450)).expect("unexpected countdown failure");
+
+        let responses = thread::spawn(move || {
+            let mut responses = Vec::new();
+            loop {
+                let response = rx.recv();
+                let closed = matches!(response, Response::Closed(_));
+                responses.push(response);
+                if closed {
+                    break responses;
+                }
+            }
+        }).join().expect("the receiving thread should not have panicked");
+
+        assert_eq!(responses.last(), Some(&Response::Closed(CloseReason::Completed)));
+        let values = responses.iter()
+            .filter_map(|response| if let Response::Value(left) = response { Some(*left) } else { None })
+            .collect::<Vec<_>>();
+        // How many ticks a loaded machine misses varies, but the values always count down from the full duration.
+        assert_eq!(values.first(), Some(&Duration::from_millis(450)));
+        assert_eq!(values.last(), Some(&Duration::ZERO));
+        assert!(values.is_sorted_by(|earlier, later| earlier > later), "expected the values to decrease, but got {values:?}");
+    }
+
+    #[test]
+    fn should_keep_returning_closed_once_the_countdown_has_ended() {
+        let timer = BlockingCountdown::try_new(Duration::from_millis(10)).expect("should have created countdown");
+        let rx = timer.start(Duration::from_millis(10)).expect("unexpected countdown failure");
+
+        while let Response::Value(_) = rx.recv() {}
+
+        assert_eq!(rx.recv(), Response::Closed(CloseReason::Completed));
+        assert_eq!(rx.recv_timeout(Duration::from_millis(10)), Ok(Response::Closed(CloseReason::Completed)));
+    }
+
+    #[test]
+    fn should_time_out_waiting_for_a_value_that_is_not_due_yet() {
+        let timer = BlockingCountdown::try_new(Duration::from_secs(1)).expect("should have created countdown");
+        let rx = timer.start(Duration::from_secs(2)).expect("unexpected countdown failure");
+        assert_eq!(rx.recv(), Response::Value(Duration::from_secs(2)));
+
+        let timeout = Duration::from_millis(10);
+
+        assert_eq!(rx.recv_timeout(timeout), Err(ChannelError::Timeout(timeout).into()));
+    }
+
+    #[rstest]
+    #[case::zero(0, InvalidCountdown::ZeroInterval)]
+    #[case::below_the_minimum(9, InvalidCountdown::IntervalSmallerThanMinimum(Duration::from_millis(9)))]
+    #[case::over_one_hour(60 * 60 * 1000 + 1, InvalidCountdown::IntervalGreaterThanOneHour(Duration::from_millis(60 * 60 * 1000 + 1)))]
+    fn should_fail_to_create_a_countdown_given_an_invalid_period(#[case] period_millis: u64, #[case] expected: InvalidCountdown) {
+        assert_eq!(BlockingCountdown::try_from_millis(period_millis).map(drop), Err(TimerError::from(expected).into()));
+    }
+
+    #[rstest]
+    #[case::zero(0, InvalidDuration::ZeroDuration)]
+    #[case::within_the_period(50, InvalidDuration::DurationSmallerThanPeriod {
+        duration: Duration::from_millis(50),
+        period: Duration::from_millis(100),
+    })]
+    #[case::over_one_day(24 * 60 * 60 * 1000 + 1, InvalidDuration::DurationGreaterThanOneDay(Duration::from_millis(24 * 60 * 60 * 1000 + 1)))]
+    fn should_fail_to_start_a_countdown_given_an_invalid_duration(#[case] duration_millis: u64, #[case] expected: InvalidDuration) {
+        let timer = BlockingCountdown::try_new(Duration::from_millis(100)).expect("should have created countdown");
+
+        assert_eq!(timer.start(Duration::from_millis(duration_millis)).map(drop), Err(expected.into()));
+    }
+}
@@ -1,49 +1,175 @@
-use std::sync::Arc;
+use std::{collections::VecDeque, fmt::{self, Display}, sync::{self, Arc}};
 
-use tokio::{sync::{watch::{self}, Mutex, RwLock}, time::{self, Duration}};
+use tokio::{sync::{watch::{self}, Mutex}, task::AbortHandle, time::{self, Duration, Instant}};
 
-use thiserror::Error;
+use crate::{core::validation, countdown::Result};
 
-use crate::countdown::Result;
+use super::{ChannelError, CloseReason, CountdownError, FailureCode, Progress, Receiver, Response, Sender, Violation};
 
-use super::{CountdownError, Receiver, Response, Sender};
-
-const DEFAULT_TIMEOUT_MS: u32 = 1000;
-const DEFAULT_PERIOD_MS: u16 = 100;
-const DEFAULT_ACK_POLL_MS: u8 = 10;
+pub(super) const DEFAULT_TIMEOUT_MS: u32 = 1000;
+const DEFAULT_LAG_WINDOW: u16 = 10;
+const DEFAULT_LAG_THRESHOLD_PERCENT: u8 = 50;
 
 type ChanResult<T> = std::result::Result<T, ChannelError>;
 
 trait AwaitWithTimeout<T> {
-    fn await_with_timeout(&mut self, timeout: Duration, retry_period: Duration) -> impl Future<Output = ChanResult<T>>;
+    /// Waits for a changed value, or for the channel to close, failing once `timeout` elapses. With no timeout, waits
+    /// for as long as it takes.
+    fn await_with_timeout(&mut self, timeout: Option<Duration>, closed: watch::Receiver<Option<CloseReason>>) -> impl Future<Output = ChanResult<Option<T>>>;
+}
+
+/// Raised once by a [`ChannelReceiver`] whose consumer skips too many values, typically because it takes longer to
+/// handle a value than the countdown period.
+///
+/// # Examples
+///
+/// ```
+/// use libtomatillo::countdown::LagWarning;
+///
+/// let warning = LagWarning { skipped: 30, window: 10 };
+///
+/// assert_eq!(warning.to_string(), "skipped 30 of the last 40 values, consider a longer countdown period");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct LagWarning {
+    /// The number of values skipped over the window.
+    pub skipped: u64,
+    /// The number of values delivered that the skip ratio was measured over.
+    pub window: u16,
 }
 
-#[derive(Debug, Error, PartialEq)]
-pub enum ChannelError {
-    #[error("timed out after {0:?} waiting for update")] 
-    Timeout(Duration),
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Sequenced<T> {
+    seq: u64,
+    cycle: u32,
+    value: T,
 }
 
 #[derive(Debug)]
-pub(super) struct Channel<T: Copy> {
-    tx: Arc<Mutex<watch::Sender<T>>>,
-    rx: Arc<Mutex<watch::Receiver<T>>>,
-    ack_tx: Arc<Mutex<watch::Sender<bool>>>,
-    ack_rx: Arc<Mutex<watch::Receiver<bool>>>,
+struct Delivery {
+    last_value_at: Instant,
+    next_seq: u64,
+    cycle: u32,
+    skipped_total: u64,
+    recent_skips: VecDeque<u64>,
+    lag_warned: bool,
+    lag_warning: Option<LagWarning>,
+}
 
-    closed: Arc<RwLock<bool>>,
+/// The time added to a running countdown by [`ChannelReceiver::add`], shared with the task sending its values.
+#[derive(Debug, Default)]
+pub(super) struct AddedTime(sync::Mutex<Added>);
 
-    timeout_ms: u32,
-    retry_period_ms: u16,
-    ack_poll_ms: u8,
+#[derive(Debug, Default)]
+struct Added {
+    millis: u64,
+    finished: bool,
 }
 
 #[derive(Debug)]
+pub(super) struct Channel<T: Copy> {
+    tx: Arc<Mutex<watch::Sender<Sequenced<T>>>>,
+    rx: Arc<Mutex<watch::Receiver<Sequenced<T>>>>,
+    ack_tx: Arc<Mutex<watch::Sender<Option<u64>>>>,
+    ack_rx: Arc<Mutex<watch::Receiver<Option<u64>>>>,
+
+    closed: watch::Sender<Option<CloseReason>>,
+    delivery: sync::Mutex<Delivery>,
+    producer: sync::Mutex<Option<AbortHandle>>,
+    total: sync::Mutex<T>,
+    added: Arc<AddedTime>,
+    max_duration: Option<Duration>,
+    labels: Option<Arc<[String]>>,
+
+    timeout_ms: u32,
+    lag_window: u16,
+    lag_threshold_percent: u8,
+    ack_strategy: AckStrategy,
+}
+
+/// How a channel's receiver acknowledges the values it receives, and so how its sender closes it.
+///
+/// Either way, a value that has not been received when the channel closes is still delivered before
+/// [`Response::Closed`], unless the countdown was cancelled.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum AckStrategy {
+    /// The receiver acknowledges every value, and the sender waits for the last one to be acknowledged before closing.
+    #[default]
+    Handshake,
+    /// The receiver acknowledges nothing, and the sender closes straight away.
+    FireAndForget,
+}
+
+#[derive(Debug, Clone)]
 pub struct ChannelSender<T: Copy>(Arc<Channel<T>>);
 
+/// The receiving half of a countdown channel, returned by [`super::Countdown::start`].
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+///
+/// use libtomatillo::countdown::{AsyncCountdown, ChannelReceiver, Countdown, Receiver, Response};
+///
+/// # #[tokio::main(flavor = "current_thread", start_paused = true)]
+/// # async fn main() {
+/// let timer = AsyncCountdown::try_new(Duration::from_millis(100)).expect("should have created the timer");
+/// let (rx, _): (ChannelReceiver<Duration>, _) = timer.start(Duration::from_millis(500)).await.expect("should have started the countdown");
+///
+/// assert_eq!(rx.recv().await, Ok(Response::Value(Duration::from_millis(500))));
+/// # }
+/// ```
 #[derive(Debug)]
 pub struct ChannelReceiver<T: Copy>(Arc<Channel<T>>);
 
+impl<T: Copy> Clone for ChannelReceiver<T> {
+    /// Returns another receiver of the same channel. Clones share one stream of values: each value is delivered to
+    /// whichever clone receives first, not to all of them.
+    fn clone(&self) -> Self {
+        Self(Arc::clone(&self.0))
+    }
+}
+
+/// A [`ChannelReceiver`] that checks each value it receives against the countdown protocol, for embedders that want to
+/// catch bugs in their own channel adapters. Returned by [`ChannelReceiver::strict`].
+///
+/// Rather than panicking, [`Receiver::recv`] fails with [`ChannelError::ProtocolViolation`] when a value is not smaller
+/// than the one before it, or arrives after [`Response::Closed`].
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+///
+/// use libtomatillo::countdown::{AsyncCountdown, Countdown, Receiver, Response};
+///
+/// # #[tokio::main(flavor = "current_thread", start_paused = true)]
+/// # async fn main() {
+/// let timer = AsyncCountdown::try_new(Duration::from_millis(100)).expect("should have created the timer");
+/// let (rx, _) = timer.start(Duration::from_millis(300)).await.expect("should have started the countdown");
+/// let rx = rx.strict();
+///
+/// while let Response::Value(_) = rx.recv().await.expect("the countdown should follow the protocol") {}
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct StrictReceiver<T: Copy> {
+    inner: ChannelReceiver<T>,
+    last: Mutex<LastDelivery<T>>,
+}
+
+#[derive(Debug)]
+struct LastDelivery<T> {
+    value: Option<Sequenced<T>>,
+    closed: bool,
+}
+
+/// Closes a channel on behalf of its sender without waiting for the receiver to acknowledge the last value.
+#[derive(Debug)]
+pub(super) struct ChannelCloser<T: Copy>(Arc<Channel<T>>);
+
 type Mutator<T> = Box<dyn FnOnce(&mut T)>;
 
 pub fn with_timeout<T: Copy>(timeout_ms: u32) -> Mutator<Channel<T>> {
@@ -52,20 +178,55 @@ pub fn with_timeout<T: Copy>(timeout_ms: u32) -> Mutator<Channel<T>> {
     })
 }
 
-pub fn with_retry_period<T: Copy>(period_ms: u16) -> Mutator<Channel<T>> {
+pub fn with_ack_strategy<T: Copy>(ack_strategy: AckStrategy) -> Mutator<Channel<T>> {
+    Box::new(move |watcher| {
+        watcher.ack_strategy = ack_strategy;
+    })
+}
+
+pub fn with_total<T: Copy + 'static>(total: T) -> Mutator<Channel<T>> {
+    Box::new(move |watcher| {
+        *watcher.total.get_mut().unwrap_or_else(sync::PoisonError::into_inner) = total;
+    })
+}
+
+pub fn with_cycles_completed<T: Copy>(cycles: u32) -> Mutator<Channel<T>> {
+    Box::new(move |watcher| {
+        if let Some(tx) = Arc::get_mut(&mut watcher.tx) {
+            tx.get_mut().send_modify(|v| v.cycle = cycles);
+        }
+        watcher.delivery.get_mut().unwrap_or_else(sync::PoisonError::into_inner).cycle = cycles;
+    })
+}
+
+pub fn with_max_duration<T: Copy>(max_duration: Duration) -> Mutator<Channel<T>> {
+    Box::new(move |watcher| {
+        watcher.max_duration = Some(max_duration);
+    })
+}
+
+pub fn with_labels<T: Copy>(labels: Arc<[String]>) -> Mutator<Channel<T>> {
+    Box::new(move |watcher| {
+        watcher.labels = Some(labels);
+    })
+}
+
+pub fn with_lag_warning<T: Copy>(window: u16, threshold_percent: u8) -> Mutator<Channel<T>> {
     Box::new(move |watcher| {
-        watcher.retry_period_ms = period_ms;
+        watcher.lag_window = window;
+        watcher.lag_threshold_percent = threshold_percent;
     })
 }
 
 impl<T: Copy + PartialEq> Channel<T> {
+    #[cfg(test)]
     pub fn new(init: T) -> (ChannelSender<T>, ChannelReceiver<T>) {
         Self::new_with_options(init, [])
     }
 
     pub fn new_with_options(init: T, mutators: impl IntoIterator<Item = Mutator<Channel<T>>>) -> (ChannelSender<T>, ChannelReceiver<T>) {
-        let (tx, mut rx) = watch::channel(init);
-        let (ack_tx, ack_rx) = watch::channel(false);
+        let (tx, mut rx) = watch::channel(Sequenced { seq: 0, cycle: 0, value: init });
+        let (ack_tx, ack_rx) = watch::channel(None);
         rx.mark_changed();
 
         let mut channel = Channel { 
@@ -75,11 +236,18 @@ impl<T: Copy + PartialEq> Channel<T> {
             ack_tx: Arc::new(Mutex::new(ack_tx)),
             ack_rx: Arc::new(Mutex::new(ack_rx)),
 
-            closed: Arc::new(RwLock::new(false)),
+            closed: watch::Sender::new(None),
+            delivery: sync::Mutex::new(Delivery::new()),
+            producer: sync::Mutex::new(None),
+            total: sync::Mutex::new(init),
+            added: Arc::default(),
+            max_duration: None,
+            labels: None,
 
             timeout_ms: DEFAULT_TIMEOUT_MS,
-            retry_period_ms: DEFAULT_PERIOD_MS,
-            ack_poll_ms: DEFAULT_ACK_POLL_MS,
+            lag_window: DEFAULT_LAG_WINDOW,
+            lag_threshold_percent: DEFAULT_LAG_THRESHOLD_PERCENT,
+            ack_strategy: AckStrategy::default(),
         };
 
         mutators.into_iter().for_each(|mutator| mutator(&mut channel));
@@ -88,95 +256,747 @@ impl<T: Copy + PartialEq> Channel<T> {
         (ChannelSender(chan.clone()), ChannelReceiver(chan))
     }
 
-    async fn read(&self) -> ChanResult<Response<T>> {
-        if self.closed.read().await.clone() {
-            return Ok(Response::Closed);
+    async fn read(&self, timeout: Option<Duration>) -> ChanResult<Response<Sequenced<T>>> {
+        // A value sent just before the channel closed is still delivered, even if the previous receive timed out
+        // while it was being sent: the closed flag is only checked once no value is pending.
+        let mut rx = self.rx.lock().await;
+        let Some(sequenced) = rx.await_with_timeout(timeout, self.closed.subscribe()).await? else {
+            return Ok(Response::Closed(self.close_reason().unwrap_or(CloseReason::Completed)));
+        };
+        // A cancelled countdown delivers nothing more, not even a value sent before it was cancelled.
+        if self.close_reason() == Some(CloseReason::Cancelled) {
+            return Ok(Response::Closed(CloseReason::Cancelled));
+        }
+        // The first value of a new cycle is left pending behind its marker, to be delivered by the next receive.
+        if sequenced.cycle != self.delivery().cycle {
+            self.delivery().cycle = sequenced.cycle;
+            rx.mark_changed();
+            return Ok(Response::CycleCompleted(sequenced.cycle));
         }
+        self.delivery().record(sequenced.seq, self.lag_window, self.lag_threshold_percent);
+
+        Ok(Response::Value(sequenced))
+    }
+
+    fn timeout(&self) -> Duration {
+        Duration::from_millis(self.timeout_ms.into())
+    }
 
-        let val = self.rx.lock().await.await_with_timeout(
-            Duration::from_millis(self.timeout_ms.into()), 
-            Duration::from_millis(self.retry_period_ms.into())
-        ).await?;
+    fn is_closed(&self) -> bool {
+        self.closed.borrow().is_some()
+    }
 
-        Ok(Response::Value(val))
+    fn close_reason(&self) -> Option<CloseReason> {
+        *self.closed.borrow()
     }
 
-    async fn ack(&self) -> ChanResult<()> {
-        // TODO: Add timeout
-        self.ack_tx.lock().await.send_replace(true);
+    /// Closes the channel for `reason`, unless it is already closed, in which case the first reason is kept.
+    fn mark_closed(&self, reason: CloseReason) {
+        self.closed.send_if_modified(|closed| {
+            if closed.is_some() {
+                return false;
+            }
+
+            *closed = Some(reason);
+            true
+        });
+    }
+
+    fn cancel(&self) {
+        self.abort_producer();
+        self.mark_closed(CloseReason::Cancelled);
+    }
+
+    fn abort_producer(&self) {
+        if let Some(producer) = self.producer.lock().unwrap_or_else(sync::PoisonError::into_inner).take() {
+            producer.abort();
+        }
+    }
+
+    async fn latest(&self) -> T {
+        self.tx.lock().await.borrow().value
+    }
+
+    fn total(&self) -> T {
+        *self.total.lock().unwrap_or_else(sync::PoisonError::into_inner)
+    }
+
+    fn delivery(&self) -> sync::MutexGuard<'_, Delivery> {
+        self.delivery.lock().unwrap_or_else(sync::PoisonError::into_inner)
+    }
+
+    fn time_since_last_value(&self) -> Duration {
+        self.delivery().last_value_at.elapsed()
+    }
+
+    /// Acknowledges the value sent as `seq`, and so every value sent before it.
+    async fn ack(&self, seq: u64) -> ChanResult<()> {
+        if self.ack_strategy == AckStrategy::FireAndForget {
+            return Ok(());
+        }
+
+        // A watch channel never waits on its receivers, so acknowledging cannot hold up the receiver.
+        self.ack_tx.lock().await.send_if_modified(|acked| {
+            let modified = acked.is_none_or(|last| last < seq);
+            if modified {
+                *acked = Some(seq);
+            }
+            modified
+        });
 
         Ok(())
     }
 
     async fn write(&self, value: T) -> Result<()> {
         let tx = self.tx.lock().await;
-        tx.send_modify(|v| *v = value);
+        tx.send_modify(|v| *v = Sequenced { seq: v.seq + 1, cycle: v.cycle, value });
         
         Ok(())
     }
 
-    async fn wait_ack(&self) -> ChanResult<()> {
-        self.ack_rx.lock().await.await_with_timeout(
-            Duration::from_millis(self.timeout_ms.into()), 
-            Duration::from_millis(self.ack_poll_ms.into())
-        ).await?;
+    async fn write_cycle(&self, value: T) {
+        let tx = self.tx.lock().await;
+        tx.send_modify(|v| *v = Sequenced { seq: v.seq + 1, cycle: v.cycle.wrapping_add(1), value });
+    }
+
+    /// Waits for the receiver to acknowledge the value sent as `seq`, or any sent after it.
+    async fn wait_ack(&self, seq: u64) -> ChanResult<()> {
+        if self.ack_strategy == AckStrategy::FireAndForget {
+            return Ok(());
+        }
+
+        let mut ack_rx = self.ack_rx.lock().await;
+        let mut closed = self.closed.subscribe();
+        let acked = async {
+            tokio::select! {
+                biased;
+                _ = ack_rx.wait_for(|acked| acked.is_some_and(|last| last >= seq)) => {},
+                _ = closed.wait_for(Option::is_some) => {},
+            }
+        };
+
+        time::timeout(self.timeout(), acked).await.map_err(|_| ChannelError::Timeout(self.timeout()))
+    }
+}
+
+impl<T: Copy + PartialEq> ChannelReceiver<T> {
+    /// Returns how long it has been since the last value was delivered by [`Receiver::recv`].
+    ///
+    /// Before the first value is received, this is measured from the creation of the channel.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use libtomatillo::countdown::{AsyncCountdown, Countdown, Receiver};
+    ///
+    /// # #[tokio::main(flavor = "current_thread", start_paused = true)]
+    /// # async fn main() {
+    /// let timer = AsyncCountdown::try_new(Duration::from_millis(100)).expect("should have created the timer");
+    /// let (rx, _) = timer.start(Duration::from_millis(500)).await.expect("should have started the countdown");
+    /// rx.recv().await.expect("should have received the first value");
+    ///
+    /// tokio::time::advance(Duration::from_millis(40)).await;
+    ///
+    /// assert_eq!(rx.time_since_last_value(), Duration::from_millis(40));
+    /// # }
+    /// ```
+    pub fn time_since_last_value(&self) -> Duration {
+        self.0.time_since_last_value()
+    }
+
+    /// Returns how long is left before a pending [`Receiver::recv`] would fail with [`ChannelError::Timeout`],
+    /// measured from the last delivered value.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(remaining)` - The time left until the timeout fires.
+    /// * `None` - The timeout has already elapsed, or the channel is closed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use libtomatillo::countdown::{AsyncCountdown, Countdown, Receiver};
+    ///
+    /// # #[tokio::main(flavor = "current_thread", start_paused = true)]
+    /// # async fn main() {
+    /// let timer = AsyncCountdown::try_new(Duration::from_millis(100)).expect("should have created the timer");
+    /// let (rx, _) = timer.start(Duration::from_millis(500)).await.expect("should have started the countdown");
+    /// rx.recv().await.expect("should have received the first value");
+    ///
+    /// tokio::time::advance(Duration::from_millis(40)).await;
+    ///
+    /// assert_eq!(rx.timeout_remaining(), Some(Duration::from_millis(960)));
+    /// # }
+    /// ```
+    pub fn timeout_remaining(&self) -> Option<Duration> {
+        if self.0.is_closed() {
+            return None;
+        }
+
+        self.0.timeout()
+            .checked_sub(self.time_since_last_value())
+            .filter(|remaining| !remaining.is_zero())
+    }
+
+    /// Returns the total number of values sent that were replaced before they could be received.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use libtomatillo::countdown::{AsyncCountdown, Countdown, Receiver};
+    ///
+    /// # #[tokio::main(flavor = "current_thread", start_paused = true)]
+    /// # async fn main() {
+    /// let timer = AsyncCountdown::try_new(Duration::from_millis(100)).expect("should have created the timer");
+    /// let (rx, _) = timer.start(Duration::from_millis(500)).await.expect("should have started the countdown");
+    /// rx.recv().await.expect("should have received the first value");
+    ///
+    /// assert_eq!(rx.skipped_total(), 0);
+    /// # }
+    /// ```
+    pub fn skipped_total(&self) -> u64 {
+        self.0.delivery().skipped_total
+    }
+
+    /// Returns how this channel acknowledges values, which [`super::AsyncCountdown`] chooses from its period.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use libtomatillo::countdown::{AckStrategy, AsyncCountdown, Countdown};
+    ///
+    /// # #[tokio::main(flavor = "current_thread", start_paused = true)]
+    /// # async fn main() {
+    /// let timer = AsyncCountdown::try_new(Duration::from_millis(1000)).expect("should have created the timer");
+    /// let (rx, _) = timer.start(Duration::from_millis(5000)).await.expect("should have started the countdown");
+    ///
+    /// assert_eq!(rx.ack_strategy(), AckStrategy::Handshake);
+    /// # }
+    /// ```
+    pub fn ack_strategy(&self) -> AckStrategy {
+        self.0.ack_strategy
+    }
+
+    /// Returns the label of the segment at `index` of the [`super::Sequence`] this channel receives, as received in
+    /// [`Response::CycleCompleted`], or `None` past the last segment or for a countdown started on its own.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use libtomatillo::countdown::{AsyncCountdown, Countdown, Sequence};
+    ///
+    /// # #[tokio::main(flavor = "current_thread", start_paused = true)]
+    /// # async fn main() {
+    /// let timer = AsyncCountdown::try_new(Duration::from_millis(100)).expect("should have created the timer");
+    /// let sequence = Sequence::try_new(timer, [("work", Duration::from_millis(200)), ("break", Duration::from_millis(100))])
+    ///     .expect("should have created the sequence");
+    /// let (rx, _) = sequence.start(Duration::from_millis(300)).await.expect("should have started the sequence");
+    ///
+    /// assert_eq!(rx.segment_label(0), Some("work"));
+    /// assert_eq!(rx.segment_label(1), Some("break"));
+    /// assert_eq!(rx.segment_label(2), None);
+    /// # }
+    /// ```
+    pub fn segment_label(&self, index: u32) -> Option<&str> {
+        let index = usize::try_from(index).ok()?;
+        self.0.labels.as_deref()?.get(index).map(String::as_str)
+    }
+
+    /// Takes the [`LagWarning`] raised when the share of skipped values over a window of recent deliveries exceeded the
+    /// threshold. The warning is raised at most once per channel, so this returns `Some` at most once.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use libtomatillo::countdown::{AsyncCountdown, Countdown, Receiver};
+    ///
+    /// # #[tokio::main(flavor = "current_thread", start_paused = true)]
+    /// # async fn main() {
+    /// let timer = AsyncCountdown::try_new(Duration::from_millis(100)).expect("should have created the timer");
+    /// let (rx, _) = timer.start(Duration::from_millis(500)).await.expect("should have started the countdown");
+    /// rx.recv().await.expect("should have received the first value");
+    ///
+    /// assert_eq!(rx.take_lag_warning(), None);
+    /// # }
+    /// ```
+    pub fn take_lag_warning(&self) -> Option<LagWarning> {
+        self.0.delivery().lag_warning.take()
+    }
+
+    /// Receives a value like [`Receiver::recv`], but waits for as long as it takes instead of failing with
+    /// [`ChannelError::Timeout`].
+    ///
+    /// Use it when the countdown runs in the same process and cannot silently die, for example to drive a display with
+    /// a coarse period: it still returns as soon as the channel closes, and dropping the future stops the wait. Prefer
+    /// [`Receiver::recv`] when the sender might stop without closing the channel, so that a stuck countdown is
+    /// noticed.
+    ///
+    /// # Returns
+    ///
+    /// A [`Result`] that is:
+    ///
+    /// * `Ok(Response::Value(value))` - A new value has been received.
+    /// * `Ok(Response::Closed(reason))` - The sender is closed for `reason` and no more values will be sent.
+    /// * `Err(err)` - The value could not be acknowledged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use libtomatillo::countdown::{AsyncCountdown, CloseReason, Countdown, Response};
+    ///
+    /// # #[tokio::main(flavor = "current_thread", start_paused = true)]
+    /// # async fn main() {
+    /// let timer = AsyncCountdown::try_new(Duration::from_millis(100)).expect("should have created the timer");
+    /// let (rx, _) = timer.start(Duration::from_millis(300)).await.expect("should have started the countdown");
+    ///
+    /// while let Response::Value(_) = rx.recv_forever().await.expect("should have received every value") {}
+    /// # }
+    /// ```
+    pub async fn recv_forever(&self) -> Result<Response<T>> {
+        Ok(match self.recv_sequenced(None).await? {
+            Response::Value(Sequenced { value, .. }) => Response::Value(value),
+            Response::CycleCompleted(cycles) => Response::CycleCompleted(cycles),
+            Response::Closed(reason) => Response::Closed(reason),
+        })
+    }
+
+    /// Cancels the countdown: the task sending its values is stopped, and every receiver observes
+    /// [`Response::Closed`] with [`CloseReason::Cancelled`] on its next, or pending, receive, without any value sent
+    /// before the cancellation.
+    ///
+    /// Cancelling a countdown that has already closed, including one that was already cancelled, does nothing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use libtomatillo::countdown::{AsyncCountdown, CloseReason, Countdown, Receiver, Response};
+    ///
+    /// # #[tokio::main(flavor = "current_thread", start_paused = true)]
+    /// # async fn main() {
+    /// let timer = AsyncCountdown::try_new(Duration::from_millis(100)).expect("should have created the timer");
+    /// let (rx, _) = timer.start(Duration::from_millis(60_000)).await.expect("should have started the countdown");
+    ///
+    /// rx.cancel();
+    ///
+    /// assert_eq!(rx.recv().await, Ok(Response::Closed(CloseReason::Cancelled)));
+    /// # }
+    /// ```
+    pub fn cancel(&self) {
+        self.0.cancel();
+    }
 
-        self.ack_tx.lock().await.send_replace(false);
+    /// Returns a [`StrictReceiver`] of this channel, which fails when it receives a value that breaks the countdown
+    /// protocol.
+    pub fn strict(self) -> StrictReceiver<T> {
+        StrictReceiver { inner: self, last: Mutex::new(LastDelivery { value: None, closed: false }) }
+    }
+
+    /// Returns the latest value sent, whether or not it has been received.
+    pub(super) async fn latest(&self) -> T {
+        self.0.latest().await
+    }
+
+    async fn recv_sequenced(&self, timeout: Option<Duration>) -> Result<Response<Sequenced<T>>> {
+        let chan = self.0.clone();
+
+        let response = chan.read(timeout).await.map_err(CountdownError::from)?;
+        if let Response::Value(Sequenced { seq, .. }) = response {
+            chan.ack(seq).await.map_err(CountdownError::from)?;
+        }
+
+        Ok(response)
+    }
+}
+
+impl ChannelReceiver<Duration> {
+    /// Adds time to the running countdown, making it `duration` longer: a countdown has that much more left from the
+    /// next tick on, and a count-up counts that much further before it closes.
+    ///
+    /// The next value is then greater than the last one, which a [`StrictReceiver`] reports as
+    /// [`Violation::Increased`].
+    ///
+    /// # Arguments
+    ///
+    /// * `duration` - The time to add, to the millisecond.
+    ///
+    /// # Returns
+    ///
+    /// A [`Result`] that is:
+    ///
+    /// * `Ok(())` - The time has been added.
+    /// * `Err(err)` - The countdown has already closed, or would be longer, including all the time added to it, than
+    ///   the maximum duration of its timer, see [`super::AsyncCountdownBuilder::max_duration`], or the longest
+    ///   countdown supported. A count-up without a cap already runs for as long as it is allowed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use libtomatillo::countdown::{AsyncCountdown, Countdown, Receiver, Response};
+    ///
+    /// # #[tokio::main(flavor = "current_thread", start_paused = true)]
+    /// # async fn main() {
+    /// let timer = AsyncCountdown::try_new(Duration::from_millis(100)).expect("should have created the timer");
+    /// let (rx, _) = timer.start(Duration::from_millis(1000)).await.expect("should have started the countdown");
+    /// assert_eq!(rx.recv().await, Ok(Response::Value(Duration::from_millis(1000))));
+    ///
+    /// rx.add(Duration::from_millis(500)).await.expect("should have added time");
+    ///
+    /// assert_eq!(rx.recv().await, Ok(Response::Value(Duration::from_millis(1400))));
+    /// # }
+    /// ```
+    pub async fn add(&self, duration: Duration) -> Result<()> {
+        let closed = |reason: Option<CloseReason>| ChannelError::Closed(reason.unwrap_or(CloseReason::Completed));
+        if let Some(reason) = self.0.close_reason() {
+            return Err(closed(Some(reason)).into());
+        }
+
+        // The latest value is the time elapsed of a count-up, so check the length of the whole countdown instead.
+        let total = self.0.total().saturating_add(self.0.added.duration()).saturating_add(duration);
+        validation::validate_within_one_day(total)?;
+        validation::validate_within_maximum(total, self.0.max_duration)?;
+
+        if !self.0.added.add(u64::try_from(duration.as_millis()).unwrap_or(u64::MAX)) {
+            return Err(closed(self.0.close_reason()).into());
+        }
 
         Ok(())
     }
+
+    /// Receives the latest value like [`Receiver::recv`], along with the duration of the countdown and how many values
+    /// were sent before it, so that a progress bar needs nothing but the receiver.
+    ///
+    /// # Returns
+    ///
+    /// A [`Result`] that is:
+    ///
+    /// * `Ok(Response::Value(progress))` - The [`Progress`] of the countdown as of the latest value.
+    /// * `Ok(Response::Closed(reason))` - The countdown has ended, and no more values will follow.
+    /// * `Err(err)` - No value arrived in time, see [`Receiver::recv`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use libtomatillo::countdown::{AsyncCountdown, Countdown, Progress, Response};
+    ///
+    /// # #[tokio::main(flavor = "current_thread", start_paused = true)]
+    /// # async fn main() {
+    /// let timer = AsyncCountdown::try_new(Duration::from_millis(100)).expect("should have created the timer");
+    /// let (rx, _) = timer.start(Duration::from_millis(400)).await.expect("should have started the countdown");
+    /// rx.recv_progress().await.expect("should have received the full duration");
+    ///
+    /// let progress = rx.recv_progress().await.expect("should have received the first tick");
+    /// let (remaining, total) = (Duration::from_millis(300), Duration::from_millis(400));
+    /// assert_eq!(progress, Response::Value(Progress { remaining, total, tick_index: 1 }));
+    /// # }
+    /// ```
+    pub async fn recv_progress(&self) -> Result<Response<Progress>> {
+        Ok(match self.recv_sequenced(Some(self.0.timeout())).await? {
+            Response::Value(Sequenced { seq, value, .. }) => Response::Value(Progress {
+                remaining: value,
+                total: self.0.total().saturating_add(self.0.added.duration()),
+                tick_index: seq,
+            }),
+            Response::CycleCompleted(cycles) => Response::CycleCompleted(cycles),
+            Response::Closed(reason) => Response::Closed(reason),
+        })
+    }
 }
 
 impl<T: Copy + PartialEq> Receiver<T> for ChannelReceiver<T> {
     async fn recv(&self) -> Result<super::Response<T>> {
-        let chan = self.0.clone();
+        Ok(match self.recv_sequenced(Some(self.0.timeout())).await? {
+            Response::Value(Sequenced { value, .. }) => Response::Value(value),
+            Response::CycleCompleted(cycles) => Response::CycleCompleted(cycles),
+            Response::Closed(reason) => Response::Closed(reason),
+        })
+    }
+}
+
+impl<T: Copy + PartialOrd> Receiver<T> for StrictReceiver<T> {
+    async fn recv(&self) -> Result<super::Response<T>> {
+        let mut last = self.last.lock().await;
+
+        let current = match self.inner.recv_sequenced(Some(self.inner.0.timeout())).await? {
+            Response::Value(current) => current,
+            // Each cycle counts down from the top again.
+            Response::CycleCompleted(cycles) => {
+                last.value = None;
+                return Ok(Response::CycleCompleted(cycles));
+            }
+            Response::Closed(reason) => {
+                last.closed = true;
+                return Ok(Response::Closed(reason));
+            }
+        };
 
-        let response = chan.read().await.map_err(CountdownError::from)?;
-        chan.ack().await.map_err(CountdownError::from)?;
+        let violation = last.violated_by(current);
+        last.value = Some(current);
 
-        Ok(response)
+        match violation {
+            Some(violation) => Err(ChannelError::ProtocolViolation(violation).into()),
+            None => Ok(Response::Value(current.value)),
+        }
+    }
+}
+
+impl<T: Copy + PartialOrd> LastDelivery<T> {
+    fn violated_by(&self, current: Sequenced<T>) -> Option<Violation> {
+        if self.closed {
+            return Some(Violation::AfterClosed);
+        }
+
+        let previous = self.value?;
+        if current.seq <= previous.seq || current.value == previous.value {
+            Some(Violation::Repeated)
+        } else if current.value > previous.value {
+            Some(Violation::Increased)
+        } else {
+            None
+        }
+    }
+}
+
+impl<T: Copy + PartialEq> ChannelSender<T> {
+    pub(super) fn closer(&self) -> ChannelCloser<T> {
+        ChannelCloser(Arc::clone(&self.0))
+    }
+
+    /// Waits for the receiver to acknowledge the last value of a cycle, like [`Sender::close`], then starts the next
+    /// cycle of `total` from `value`, with no time added to it. Progress is measured against `total` from then on. The
+    /// receiver gets [`Response::CycleCompleted`] before `value`.
+    pub(super) async fn next_cycle(&self, total: T, value: T) -> Result<()> {
+        // The value is about to be replaced, so only an acknowledgement of it, and not of an earlier value, will do.
+        let seq = self.0.tx.lock().await.borrow().seq;
+        if let Err(err) = self.0.wait_ack(seq).await {
+            self.0.mark_closed(CloseReason::Failed(FailureCode::Unacknowledged));
+            return Err(err.into());
+        }
+        self.0.added.reset();
+        *self.0.total.lock().unwrap_or_else(sync::PoisonError::into_inner) = total;
+        self.0.write_cycle(value).await;
+
+        Ok(())
+    }
+
+    /// Returns the total the countdown measures its progress against, not counting any time added to it.
+    pub(super) fn total(&self) -> T {
+        self.0.total()
+    }
+
+    /// Stops the task sending this channel's values, then sends `value` as the last one, unless it was the last one
+    /// sent already, and closes the channel with [`CloseReason::Skipped`].
+    ///
+    /// Returns whether it skipped: a channel that has already closed is left as it is.
+    pub(super) async fn skip(&self, value: T) -> bool {
+        // Holding the lock waits out a value being sent, and keeps the task from sending another before it stops.
+        let tx = self.0.tx.lock().await;
+        if self.0.is_closed() {
+            return false;
+        }
+
+        self.0.abort_producer();
+        tx.send_if_modified(|last| {
+            if last.value == value {
+                return false;
+            }
+
+            *last = Sequenced { seq: last.seq + 1, cycle: last.cycle, value };
+            true
+        });
+        self.0.mark_closed(CloseReason::Skipped);
+        true
+    }
+}
+
+impl<T: Copy + PartialEq> ChannelCloser<T> {
+    /// Closes the channel immediately for `reason`. The receiver observes [`Response::Closed`] on its next, or pending,
+    /// receive, once it has received the last value sent, unless `reason` is [`CloseReason::Cancelled`].
+    pub(super) fn close(&self, reason: CloseReason) {
+        self.0.mark_closed(reason);
+    }
+
+    pub(super) fn is_closed(&self) -> bool {
+        self.0.is_closed()
+    }
+
+    /// Returns why the channel closed, if it has.
+    pub(super) fn close_reason(&self) -> Option<CloseReason> {
+        self.0.close_reason()
+    }
+
+    /// Returns the time added to the countdown by [`ChannelReceiver::add`].
+    pub(super) fn added_time(&self) -> Arc<AddedTime> {
+        Arc::clone(&self.0.added)
+    }
+
+    /// Sets the task that sends this channel's values, so that [`ChannelReceiver::cancel`] can stop it.
+    pub(super) fn set_producer(&self, producer: AbortHandle) {
+        *self.0.producer.lock().unwrap_or_else(sync::PoisonError::into_inner) = Some(producer);
+    }
+}
+
+impl AddedTime {
+    /// Returns the total time added so far, in milliseconds.
+    pub(super) fn millis(&self) -> u64 {
+        self.added().millis
+    }
+
+    /// Returns the total time added so far.
+    fn duration(&self) -> Duration {
+        Duration::from_millis(self.millis())
+    }
+
+    /// Stops any more time being added, unless some has been since [`AddedTime::millis`] returned `millis`.
+    ///
+    /// Returns whether the countdown is finished: if not, it has more time left to count down.
+    pub(super) fn finish(&self, millis: u64) -> bool {
+        let mut added = self.added();
+        added.finished = added.millis == millis;
+        added.finished
+    }
+
+    /// Clears the time added, so that the next cycle of a repeating countdown can be added to again.
+    pub(super) fn reset(&self) {
+        *self.added() = Added::default();
+    }
+
+    fn add(&self, millis: u64) -> bool {
+        let mut added = self.added();
+        if added.finished {
+            return false;
+        }
+
+        added.millis = added.millis.saturating_add(millis);
+        true
+    }
+
+    fn added(&self) -> sync::MutexGuard<'_, Added> {
+        self.0.lock().unwrap_or_else(sync::PoisonError::into_inner)
     }
 }
 
 impl<T: Copy + PartialEq> Sender<T> for ChannelSender<T> {
     async fn send(&self, value: T) -> Result<()> {
-        // TODO: Add a timeout
+        // Sending replaces the latest value without waiting on the receiver, so it needs no timeout of its own.
         let chan = self.0.clone();
         
         chan.write(value).await.map_err(CountdownError::from)
     }
 
     async fn close(&self) -> Result<()> {
-        // TODO: Add a timeout
         let chan = self.0.clone();
 
-        chan.wait_ack().await.map_err(CountdownError::from)?;
-        *chan.closed.write().await = true;
+        // The last value is still delivered once the channel has closed, so any acknowledgement will do. Waiting for it
+        // times out like a receive does.
+        if let Err(err) = chan.wait_ack(0).await {
+            chan.mark_closed(CloseReason::Failed(FailureCode::Unacknowledged));
+            return Err(err.into());
+        }
+        chan.mark_closed(CloseReason::Completed);
 
         Ok(())
     }
 }
 
-impl<T: Clone> AwaitWithTimeout<T> for watch::Receiver<T> {
-    async fn await_with_timeout(&mut self, timeout: Duration, retry_period: Duration) -> ChanResult<T> {
-        let poll = async |rx: &mut watch::Receiver<T>| {
-            let val_ref = rx.borrow_and_update();
+impl Delivery {
+    fn new() -> Self {
+        Self {
+            last_value_at: Instant::now(),
+            next_seq: 0,
+            cycle: 0,
+            skipped_total: 0,
+            recent_skips: VecDeque::new(),
+            lag_warned: false,
+            lag_warning: None,
+        }
+    }
 
-            if !val_ref.has_changed() {
-                return None;
-            }
+    fn record(&mut self, seq: u64, lag_window: u16, lag_threshold_percent: u8) {
+        let skipped = seq.saturating_sub(self.next_seq);
+        self.next_seq = seq + 1;
+        self.skipped_total += skipped;
+        self.last_value_at = Instant::now();
 
-            Some(val_ref.clone())
-        };
+        if self.lag_warned || lag_window == 0 {
+            return;
+        }
 
+        self.recent_skips.push_back(skipped);
+        if self.recent_skips.len() > lag_window.into() {
+            self.recent_skips.pop_front();
+        }
+
+        if self.recent_skips.len() < lag_window.into() {
+            return;
+        }
+
+        let skipped: u64 = self.recent_skips.iter().sum();
+        let sent = skipped + u64::from(lag_window);
+        if skipped * 100 > sent * u64::from(lag_threshold_percent) {
+            self.lag_warned = true;
+            self.lag_warning = Some(LagWarning { skipped, window: lag_window });
+        }
+    }
+}
+
+impl Display for LagWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "skipped {} of the last {} values, consider a longer countdown period",
+            self.skipped,
+            self.skipped + u64::from(self.window)
+        )
+    }
+}
+
+impl<T: Clone> AwaitWithTimeout<T> for watch::Receiver<T> {
+    async fn await_with_timeout(&mut self, timeout: Option<Duration>, mut closed: watch::Receiver<Option<CloseReason>>) -> ChanResult<Option<T>> {
         let wait_for_changed_value = async {
             loop {
-                if let Some(v) = poll(self).await {
-                    return v;
+                if self.has_changed().unwrap_or(false) {
+                    return Some(self.borrow_and_update().clone());
                 }
 
-                time::sleep(retry_period).await;
-            }   
+                if closed.borrow_and_update().is_some() {
+                    return None;
+                }
+
+                tokio::select! {
+                    biased;
+                    changed = self.changed() => match changed {
+                        Ok(()) => return Some(self.borrow().clone()),
+                        Err(_) => return None,
+                    },
+                    closing = closed.changed() => if closing.is_err() {
+                        return None;
+                    },
+                }
+            }
+        };
+
+        let Some(timeout) = timeout else {
+            return Ok(wait_for_changed_value.await);
         };
 
         time::timeout(timeout, wait_for_changed_value).await
@@ -188,7 +1008,9 @@ impl<T: Clone> AwaitWithTimeout<T> for watch::Receiver<T> {
 mod tests {
     use tokio::time::Duration;
 
-    use crate::countdown::{CountdownError, Response};
+    use rstest::rstest;
+
+    use crate::countdown::{CloseReason, CountdownError, FailureCode, Response};
 
     use super::*;
 
@@ -212,6 +1034,347 @@ mod tests {
         assert_eq!(rx.recv().await.expect_err("expected error"), CountdownError::ChannelError(ChannelError::Timeout(Duration::from_millis(500))));
     }
 
+    #[tokio::test]
+    async fn should_wait_past_the_timeout_when_receiving_forever() {
+        time::pause();
+        let (tx, rx) = Channel::new(1_200_000u64);
+        assert_eq!(rx.recv_forever().await.expect("unexpected error"), Response::Value(1_200_000));
+
+        let sender = tokio::spawn(async move {
+            time::sleep(Duration::from_secs(600)).await;
+            tx.send(600_000).await.expect("unexpected error sending value");
+            time::sleep(Duration::from_secs(600)).await;
+            tx.send(0).await.expect("unexpected error sending value");
+            tx.close().await.expect("unexpected error closing");
+        });
+
+        assert_eq!(rx.recv_forever().await.expect("should not have timed out"), Response::Value(600_000));
+        assert_eq!(rx.recv_forever().await.expect("should not have timed out"), Response::Value(0));
+        assert_eq!(rx.recv_forever().await.expect("should not have timed out"), Response::Closed(CloseReason::Completed));
+        sender.await.expect("the sender should have finished");
+    }
+
+    #[tokio::test]
+    async fn should_return_promptly_on_close_when_receiving_forever() {
+        time::pause();
+        let (tx, rx) = Channel::new(42u64);
+        assert_eq!(rx.recv_forever().await.expect("unexpected error"), Response::Value(42));
+
+        let receiving = tokio::spawn(async move { (rx.recv_forever().await, Instant::now()) });
+        time::sleep(Duration::from_secs(600)).await;
+        let closed_at = Instant::now();
+        tx.closer().close(CloseReason::Cancelled);
+
+        let (response, received_at) = receiving.await.expect("the receiver should have finished");
+        assert_eq!(response.expect("should not have timed out"), Response::Closed(CloseReason::Cancelled));
+        assert_eq!(received_at, closed_at);
+    }
+
+    #[tokio::test]
+    async fn should_report_time_since_last_value_and_remaining_timeout() {
+        time::pause();
+        let (tx, rx) = Channel::new(42u32);
+        assert_eq!(rx.recv().await.expect("unexpected error"), Response::Value(42));
+        assert_eq!(rx.time_since_last_value(), Duration::ZERO);
+        assert_eq!(rx.timeout_remaining(), Some(Duration::from_millis(1000)));
+
+        time::advance(Duration::from_millis(300)).await;
+        assert_eq!(rx.time_since_last_value(), Duration::from_millis(300));
+        assert_eq!(rx.timeout_remaining(), Some(Duration::from_millis(700)));
+
+        tx.send(41).await.expect("unexpected error sending value");
+        assert_eq!(rx.recv().await.expect("unexpected error"), Response::Value(41));
+        assert_eq!(rx.time_since_last_value(), Duration::ZERO);
+
+        time::advance(Duration::from_millis(1000)).await;
+        assert_eq!(rx.time_since_last_value(), Duration::from_millis(1000));
+        assert_eq!(rx.timeout_remaining(), None);
+    }
+
+    #[tokio::test]
+    async fn should_report_no_remaining_timeout_once_closed() {
+        let (tx, rx) = Channel::new(0u32);
+        assert_eq!(rx.recv().await.expect("unexpected error"), Response::Value(0));
+
+        tx.close().await.expect("unexpected error closing channel");
+
+        assert_eq!(rx.timeout_remaining(), None);
+    }
+
+    #[tokio::test]
+    async fn should_return_closed_to_a_pending_receive_when_closed_without_ack() {
+        time::pause();
+        let (tx, rx) = Channel::new(0u32);
+        assert_eq!(rx.recv().await.expect("unexpected error awaiting initial value"), Response::Value(0));
+
+        let rx_handle = tokio::spawn(async move { rx.recv().await });
+        time::advance(Duration::from_millis(250)).await;
+        tx.closer().close(CloseReason::Cancelled);
+
+        assert_eq!(rx_handle.await.unwrap().expect("unexpected error awaiting closed"), Response::Closed(CloseReason::Cancelled));
+    }
+
+    #[tokio::test]
+    async fn should_close_as_failed_when_the_last_value_is_never_acknowledged() {
+        time::pause();
+        let (tx, rx) = Channel::new_with_options(0u32, [with_timeout(100)]);
+
+        assert_eq!(tx.close().await.expect_err("expected error"), CountdownError::ChannelError(ChannelError::Timeout(Duration::from_millis(100))));
+
+        assert_eq!(rx.recv().await.expect("unexpected error awaiting last value"), Response::Value(0));
+        assert_eq!(
+            rx.recv().await.expect("unexpected error awaiting closed"),
+            Response::Closed(CloseReason::Failed(FailureCode::Unacknowledged)),
+        );
+    }
+
+    #[tokio::test]
+    async fn should_deliver_nothing_more_once_cancelled() {
+        time::pause();
+        let (tx, rx) = Channel::new(10u32);
+        assert_eq!(rx.recv().await.expect("unexpected error awaiting initial value"), Response::Value(10));
+
+        tx.send(9).await.expect("unexpected error sending value");
+        rx.cancel();
+        rx.cancel();
+        tx.send(8).await.expect("unexpected error sending value");
+
+        assert_eq!(rx.recv().await.expect("unexpected error awaiting closed"), Response::Closed(CloseReason::Cancelled));
+        assert_eq!(rx.recv().await.expect("unexpected error awaiting closed"), Response::Closed(CloseReason::Cancelled));
+    }
+
+    #[tokio::test]
+    async fn should_keep_completed_when_cancelled_after_closing() {
+        let (tx, rx) = Channel::new(0u32);
+        let pending = tokio::spawn({
+            let rx = rx.clone();
+            async move { rx.recv().await }
+        });
+        tx.close().await.expect("unexpected error closing channel");
+        assert_eq!(pending.await.unwrap().expect("unexpected error awaiting last value"), Response::Value(0));
+
+        rx.cancel();
+
+        assert_eq!(rx.recv().await.expect("unexpected error awaiting closed"), Response::Closed(CloseReason::Completed));
+    }
+
+    #[tokio::test]
+    async fn should_keep_the_first_close_reason() {
+        let (tx, rx) = Channel::new(0u32);
+        assert_eq!(rx.recv().await.expect("unexpected error awaiting initial value"), Response::Value(0));
+
+        tx.closer().close(CloseReason::Cancelled);
+        tx.close().await.expect("unexpected error closing channel");
+
+        assert_eq!(rx.recv().await.expect("unexpected error awaiting closed"), Response::Closed(CloseReason::Cancelled));
+    }
+
+    #[tokio::test]
+    async fn should_accept_a_strictly_decreasing_countdown() {
+        let (tx, rx) = Channel::new(10u32);
+        let rx = rx.strict();
+        assert_eq!(rx.recv().await.expect("unexpected error"), Response::Value(10));
+
+        tx.send(9).await.expect("unexpected error sending value");
+        assert_eq!(rx.recv().await.expect("unexpected error"), Response::Value(9));
+
+        tx.close().await.expect("unexpected error closing channel");
+        assert_eq!(rx.recv().await.expect("unexpected error awaiting closed"), Response::Closed(CloseReason::Completed));
+    }
+
+    #[tokio::test]
+    async fn should_deliver_the_cycle_completed_before_the_first_value_of_the_next_cycle() {
+        let (tx, rx) = Channel::new(10u32);
+        let rx = rx.strict();
+        assert_eq!(rx.recv().await.expect("unexpected error"), Response::Value(10));
+        tx.send(0).await.expect("unexpected error sending value");
+        assert_eq!(rx.recv().await.expect("unexpected error"), Response::Value(0));
+
+        tx.next_cycle(10, 10).await.expect("unexpected error starting the next cycle");
+
+        assert_eq!(rx.recv().await.expect("unexpected error"), Response::CycleCompleted(1));
+        assert_eq!(rx.recv().await.expect("unexpected error"), Response::Value(10));
+    }
+
+    #[tokio::test]
+    async fn should_wait_for_the_last_value_of_a_cycle_to_be_acknowledged_before_the_next() {
+        time::pause();
+        let (tx, rx) = Channel::new_with_options(10u32, [with_timeout(500)]);
+        assert_eq!(rx.recv().await.expect("unexpected error"), Response::Value(10));
+        tx.send(0).await.expect("unexpected error sending value");
+
+        let error = tx.next_cycle(10, 10).await.expect_err("expected the last value to go unacknowledged");
+
+        assert_eq!(error, ChannelError::Timeout(Duration::from_millis(500)).into());
+        assert_eq!(rx.recv().await.expect("unexpected error"), Response::Value(0));
+        assert_eq!(rx.recv().await.expect("unexpected error"), Response::Closed(CloseReason::Failed(FailureCode::Unacknowledged)));
+    }
+
+    #[tokio::test]
+    async fn should_fail_a_strict_receive_given_a_value_greater_than_the_previous_one() {
+        let (tx, rx) = Channel::new(10u32);
+        let rx = rx.strict();
+        assert_eq!(rx.recv().await.expect("unexpected error"), Response::Value(10));
+
+        tx.send(11).await.expect("unexpected error sending value");
+
+        assert_eq!(rx.recv().await.expect_err("expected error"), ChannelError::ProtocolViolation(Violation::Increased).into());
+    }
+
+    #[tokio::test]
+    async fn should_fail_a_strict_receive_given_the_same_value_as_the_previous_one() {
+        let (tx, rx) = Channel::new(10u32);
+        let rx = rx.strict();
+        assert_eq!(rx.recv().await.expect("unexpected error"), Response::Value(10));
+
+        tx.send(10).await.expect("unexpected error sending value");
+
+        assert_eq!(rx.recv().await.expect_err("expected error"), ChannelError::ProtocolViolation(Violation::Repeated).into());
+    }
+
+    #[tokio::test]
+    async fn should_fail_a_strict_receive_given_a_value_after_closed() {
+        let (tx, rx) = Channel::new(10u32);
+        let rx = rx.strict();
+        assert_eq!(rx.recv().await.expect("unexpected error"), Response::Value(10));
+
+        tx.close().await.expect("unexpected error closing channel");
+        assert_eq!(rx.recv().await.expect("unexpected error awaiting closed"), Response::Closed(CloseReason::Completed));
+        tx.send(9).await.expect("unexpected error sending value");
+
+        assert_eq!(rx.recv().await.expect_err("expected error"), ChannelError::ProtocolViolation(Violation::AfterClosed).into());
+    }
+
+    #[rstest]
+    #[case::before_the_timeout(99)]
+    #[case::at_the_timeout(100)]
+    #[case::after_the_timeout(101)]
+    #[tokio::test]
+    async fn should_deliver_the_final_value_before_closed_when_the_receiver_times_out(#[case] send_after_millis: u64) {
+        time::pause();
+        let (tx, rx) = Channel::new_with_options(1u32, [with_timeout(100)]);
+        assert_eq!(rx.recv().await.expect("unexpected error awaiting initial value"), Response::Value(1));
+
+        let tx_handle = tokio::spawn(async move {
+            time::sleep(Duration::from_millis(send_after_millis)).await;
+            tx.send(0).await.expect("unexpected error sending value");
+            tx.close().await
+        });
+
+        let mut responses = Vec::new();
+        while responses.last().is_none_or(|response| !matches!(response, Response::Closed(_))) {
+            match rx.recv().await {
+                Ok(response) => responses.push(response),
+                Err(error) => assert_eq!(error, ChannelError::Timeout(Duration::from_millis(100)).into()),
+            }
+        }
+
+        tx_handle.await.unwrap().expect("unexpected error closing channel");
+        assert_eq!(responses, [Response::Value(0), Response::Closed(CloseReason::Completed)]);
+    }
+
+    #[rstest]
+    #[case::handshake(AckStrategy::Handshake)]
+    #[case::fire_and_forget(AckStrategy::FireAndForget)]
+    #[tokio::test]
+    async fn should_deliver_the_final_value_before_closed_with_either_ack_strategy(#[case] ack_strategy: AckStrategy) {
+        time::pause();
+        let (tx, rx) = Channel::new_with_options(2u32, [with_ack_strategy(ack_strategy)]);
+        assert_eq!(rx.recv().await.expect("unexpected error awaiting initial value"), Response::Value(2));
+
+        tx.send(1).await.expect("unexpected error sending value");
+        tx.send(0).await.expect("unexpected error sending value");
+        tx.close().await.expect("unexpected error closing channel");
+
+        assert_eq!(rx.recv().await.expect("unexpected error awaiting last value"), Response::Value(0));
+        assert_eq!(rx.recv().await.expect("unexpected error awaiting closed"), Response::Closed(CloseReason::Completed));
+    }
+
+    #[tokio::test]
+    async fn should_close_without_waiting_for_an_ack_when_firing_and_forgetting() {
+        time::pause();
+        let (tx, rx) = Channel::new_with_options(0u32, [with_ack_strategy(AckStrategy::FireAndForget), with_timeout(100)]);
+
+        tx.close().await.expect("unexpected error closing channel");
+
+        assert_eq!(rx.recv().await.expect("unexpected error awaiting last value"), Response::Value(0));
+        assert_eq!(rx.recv().await.expect("unexpected error awaiting closed"), Response::Closed(CloseReason::Completed));
+    }
+
+    #[tokio::test]
+    async fn should_count_values_replaced_before_they_were_received() {
+        let (tx, rx) = Channel::new(0u32);
+        tx.send(1).await.expect("unexpected error sending value");
+        assert_eq!(rx.recv().await.expect("unexpected error"), Response::Value(1));
+        assert_eq!(rx.skipped_total(), 1);
+
+        for value in 2..=4 {
+            tx.send(value).await.expect("unexpected error sending value");
+        }
+        assert_eq!(rx.recv().await.expect("unexpected error"), Response::Value(4));
+        assert_eq!(rx.skipped_total(), 3);
+
+        tx.send(5).await.expect("unexpected error sending value");
+        assert_eq!(rx.recv().await.expect("unexpected error"), Response::Value(5));
+        assert_eq!(rx.skipped_total(), 3);
+    }
+
+    /// Sends the values from one to `last`, one every 100ms, keeping the channel open once they have all been sent.
+    fn send_every_100ms(tx: ChannelSender<u32>, last: u32) -> tokio::task::JoinHandle<ChannelSender<u32>> {
+        tokio::spawn(async move {
+            let mut ticks = time::interval_at(Instant::now() + Duration::from_millis(100), Duration::from_millis(100));
+            for value in 1..=last {
+                ticks.tick().await;
+                tx.send(value).await.expect("unexpected error sending value");
+            }
+            tx
+        })
+    }
+
+    /// Receives every value up to `last`, taking `handling` to handle each, and returns the lag warnings taken after
+    /// each value.
+    async fn receive_until(rx: &ChannelReceiver<u32>, last: u32, handling: Duration) -> Vec<(u32, LagWarning)> {
+        let mut warnings = Vec::new();
+        loop {
+            let Response::Value(value) = rx.recv().await.expect("unexpected error receiving value") else {
+                panic!("expected the channel to stay open until {last} was received");
+            };
+            warnings.extend(rx.take_lag_warning().map(|warning| (value, warning)));
+            if value == last {
+                return warnings;
+            }
+            time::sleep(handling).await;
+        }
+    }
+
+    #[tokio::test]
+    async fn should_raise_a_single_lag_warning_when_a_slow_consumer_skips_too_many_values() {
+        time::pause();
+        let (tx, rx) = Channel::new_with_options(0u32, [with_lag_warning(4, 50)]);
+        let sender = send_every_100ms(tx, 30);
+
+        // Handling each value for 310ms skips the two sent meanwhile, so the first full window of four has skipped six.
+        let warnings = receive_until(&rx, 30, Duration::from_millis(310)).await;
+
+        assert_eq!(warnings, [(9, LagWarning { skipped: 6, window: 4 })]);
+        assert_eq!(rx.take_lag_warning(), None);
+        assert_eq!(rx.skipped_total(), 20);
+        drop(sender.await.expect("unexpected panic sending values"));
+    }
+
+    #[tokio::test]
+    async fn should_not_raise_a_lag_warning_when_the_consumer_keeps_up() {
+        time::pause();
+        let (tx, rx) = Channel::new_with_options(0u32, [with_lag_warning(4, 50)]);
+        let sender = send_every_100ms(tx, 10);
+
+        let warnings = receive_until(&rx, 10, Duration::from_millis(50)).await;
+
+        assert_eq!(warnings, []);
+        assert_eq!(rx.skipped_total(), 0);
+        drop(sender.await.expect("unexpected panic sending values"));
+    }
+
     #[tokio::test]
     async fn should_return_the_initial_value() {
         let (_, rx) = Channel::new(42u32);
@@ -227,7 +1390,7 @@ mod tests {
 
         tx.close().await.expect("unexpected error closing channel");
 
-        assert_eq!(rx.recv().await.expect("unexpected error awaiting closed"), Response::Closed);
+        assert_eq!(rx.recv().await.expect("unexpected error awaiting closed"), Response::Closed(CloseReason::Completed));
     }
 
     #[tokio::test]
@@ -253,7 +1416,29 @@ mod tests {
         tx.send(25).await.expect("unexpected error sending value");
         tx.close().await.expect("unexpected error closing channel");
 
-        assert_eq!(rx.recv().await.expect("unexpected error awaiting closed"), Response::Closed);
+        assert_eq!(rx.recv().await.expect("unexpected error awaiting last value"), Response::Value(25));
+        assert_eq!(rx.recv().await.expect("unexpected error awaiting closed"), Response::Closed(CloseReason::Completed));
+    }
+
+    #[tokio::test]
+    async fn should_receive_the_progress_of_the_countdown() {
+        let (tx, rx) = Channel::new_with_options(Duration::from_millis(1000), [with_ack_strategy(AckStrategy::FireAndForget)]);
+        let progress = |remaining, total, tick_index| Response::Value(Progress {
+            remaining: Duration::from_millis(remaining),
+            total: Duration::from_millis(total),
+            tick_index,
+        });
+
+        assert_eq!(rx.recv_progress().await.expect("unexpected error receiving"), progress(1000, 1000, 0));
+        tx.send(Duration::from_millis(900)).await.expect("unexpected error sending value");
+        assert_eq!(rx.recv_progress().await.expect("unexpected error receiving"), progress(900, 1000, 1));
+
+        rx.add(Duration::from_millis(500)).await.expect("unexpected error adding time");
+        tx.send(Duration::from_millis(1300)).await.expect("unexpected error sending value");
+        assert_eq!(rx.recv_progress().await.expect("unexpected error receiving"), progress(1300, 1500, 2));
+
+        tx.close().await.expect("unexpected error closing channel");
+        assert_eq!(rx.recv_progress().await.expect("unexpected error receiving"), Response::Closed(CloseReason::Completed));
     }
 
     #[tokio::test]
@@ -263,7 +1448,7 @@ mod tests {
         let tx_handle = tokio::spawn(async move { tx.close().await.expect("unexpected error closing channel") });
         let rx_handle = tokio::spawn(async move { 
             assert_eq!(rx.recv().await.expect("unexpected error awaiting initial value"), Response::Value(0));
-            assert_eq!(rx.recv().await.expect("unexpected error awaiting closed"), Response::Closed);
+            assert_eq!(rx.recv().await.expect("unexpected error awaiting closed"), Response::Closed(CloseReason::Completed));
         });
 
         tokio::select! {
@@ -1,86 +1,97 @@
-use thiserror::Error;
-
-use crate::countdown::timer::TimerError;
-
+#[cfg(feature = "runtime")]
 mod timer;
+#[cfg(feature = "runtime")]
 mod channel;
+#[cfg(feature = "runtime")]
+mod sequence;
+#[cfg(feature = "blocking")]
+#[cfg_attr(docsrs, doc(cfg(feature = "blocking")))]
+pub mod blocking;
+pub mod poll;
 
-pub use timer::AsyncCountdown;
-pub use channel::{ChannelReceiver, ChannelError};
-
-pub type Result<T> = std::result::Result<T, CountdownError>;
-
-#[derive(Debug, Error, PartialEq)]
-pub enum CountdownError {
-    #[error(transparent)]
-    TimerError(#[from] TimerError),
-    #[error(transparent)]
-    ChannelError(#[from] ChannelError),
-}
-
-#[derive(Debug, PartialEq)]
-pub enum Response<T: PartialEq + Copy> {
-    Value(T),
-    Closed,
-}
+pub use crate::core::display::{sanitize_display_text, DisplaySeconds, DisplayTenths};
+pub use crate::core::error::{ChannelError, CountdownError, Result, TaskError, Violation};
+pub use crate::core::protocol::{CloseReason, FailureCode, Progress, Receiver, Response, Sender};
+pub use crate::core::schedule::Schedule;
+pub use crate::core::user_facing::UserFacing;
+pub use crate::core::validation::{InvalidCountdown, InvalidDuration, StartError, TimerError};
+#[cfg(feature = "runtime")]
+#[cfg_attr(docsrs, doc(cfg(feature = "runtime")))]
+pub use timer::{AsyncCountdown, AsyncCountdownBuilder, CountdownHandle, Direction, DuplicateStart, MissedTicks, Repeat};
+#[cfg(feature = "runtime")]
+#[cfg_attr(docsrs, doc(cfg(feature = "runtime")))]
+pub use channel::{AckStrategy, ChannelReceiver, LagWarning, StrictReceiver};
+#[cfg(feature = "runtime")]
+#[cfg_attr(docsrs, doc(cfg(feature = "runtime")))]
+pub use sequence::Sequence;
 
 /// A countdown that counts down from a specified duration.
+///
+/// Every duration a countdown takes, and every value it sends, is a [`std::time::Duration`] counted in whole
+/// milliseconds: any fraction of a millisecond left over is dropped.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+///
+/// use libtomatillo::countdown::{AsyncCountdown, Countdown, Receiver, Response};
+///
+/// # #[tokio::main(flavor = "current_thread", start_paused = true)]
+/// # async fn main() {
+/// let timer = AsyncCountdown::try_new(Duration::from_millis(100)).expect("should have created the timer");
+/// let (rx, _) = timer.start(Duration::from_millis(300)).await.expect("should have started the countdown");
+///
+/// let mut last = None;
+/// while let Ok(Response::Value(left)) = rx.recv().await {
+///     last = Some(left);
+/// }
+///
+/// assert_eq!(last, Some(Duration::ZERO));
+/// # }
+/// ```
+///
+/// Build each time from the unit it is known in, rather than converting by hand, and read it back the same way:
+///
+/// ```
+/// use std::time::Duration;
+///
+/// use libtomatillo::countdown::{AsyncCountdown, Countdown, Receiver, Response};
+///
+/// # #[tokio::main(flavor = "current_thread", start_paused = true)]
+/// # async fn main() {
+/// let timer = AsyncCountdown::try_new(Duration::from_secs(1)).expect("should have created the timer");
+/// // A period already in milliseconds, such as one read from a configuration file.
+/// let same = AsyncCountdown::try_from_millis(1000).expect("should have created the timer");
+/// assert_eq!(same.validate(Duration::from_secs(25 * 60)), Ok(()));
+///
+/// let (rx, handle) = timer.start(Duration::from_secs(25 * 60)).await.expect("should have started the countdown");
+///
+/// assert_eq!(rx.recv().await, Ok(Response::Value(Duration::from_secs(25 * 60))));
+/// assert_eq!(handle.remaining().as_secs() / 60, 25);
+/// # }
+/// ```
+#[cfg(feature = "runtime")]
+#[cfg_attr(docsrs, doc(cfg(feature = "runtime")))]
 pub trait Countdown<T: Copy> {
     /// Starts the countdown.
     ///
+    /// Every countdown started runs on its own schedule, so countdowns started concurrently from the same timer tick
+    /// independently of each other, each from the moment it started.
+    ///
     /// # Arguments
     ///
-    /// * `duration_millis` - The duration of the countdown in milliseconds.
+    /// * `duration` - The duration of the countdown, to the millisecond.
     ///
     /// # Returns
     ///
-    /// A [`Result`] that is:
+    /// A [`std::result::Result`] that is:
     ///
-    /// * `Ok(watcher)` - The countdown has started, and a [`ChannelReceiver`] is returned.
+    /// * `Ok((receiver, handle))` - The countdown has started: a [`ChannelReceiver`] of the time remaining, and a
+    ///   [`CountdownHandle`] to wait for the task running the countdown or abort it.
     /// * `Err(err)` - The countdown could not be started.
-    fn start(&self, duration_millis: u64) -> impl std::future::Future<Output = Result<ChannelReceiver<u64>>>;
+    fn start(
+        &self,
+        duration: std::time::Duration,
+    ) -> impl std::future::Future<Output = std::result::Result<(ChannelReceiver<T>, CountdownHandle), StartError>>;
 }
-
-
-/// A sender that sends updates countdown updates to a receiver and waits for ack between sends
-pub trait Sender<T> {
-    /// Sends a value to the [`Receiver`] and waits for the [`Receiver`] to acknowledge receipt.
-    /// 
-    /// # Arguments
-    /// 
-    /// * `value` - The value to send to the receiver
-    /// 
-    /// # Returns
-    /// 
-    /// A [`Result`] that is:
-    /// 
-    /// * `Ok(())` - The value has been sent successfully.
-    /// * `Err(err)` - The value could not be sent.
-    fn send(&self, value: T) -> impl std::future::Future<Output = Result<()>>;
-
-    /// Closes the sender, indicating that no more values will be sent. 
-    /// 
-    /// Implementations of this function should alert the receiver that the sender is closed to indicate no more 
-    /// values will be sent.
-    /// 
-    /// # Returns
-    /// 
-    /// A [`Result`] that is:
-    /// 
-    /// * `Ok(())` - The sender has been closed successfully.
-    /// * `Err(err)` - The sender could not be closed.
-    fn close(&self) -> impl std::future::Future<Output = Result<()>>;
-}
-
-/// Receives updates from a sender and acknowledges receipt
-pub trait Receiver<T: PartialEq + Copy> {
-    /// Receives a value from the sender and acknowledges receipt
-    /// 
-    /// # Returns
-    /// 
-    /// A [`Result`] that is:
-    /// 
-    /// * `Ok(value)` - The value has been received successfully.
-    /// * `Err(err)` - The value could not be received.
-    fn recv(&self) -> impl std::future::Future<Output = Result<Response<T>>>;
-}
\ No newline at end of file
@@ -0,0 +1,234 @@
+//! A countdown driven by its host, for embedders that can neither spawn a task or thread nor wait on a channel, such as
+//! a game engine's update loop or a plugin host that only exposes a poll function.
+
+use std::time::{Duration, Instant};
+
+use crate::core::{schedule::Schedule, validation};
+
+use super::{Result, TimerError};
+
+/// A countdown that is nothing but a state machine: the host calls [`PollCountdown::poll`] on its own schedule and gets
+/// back whatever changed since the last call. It neither spawns, allocates nor waits.
+///
+/// It follows [`PollCountdown::schedule`], like every other countdown. A poll made after several ticks were due
+/// coalesces them: it returns the latest alone, so the values returned are always a subset of the schedule, in order,
+/// however irregularly the host polls. A poll made once the countdown has run out returns
+/// [`PollResult::Completed`] in place of its final zero, however many ticks it missed.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::{Duration, Instant};
+///
+/// use libtomatillo::countdown::poll::{PollCountdown, PollResult};
+///
+/// let mut countdown = PollCountdown::try_new(Duration::from_secs(3), Duration::from_secs(1))
+///     .expect("should have created the countdown");
+/// let started = Instant::now();
+///
+/// assert_eq!(countdown.poll(started), PollResult::Tick(Duration::from_secs(3)));
+/// assert_eq!(countdown.poll(started + Duration::from_millis(500)), PollResult::NoChange);
+/// assert_eq!(countdown.poll(started + Duration::from_millis(2100)), PollResult::Tick(Duration::from_secs(1)));
+/// assert_eq!(countdown.poll(started + Duration::from_secs(3)), PollResult::Completed);
+/// assert_eq!(countdown.poll(started + Duration::from_secs(4)), PollResult::NoChange);
+/// ```
+#[derive(Debug, Clone)]
+pub struct PollCountdown {
+    schedule: Schedule,
+    started: Option<Instant>,
+    /// The index of the last tick returned.
+    returned: Option<u64>,
+}
+
+/// What changed since the last call to [`PollCountdown::poll`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PollResult {
+    /// No tick has been due since the last poll.
+    NoChange,
+    /// The latest tick due, with the time left when it was due.
+    Tick(Duration),
+    /// The countdown has run out. Returned once, and followed by [`PollResult::NoChange`] on every later poll.
+    Completed,
+}
+
+impl PollCountdown {
+    /// Creates a countdown of `duration` ticking every `period`, which starts when it is first polled.
+    ///
+    /// # Arguments
+    ///
+    /// * `duration` - The duration of the countdown.
+    /// * `period` - The interval at which the countdown ticks.
+    ///
+    /// # Returns
+    ///
+    /// A [`Result`] that is:
+    ///
+    /// * `Ok(countdown)` - The countdown has been created.
+    /// * `Err(err)` - `period` or `duration` is invalid, for the same reasons as they would be for an
+    ///   `AsyncCountdown`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use libtomatillo::countdown::poll::PollCountdown;
+    ///
+    /// assert!(PollCountdown::try_new(Duration::from_secs(60), Duration::from_secs(1)).is_ok());
+    /// assert!(PollCountdown::try_new(Duration::from_secs(60), Duration::ZERO).is_err());
+    /// assert!(PollCountdown::try_new(Duration::ZERO, Duration::from_secs(1)).is_err());
+    /// ```
+    pub fn try_new(duration: Duration, period: Duration) -> Result<Self> {
+        validation::validate_period(period).map_err(TimerError::from)?;
+        validation::validate_duration(duration, period)?;
+
+        Ok(Self { schedule: Schedule::new(duration, period), started: None, returned: None })
+    }
+
+    /// Returns the ticks the countdown returns when it is polled at least once every period, on time.
+    #[must_use]
+    pub fn schedule(&self) -> Schedule {
+        // The countdown looks ticks up in its own schedule, but never iterates it.
+        self.schedule.clone()
+    }
+
+    /// Advances the countdown to `now`, starting it on the first poll.
+    ///
+    /// # Arguments
+    ///
+    /// * `now` - The time the host polls at. A poll earlier than the one before it changes nothing.
+    ///
+    /// # Returns
+    ///
+    /// * `PollResult::Tick(left)` - The latest tick due since the last poll, starting with the full duration
+    ///   on the first.
+    /// * `PollResult::Completed` - The countdown ran out since the last poll.
+    /// * `PollResult::NoChange` - Nothing was due since the last poll, or the countdown had already completed.
+    pub fn poll(&mut self, now: Instant) -> PollResult {
+        let started = *self.started.get_or_insert(now);
+        let elapsed = now.saturating_duration_since(started).as_millis() as u64;
+        let due = self.schedule.latest_due(elapsed);
+        if self.returned.is_some_and(|returned| due <= returned) {
+            return PollResult::NoChange;
+        }
+
+        self.returned = Some(due);
+        if due == self.schedule.last_index() {
+            PollResult::Completed
+        } else {
+            PollResult::Tick(Duration::from_millis(self.schedule.tick(due).1))
+        }
+    }
+
+    /// Returns whether the countdown has run out.
+    #[must_use]
+    pub fn is_completed(&self) -> bool {
+        self.returned == Some(self.schedule.last_index())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use crate::countdown::{CountdownError, InvalidCountdown, InvalidDuration};
+
+    use super::*;
+
+    fn countdown(duration_millis: u64, period_millis: u64) -> PollCountdown {
+        PollCountdown::try_new(Duration::from_millis(duration_millis), Duration::from_millis(period_millis))
+            .expect("should have created countdown")
+    }
+
+    /// Polls `countdown` at each of `polls_millis` after the first poll, returning what changed at each.
+    fn poll_at(countdown: &mut PollCountdown, polls_millis: &[u64]) -> Vec<PollResult> {
+        let started = Instant::now();
+        polls_millis.iter().map(|&millis| countdown.poll(started + Duration::from_millis(millis))).collect()
+    }
+
+    fn tick(millis_left: u64) -> PollResult {
+        PollResult::Tick(Duration::from_millis(millis_left))
+    }
+
+    /// Returns what polling on time returns for each tick of `schedule`.
+    fn on_schedule(schedule: Schedule) -> Vec<PollResult> {
+        schedule.map(|(_, left)| if left.is_zero() { PollResult::Completed } else { PollResult::Tick(left) }).collect()
+    }
+
+    #[rstest]
+    #[case::whole_periods(300, 100)]
+    #[case::a_partial_period(250, 100)]
+    #[case::one_period(1000, 1000)]
+    fn should_return_every_tick_of_the_schedule_when_polled_on_time(#[case] duration_millis: u64, #[case] period_millis: u64) {
+        let mut countdown = countdown(duration_millis, period_millis);
+        let polls = countdown.schedule().map(|(due, _)| due.as_millis() as u64).collect::<Vec<_>>();
+
+        let returned = poll_at(&mut countdown, &polls);
+
+        assert_eq!(returned, on_schedule(countdown.schedule()));
+        assert!(countdown.is_completed());
+    }
+
+    #[test]
+    fn should_return_nothing_between_ticks() {
+        let mut countdown = countdown(300, 100);
+
+        let returned = poll_at(&mut countdown, &[0, 1, 50, 99, 100, 150, 199]);
+
+        assert_eq!(returned, [
+            tick(300),
+            PollResult::NoChange,
+            PollResult::NoChange,
+            PollResult::NoChange,
+            tick(200),
+            PollResult::NoChange,
+            PollResult::NoChange,
+        ]);
+    }
+
+    #[rstest]
+    #[case::irregular(&[0, 130, 170, 420, 480, 1010], &[
+        tick(1000),
+        tick(900),
+        PollResult::NoChange,
+        tick(600),
+        PollResult::NoChange,
+        PollResult::Completed,
+    ])]
+    #[case::a_huge_gap(&[0, 100, 1_000_000, 2_000_000], &[
+        tick(1000),
+        tick(900),
+        PollResult::Completed,
+        PollResult::NoChange,
+    ])]
+    #[case::back_in_time(&[0, 300, 150, 300, 400], &[
+        tick(1000),
+        tick(700),
+        PollResult::NoChange,
+        PollResult::NoChange,
+        tick(600),
+    ])]
+    fn should_coalesce_the_ticks_missed_between_polls(#[case] polls_millis: &[u64], #[case] expected: &[PollResult]) {
+        let mut countdown = countdown(1000, 100);
+
+        let returned = poll_at(&mut countdown, polls_millis);
+
+        assert_eq!(returned, expected);
+        let scheduled = on_schedule(countdown.schedule());
+        let ticks = returned.iter().filter(|&&result| result != PollResult::NoChange).collect::<Vec<_>>();
+        assert!(ticks.iter().all(|tick| scheduled.contains(tick)), "expected only ticks of {scheduled:?}, but got {ticks:?}");
+    }
+
+    #[rstest]
+    #[case::zero_period(1000, 0, TimerError::from(InvalidCountdown::ZeroInterval).into())]
+    #[case::zero_duration(0, 100, InvalidDuration::ZeroDuration.into())]
+    #[case::within_the_period(50, 100, InvalidDuration::DurationSmallerThanPeriod {
+        duration: Duration::from_millis(50),
+        period: Duration::from_millis(100),
+    }.into())]
+    fn should_fail_to_create_an_invalid_countdown(#[case] duration_millis: u64, #[case] period_millis: u64, #[case] expected: CountdownError) {
+        let created = PollCountdown::try_new(Duration::from_millis(duration_millis), Duration::from_millis(period_millis));
+
+        assert_eq!(created.map(drop), Err(expected));
+    }
+}
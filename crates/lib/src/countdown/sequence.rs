@@ -0,0 +1,311 @@
+use std::time::Duration;
+
+use crate::core::validation;
+
+use super::{timer::Cycle, AsyncCountdown, ChannelReceiver, Countdown, CountdownHandle, InvalidDuration, StartError};
+
+/// An ordered list of labelled countdowns, such as work and break periods, run one after the other on a single
+/// receiver.
+///
+/// The receiver counts each segment down to zero in turn, then gets [`super::Response::CycleCompleted`] with the index of
+/// the segment that starts next, which [`Sequence::label`] and [`ChannelReceiver::segment_label`] name, before the first
+/// value of that segment. An [`crate::event_loop::EventLoop`] returns it as
+/// [`crate::event_loop::AppEvent::SegmentStarted`], with the label. [`super::Response::Closed`] follows the zero of the
+/// last segment. Every segment ticks on the timer the sequence was created with, whatever [`super::Repeat`] the timer is
+/// set to.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+///
+/// use libtomatillo::countdown::{AsyncCountdown, Countdown, Receiver, Response, Sequence};
+///
+/// # #[tokio::main(flavor = "current_thread", start_paused = true)]
+/// # async fn main() {
+/// let timer = AsyncCountdown::try_new(Duration::from_millis(100)).expect("should have created the timer");
+/// let sequence = Sequence::try_new(timer, [("work", Duration::from_millis(200)), ("break", Duration::from_millis(100))])
+///     .expect("should have created the sequence");
+/// let (rx, _) = sequence.start(Duration::from_millis(300)).await.expect("should have started the sequence");
+///
+/// let mut labels = vec![sequence.label(0)];
+/// while let Ok(response) = rx.recv().await {
+///     match response {
+///         Response::Value(_) => {},
+///         Response::CycleCompleted(index) => labels.push(sequence.label(index)),
+///         Response::Closed(_) => break,
+///     }
+/// }
+///
+/// assert_eq!(labels, [Some("work"), Some("break")]);
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct Sequence {
+    timer: AsyncCountdown,
+    segments: Vec<(String, Duration)>,
+}
+
+impl Sequence {
+    /// Creates a sequence of `segments` that count down on `timer`.
+    ///
+    /// # Arguments
+    ///
+    /// * `timer` - The timer each segment ticks on.
+    /// * `segments` - The label and duration of each segment, in the order they run.
+    ///
+    /// # Returns
+    ///
+    /// A [`std::result::Result`] that is:
+    ///
+    /// * `Ok(sequence)` - The sequence has been created.
+    /// * `Err(err)` - There are no segments, or the duration of one is invalid for `timer`, see
+    ///   [`AsyncCountdown::validate`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use libtomatillo::countdown::{AsyncCountdown, InvalidDuration, Sequence, StartError};
+    ///
+    /// let timer = AsyncCountdown::try_new(Duration::from_millis(1000)).expect("should have created the timer");
+    /// let error = Sequence::try_new(timer, [("work", Duration::from_secs(1500)), ("break", Duration::ZERO)])
+    ///     .expect_err("should have rejected the break");
+    ///
+    /// assert_eq!(error, StartError::InvalidSegment { index: 1, label: "break".to_owned(), source: InvalidDuration::ZeroDuration });
+    /// ```
+    pub fn try_new<L: Into<String>>(
+        timer: AsyncCountdown,
+        segments: impl IntoIterator<Item = (L, Duration)>,
+    ) -> std::result::Result<Self, StartError> {
+        let segments = segments.into_iter().map(|(label, duration)| (label.into(), duration)).collect::<Vec<_>>();
+        if segments.is_empty() {
+            return Err(StartError::EmptySequence);
+        }
+
+        for (index, (label, duration)) in segments.iter().enumerate() {
+            // A timer that counts up takes zero for no cap, which would leave the segments after it waiting forever.
+            let validated = if duration.is_zero() { Err(InvalidDuration::ZeroDuration) } else { timer.validate(*duration) };
+            validated.map_err(|source| StartError::InvalidSegment { index, label: label.clone(), source })?;
+        }
+
+        Ok(Self { timer, segments })
+    }
+
+    /// Returns the label and duration of each segment, in the order they run.
+    #[must_use]
+    pub fn segments(&self) -> &[(String, Duration)] {
+        &self.segments
+    }
+
+    /// Returns the label of the segment at `index`, as received in [`super::Response::CycleCompleted`], or `None` past
+    /// the last segment.
+    #[must_use]
+    pub fn label(&self, index: u32) -> Option<&str> {
+        let index = usize::try_from(index).ok()?;
+        self.segments.get(index).map(|(label, _)| label.as_str())
+    }
+
+    /// Returns the duration of the whole sequence, which [`Countdown::start`] counts down all of.
+    #[must_use]
+    pub fn total(&self) -> Duration {
+        self.segments.iter().map(|(_, duration)| *duration).sum()
+    }
+}
+
+impl Countdown<Duration> for Sequence {
+    /// Starts the sequence with `duration` left of it, so that [`Sequence::total`] runs every segment while
+    /// less resumes part way through: the segments already done are skipped, and the receiver gets what is left of the
+    /// one under way.
+    ///
+    /// [`ChannelReceiver::recv_progress`] measures progress against the segment under way, and
+    /// [`ChannelReceiver::add`] adds time to it alone.
+    ///
+    /// # Arguments
+    ///
+    /// * `duration` - The time left of the whole sequence, to the millisecond.
+    ///
+    /// # Returns
+    ///
+    /// A [`std::result::Result`] that is:
+    ///
+    /// * `Ok((receiver, handle))` - The sequence has started: a [`ChannelReceiver`] of the time left of each segment,
+    ///   and a [`CountdownHandle`] to the task running them.
+    /// * `Err(err)` - `duration` is zero or longer than the sequence.
+    async fn start(&self, duration: Duration) -> std::result::Result<(ChannelReceiver<Duration>, CountdownHandle), StartError> {
+        let total = self.total();
+        validation::validate_remaining(duration, total)?;
+
+        // Every segment is at most a day long, so each fits in milliseconds.
+        let durations = self.segments.iter().map(|(_, duration)| duration.as_millis() as u64).collect::<Vec<_>>();
+        let elapsed = (total.as_millis() as u64).saturating_sub(duration.as_millis() as u64);
+        let mut end = 0;
+        let (index, remaining) = durations.iter().enumerate()
+            .find_map(|(index, duration)| {
+                end += duration;
+                (end > elapsed).then(|| (index, end - elapsed))
+            })
+            .expect("some time should be left of the sequence");
+
+        let duration = durations[index];
+        let completed = u32::try_from(index).unwrap_or(u32::MAX);
+        let next = durations.into_iter().skip(index + 1);
+        let labels = self.segments.iter().map(|(label, _)| label.clone()).collect();
+        let cycle = Cycle::new(duration, remaining);
+        Ok(self.timer.spawn_cycles(&mut self.timer.tasks(), None, cycle, completed, next, Some(labels)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+    use tokio::time::{self, Instant};
+
+    use crate::{
+        countdown::{CloseReason, Direction, Progress, Receiver, Response},
+        run_with_output,
+    };
+
+    use super::*;
+
+    fn timer() -> AsyncCountdown {
+        AsyncCountdown::try_new(Duration::from_millis(100)).expect("should have created countdown")
+    }
+
+    #[tokio::test]
+    async fn should_count_down_every_segment_in_turn() {
+        time::pause();
+        let sequence = Sequence::try_new(timer(), [
+            ("work", Duration::from_millis(300)),
+            ("break", Duration::from_millis(100)),
+            ("work", Duration::from_millis(200)),
+        ]).expect("should have created the sequence");
+        let started_at = Instant::now();
+
+        let (rx, handle) = sequence.start(Duration::from_millis(600)).await.expect("unexpected sequence failure");
+
+        let mut responses = Vec::new();
+        loop {
+            let response = rx.recv().await.expect("unexpected error receiving");
+            let closed = matches!(response, Response::Closed(_));
+            responses.push(response);
+            if closed {
+                break;
+            }
+        }
+
+        assert_eq!(responses, [
+            Response::Value(Duration::from_millis(300)),
+            Response::Value(Duration::from_millis(200)),
+            Response::Value(Duration::from_millis(100)),
+            Response::Value(Duration::ZERO),
+            Response::CycleCompleted(1),
+            Response::Value(Duration::from_millis(100)),
+            Response::Value(Duration::ZERO),
+            Response::CycleCompleted(2),
+            Response::Value(Duration::from_millis(200)),
+            Response::Value(Duration::from_millis(100)),
+            Response::Value(Duration::ZERO),
+            Response::Closed(CloseReason::Completed),
+        ]);
+        // The paused clock rounds each timer up to the next whole millisecond.
+        assert!(started_at.elapsed().as_millis().abs_diff(600) <= 3, "took {:?}", started_at.elapsed());
+        assert_eq!(handle.wait().await, Ok(()));
+    }
+
+    #[tokio::test]
+    async fn should_resume_a_sequence_part_way_through_a_segment() {
+        time::pause();
+        let sequence = Sequence::try_new(timer(), [("work", Duration::from_millis(300)), ("break", Duration::from_millis(200))])
+            .expect("should have created the sequence");
+
+        let (rx, _) = sequence.start(Duration::from_millis(100)).await.expect("unexpected sequence failure");
+
+        assert_eq!(
+            rx.recv_progress().await,
+            Ok(Response::Value(Progress { remaining: Duration::from_millis(100), total: Duration::from_millis(200), tick_index: 0 })),
+        );
+        assert_eq!(rx.recv().await, Ok(Response::Value(Duration::ZERO)));
+        assert_eq!(rx.recv().await, Ok(Response::Closed(CloseReason::Completed)));
+    }
+
+    #[tokio::test]
+    async fn should_measure_the_progress_of_each_segment_against_its_own_duration() {
+        time::pause();
+        let sequence = Sequence::try_new(timer(), [("work", Duration::from_millis(100)), ("break", Duration::from_millis(200))])
+            .expect("should have created the sequence");
+        let (rx, _) = sequence.start(Duration::from_millis(300)).await.expect("unexpected sequence failure");
+
+        let mut totals = Vec::new();
+        loop {
+            match rx.recv_progress().await.expect("unexpected error receiving progress") {
+                Response::Value(progress) => totals.push(progress.total),
+                Response::CycleCompleted(_) => {},
+                Response::Closed(_) => break,
+            }
+        }
+
+        assert_eq!(totals, [100, 100, 200, 200, 200].map(Duration::from_millis));
+    }
+
+    #[rstest]
+    #[case::nothing_left(0, InvalidDuration::ZeroDuration.into())]
+    #[case::longer_than_the_sequence(301, InvalidDuration::RemainingGreaterThanDuration {
+        remaining: Duration::from_millis(301),
+        duration: Duration::from_millis(300),
+    }.into())]
+    #[tokio::test]
+    async fn should_fail_to_start_a_sequence_given_an_invalid_time_left(#[case] duration_millis: u64, #[case] expected: StartError) {
+        let sequence = Sequence::try_new(timer(), [("work", Duration::from_millis(200)), ("break", Duration::from_millis(100))])
+            .expect("should have created the sequence");
+
+        assert_eq!(sequence.start(Duration::from_millis(duration_millis)).await.map(drop), Err(expected));
+    }
+
+    #[rstest]
+    #[case::empty(Vec::new(), StartError::EmptySequence)]
+    #[case::shorter_than_the_period(vec![("work", 1000), ("break", 50)], StartError::InvalidSegment {
+        index: 1,
+        label: "break".to_owned(),
+        source: InvalidDuration::DurationSmallerThanPeriod { duration: Duration::from_millis(50), period: Duration::from_millis(100) },
+    })]
+    #[case::longer_than_a_day(vec![("sleep", 24 * 60 * 60 * 1000 + 1)], StartError::InvalidSegment {
+        index: 0,
+        label: "sleep".to_owned(),
+        source: InvalidDuration::DurationGreaterThanOneDay(Duration::from_millis(24 * 60 * 60 * 1000 + 1)),
+    })]
+    fn should_fail_to_create_an_invalid_sequence(#[case] segments: Vec<(&str, u64)>, #[case] expected: StartError) {
+        let segments = segments.into_iter().map(|(label, millis)| (label, Duration::from_millis(millis)));
+
+        assert_eq!(Sequence::try_new(timer(), segments).map(drop), Err(expected));
+    }
+
+    #[test]
+    fn should_fail_to_create_a_count_up_sequence_with_an_uncapped_segment() {
+        let timer = AsyncCountdown::builder()
+            .period(Duration::from_millis(100))
+            .direction(Direction::Up)
+            .build()
+            .expect("should have created countdown");
+
+        assert_eq!(
+            Sequence::try_new(timer, [("warm up", Duration::from_millis(200)), ("run", Duration::ZERO)]).map(drop),
+            Err(StartError::InvalidSegment { index: 1, label: "run".to_owned(), source: InvalidDuration::ZeroDuration }),
+        );
+    }
+
+    #[tokio::test]
+    async fn should_run_a_sequence_to_completion() {
+        time::pause();
+        let sequence = Sequence::try_new(timer(), [("work", Duration::from_millis(200)), ("break", Duration::from_millis(100))])
+            .expect("should have created the sequence");
+        let total = sequence.total();
+
+        let guard = run_with_output(sequence, total, std::io::sink()).await.expect("should have started the sequence");
+        let outcome = guard.wait().await.expect("should have run the sequence");
+
+        assert_eq!(outcome.reason, CloseReason::Completed);
+        assert_eq!(outcome.remaining, Duration::ZERO);
+    }
+}
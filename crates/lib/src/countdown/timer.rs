@@ -1,49 +1,342 @@
-use std::sync::Arc;
+use std::{
+    fmt,
+    future::Future,
+    iter,
+    panic::{self, AssertUnwindSafe},
+    sync::{self, atomic::{AtomicU64, Ordering}, Arc, PoisonError},
+    thread,
+    time::{SystemTime, UNIX_EPOCH},
+};
 
-use thiserror::Error;
 use tokio::{
-    sync::Mutex,
-    time::{self, Duration, Interval},
+    sync::{self as async_sync, mpsc},
+    task::{AbortHandle, JoinHandle},
+    time::{self, Duration, Instant, Interval, MissedTickBehavior},
+};
+
+use crate::core::{schedule, validation};
+
+use super::{
+    channel::{self, AckStrategy, AddedTime, Channel, ChannelCloser, ChannelReceiver, ChannelSender},
+    CloseReason, Countdown, FailureCode, InvalidDuration, Result, Sender, StartError, TaskError, TimerError,
 };
 
-use super::{channel::{Channel, ChannelReceiver}, Countdown, Result, Sender};
+/// The period of a timer that was not given one.
+const DEFAULT_PERIOD: Duration = Duration::from_millis(1000);
+/// How many periods a receiver waits for an update before timing out, unless that is shorter than the channel's
+/// default timeout.
+const TIMEOUT_PERIODS: u32 = 3;
+/// How late a tick can complete and still count as on time: the timer rounds every deadline up to the next
+/// millisecond.
+const TIMER_RESOLUTION: Duration = Duration::from_millis(1);
+/// The shortest period at which receivers acknowledge every value. Below it, acknowledging costs more than the values
+/// it protects, since the final value is delivered before the channel closes regardless.
+const HANDSHAKE_MIN_PERIOD: Duration = Duration::from_millis(100);
+/// The length, in milliseconds, of a cycle that counts up without a cap, which never runs out.
+const UNCAPPED: u64 = u64::MAX;
 
-#[derive(Debug, Error, PartialEq)]
-pub enum TimerError {
-    #[error(transparent)]
-    InvalidCountdown(#[from] InvalidCountdown),
-    #[error(transparent)]
-    InvalidDuration(#[from] InvalidDuration),
+/// What [`AsyncCountdown::start_with_key`] does when a countdown with the same key is still running.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum DuplicateStart {
+    /// Returns a receiver for the countdown that is already running.
+    #[default]
+    ReturnExisting,
+    /// Fails with [`StartError::AlreadyRunning`].
+    Reject,
 }
 
-#[derive(Debug, Error, PartialEq)]
-pub enum InvalidCountdown {
-    #[error("Interval cannot be zero")]
-    ZeroInterval,
-    #[error("Interval {0:?} cannot be greater than one hour")]
-    IntervalGreaterThanOneHour(Duration),
+/// What the interval of each countdown an [`AsyncCountdown`] starts does about the ticks it missed, e.g. because the receiver was slow to
+/// acknowledge a value or the host was suspended. Set with [`AsyncCountdownBuilder::missed_ticks`].
+///
+/// Whichever it does, the next value sent is the time really left, and the values the missed ticks would have sent are
+/// never replayed.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum MissedTicks {
+    /// Ticks straight away for each tick missed, until the interval has caught up with its schedule.
+    Burst,
+    /// Ticks once straight away, then a whole period after that, moving the schedule back by however late it was.
+    Delay,
+    /// Ticks once straight away, then carries on with the schedule from the next tick due.
+    #[default]
+    Skip,
 }
 
-#[derive(Debug, Error, PartialEq)]
-pub enum InvalidDuration {
-    #[error("Duration cannot be zero")]
-    ZeroDuration,
-    #[error("Duration {0:?} cannot be greater than one day")]
-    DurationGreaterThanOneDay(Duration),
-    #[error("Duration {duration:?} cannot be smaller than period {period:?}")]
-    DurationSmallerThanPeriod{duration: Duration, period: Duration},
+/// How many times each countdown an [`AsyncCountdown`] starts runs through its duration. Set with
+/// [`AsyncCountdownBuilder::repeat`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Repeat {
+    /// Runs once, then closes.
+    #[default]
+    Once,
+    /// Runs this many cycles in all, then closes. Zero cycles run once, like one.
+    Cycles(u32),
+    /// Starts again every time it runs out, until it is cancelled.
+    Forever,
+}
+
+/// Which way each countdown an [`AsyncCountdown`] starts counts. Set with [`AsyncCountdownBuilder::direction`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Direction {
+    /// Sends the time left, from the duration down to zero.
+    #[default]
+    Down,
+    /// Sends the time elapsed, from zero up to the duration, which caps it. A duration of zero counts up
+    /// without a cap, or up to the timer's maximum duration if it has one, until it is cancelled.
+    Up,
 }
 
 /// A countdown that counts down from a specified duration.
+///
+/// # Dropping
+///
+/// Dropping an [`AsyncCountdown`] aborts every countdown it started that is still running. The receivers of those
+/// countdowns observe [`super::Response::Closed`] with [`super::CloseReason::Cancelled`] on their next receive rather
+/// than timing out.
+/// Use [`AsyncCountdownBuilder::detach_on_drop`] to let running countdowns carry on to completion instead.
+///
+/// # Testing
+///
+/// Countdowns run on tokio's clock, so they can be tested inside `#[tokio::test(start_paused = true)]` without calling
+/// `advance`: the clock jumps straight to each tick while the receiver waits. The only timeouts are the receiver's
+/// wait for the next value and the sender's wait for its acknowledgement, both at least three periods long, so they
+/// only fire if the other side really stops. Block in tokio-aware futures only: a thread blocked outside tokio looks
+/// idle to the paused clock, which then races ahead to those timeouts.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+///
+/// use libtomatillo::countdown::{AsyncCountdown, CloseReason, Countdown, Receiver, Response};
+///
+/// # #[tokio::main(flavor = "current_thread", start_paused = true)]
+/// # async fn main() {
+/// let timer = AsyncCountdown::try_new(Duration::from_millis(100)).expect("should have created the timer");
+/// let (rx, _) = timer.start(Duration::from_millis(1000)).await.expect("should have started the countdown");
+/// assert_eq!(rx.recv().await, Ok(Response::Value(Duration::from_millis(1000))));
+///
+/// drop(timer);
+///
+/// assert_eq!(rx.recv().await, Ok(Response::Closed(CloseReason::Cancelled)));
+/// # }
+/// ```
 #[derive(Debug)]
 pub struct AsyncCountdown {
-    interval: Arc<Mutex<Interval>>
+    period: Duration,
+    missed_ticks: MissedTicks,
+    tasks: sync::Mutex<Vec<CountdownTask>>,
+    detach_on_drop: bool,
+    duplicate_start: DuplicateStart,
+    align_to_wall_clock: bool,
+    wall_clock: fn() -> SystemTime,
+    channel_timeout: Duration,
+    max_duration: Option<Duration>,
+    repeat: Repeat,
+    direction: Direction,
+    hooks: Hooks,
+    time_scale: TimeScale,
+    lag_warning: Option<(u16, u8)>,
+}
+
+/// Configures an [`AsyncCountdown`], checking every option together when it is built. Returned by
+/// [`AsyncCountdown::builder`].
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+///
+/// use libtomatillo::countdown::{AsyncCountdown, InvalidDuration, MissedTicks, StartError};
+///
+/// # #[tokio::main(flavor = "current_thread", start_paused = true)]
+/// # async fn main() {
+/// let timer = AsyncCountdown::builder()
+///     .period(Duration::from_millis(100))
+///     .missed_ticks(MissedTicks::Delay)
+///     .channel_timeout(Duration::from_secs(1))
+///     .max_duration(Duration::from_secs(60))
+///     .build()
+///     .expect("should have created the timer");
+///
+/// assert_eq!(
+///     timer.start_with_key("focus", Duration::from_millis(61_000)).await.map(drop),
+///     Err(StartError::InvalidDuration(InvalidDuration::DurationGreaterThanMaximum {
+///         duration: Duration::from_secs(61),
+///         maximum: Duration::from_secs(60),
+///     })),
+/// );
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct AsyncCountdownBuilder {
+    period: Duration,
+    missed_ticks: MissedTicks,
+    channel_timeout: Option<Duration>,
+    max_duration: Option<Duration>,
+    time_scale: Option<f64>,
+    detach_on_drop: bool,
+    duplicate_start: DuplicateStart,
+    align_to_wall_clock: bool,
+    repeat: Repeat,
+    direction: Direction,
+    hooks: Hooks,
+    lag_warning: Option<(u16, u8)>,
+}
+
+/// A handle to the task running a countdown started by [`Countdown::start`], to wait for it to finish, skip it to the
+/// end or abort it, or to look at how it is going without receiving its values.
+///
+/// # Dropping
+///
+/// Dropping a [`CountdownHandle`] detaches the task: the countdown carries on until it completes, or until the
+/// [`AsyncCountdown`] that started it is dropped.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+///
+/// use libtomatillo::countdown::{AsyncCountdown, CloseReason, Countdown, Receiver, Response, TaskError};
+///
+/// # #[tokio::main(flavor = "current_thread", start_paused = true)]
+/// # async fn main() {
+/// let timer = AsyncCountdown::try_new(Duration::from_millis(100)).expect("should have created the timer");
+/// let (rx, handle) = timer.start(Duration::from_millis(60_000)).await.expect("should have started the countdown");
+/// assert_eq!(rx.recv().await, Ok(Response::Value(Duration::from_millis(60_000))));
+///
+/// handle.abort();
+///
+/// assert_eq!(rx.recv().await, Ok(Response::Closed(CloseReason::Cancelled)));
+/// assert_eq!(handle.wait().await, Err(TaskError::Aborted));
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct CountdownHandle {
+    task: Arc<SharedTask>,
+    closer: ChannelCloser<Duration>,
+    tx: SnapshotSender,
+    direction: Direction,
+    #[cfg(feature = "demo_mode")]
+    demo: DemoControls,
+}
+
+/// The task running a countdown, shared by every [`CountdownHandle`] to it so that each of them can wait for it.
+#[derive(Debug)]
+struct SharedTask {
+    abort: AbortHandle,
+    outcome: async_sync::Mutex<TaskOutcome>,
+}
+
+#[derive(Debug)]
+enum TaskOutcome {
+    Running(JoinHandle<Result<()>>),
+    Finished(std::result::Result<(), TaskError>),
+}
+
+/// Sends a countdown's values on its channel, keeping the last one sent for [`CountdownHandle::remaining`] and
+/// passing it on to the countdown's hooks, if it has any.
+#[derive(Debug, Clone)]
+struct SnapshotSender {
+    tx: ChannelSender<Duration>,
+    /// The last value sent, in milliseconds.
+    latest: Arc<AtomicU64>,
+    hooks: Option<mpsc::UnboundedSender<HookEvent>>,
+}
+
+/// A hook registered with [`AsyncCountdownBuilder::on_tick`].
+type TickHook = Arc<sync::Mutex<dyn Fn(Duration) + Send>>;
+/// A hook registered with [`AsyncCountdownBuilder::on_complete`].
+type CompleteHook = Arc<sync::Mutex<dyn Fn() + Send>>;
+
+/// The side effects registered with [`AsyncCountdownBuilder::on_tick`] and [`AsyncCountdownBuilder::on_complete`]. Each
+/// is behind a lock, so that hooks need not be `Sync` to be shared by every countdown the timer starts.
+#[derive(Clone, Default)]
+struct Hooks {
+    tick: Vec<TickHook>,
+    complete: Vec<CompleteHook>,
+}
+
+/// What a countdown tells the thread running its hooks. A tick carries the value sent, in milliseconds.
+#[derive(Debug, Clone, Copy)]
+enum HookEvent {
+    Tick(u64),
+    Complete,
+}
+
+/// How many times faster than real time the countdowns of an [`AsyncCountdown`] run, if they run faster or slower at
+/// all. Set with [`AsyncCountdownBuilder::time_scale`].
+#[derive(Debug, Clone, Copy, Default)]
+struct TimeScale(Option<f64>);
+
+/// How far into its cycle a countdown's task has counted, and where it hears the demo controls that change how far or
+/// how fast.
+#[derive(Debug)]
+struct Pacing {
+    pace: Pace,
+    #[cfg(feature = "demo_mode")]
+    controls: mpsc::UnboundedReceiver<DemoControl>,
+}
+
+/// How far into a cycle its task had counted at an instant on tokio's clock, and how fast it counts from there.
+#[derive(Debug, Clone, Copy)]
+struct Pace {
+    at: Instant,
+    /// The milliseconds of the cycle counted by then.
+    ticked: u64,
+    time_scale: TimeScale,
+}
+
+/// What [`CountdownHandle::set_speed`] and [`CountdownHandle::set_remaining`] tell a countdown's task.
+#[cfg(feature = "demo_mode")]
+#[derive(Debug, Clone, Copy)]
+enum DemoControl {
+    /// Count at `scale` times real time from now on, ticking every `period` of real time.
+    Speed { scale: f64, period: Duration },
+    /// Jump to this many milliseconds left of the cycle.
+    Remaining(u64),
+}
+
+/// Where a [`CountdownHandle`] sends its demo controls, and what it checks them against first.
+#[cfg(feature = "demo_mode")]
+#[derive(Debug, Clone)]
+struct DemoControls {
+    tx: mpsc::UnboundedSender<DemoControl>,
+    /// The countdown's period, unless it keeps to the wall clock, which runs in real time whatever its speed.
+    period: Option<Duration>,
+    /// How long the countdown's receivers wait for a value, which must stay longer than the period at any speed.
+    timeout: Duration,
+}
+
+/// A cycle of `duration` milliseconds for a task to count, with `remaining` left of it.
+#[derive(Debug, Clone, Copy)]
+pub(super) struct Cycle {
+    duration: u64,
+    remaining: u64,
+    /// The wall clock that a countdown to a deadline reads the time left on, from when it read `remaining` on it.
+    wall_clock: Option<WallClock>,
+}
+
+/// The wall clock a cycle reads, and what it read when the cycle started.
+#[derive(Debug, Clone, Copy)]
+struct WallClock {
+    now: fn() -> SystemTime,
+    started: SystemTime,
+}
+
+#[derive(Debug)]
+pub(super) struct CountdownTask {
+    task: Arc<SharedTask>,
+    closer: ChannelCloser<Duration>,
+    key: Option<String>,
+    receiver: ChannelReceiver<Duration>,
+    tx: SnapshotSender,
+    #[cfg(feature = "demo_mode")]
+    demo: DemoControls,
 }
 
 impl Default for AsyncCountdown {
     fn default() -> Self {
-        const DEFAULT_PERIOD: u64 = 1000;
-        Self::try_new(DEFAULT_PERIOD).expect("failed to create default timer")
+        Self::builder().build().expect("failed to create default timer")
     }
 }
 
@@ -60,143 +353,3375 @@ impl AsyncCountdown {
     ///
     /// * `Ok(timer)` - The countdown timer has been created.
     /// * `Err(err)` - The countdown timer could not be created.
-    pub fn try_new(period_millis: u64) -> Result<Self> {
-        validate_period(period_millis)?;
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use libtomatillo::countdown::AsyncCountdown;
+    ///
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// assert!(AsyncCountdown::try_new(Duration::from_secs(1)).is_ok());
+    /// assert!(AsyncCountdown::try_new(Duration::ZERO).is_err());
+    /// # }
+    /// ```
+    pub fn try_new(period: Duration) -> Result<Self> {
+        Self::builder().period(period).build()
+    }
 
-        Ok(Self { interval: Arc::new(Mutex::new(time::interval(Duration::from_millis(period_millis)))) })
+    /// Creates a new [`Countdown`] timer ticking every `period_millis` milliseconds, like [`AsyncCountdown::try_new`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use libtomatillo::countdown::AsyncCountdown;
+    ///
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// let timer = AsyncCountdown::try_from_millis(250).expect("should have created the timer");
+    ///
+    /// assert!(timer.validate(Duration::from_millis(200)).is_err());
+    /// # }
+    /// ```
+    pub fn try_from_millis(period_millis: u64) -> Result<Self> {
+        Self::try_new(Duration::from_millis(period_millis))
     }
 
-    async fn validate_duration(&self, duration: u64) -> Result<()> {
-        if duration == 0 {
-            return Err(TimerError::InvalidDuration(InvalidDuration::ZeroDuration).into());
+    /// Returns an [`AsyncCountdownBuilder`] to configure a timer with, ticking every second unless told otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use libtomatillo::countdown::AsyncCountdown;
+    ///
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// assert!(AsyncCountdown::builder().period(Duration::from_millis(250)).build().is_ok());
+    /// assert!(AsyncCountdown::builder().period(Duration::ZERO).build().is_err());
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn builder() -> AsyncCountdownBuilder {
+        AsyncCountdownBuilder {
+            period: DEFAULT_PERIOD,
+            missed_ticks: MissedTicks::default(),
+            channel_timeout: None,
+            max_duration: None,
+            time_scale: None,
+            detach_on_drop: false,
+            duplicate_start: DuplicateStart::default(),
+            align_to_wall_clock: false,
+            repeat: Repeat::default(),
+            direction: Direction::default(),
+            hooks: Hooks::default(),
+            lag_warning: None,
         }
-    
-        if duration > 86_400 {
-            return Err(TimerError::InvalidDuration(InvalidDuration::DurationGreaterThanOneDay(Duration::from_millis(duration))).into());
+    }
+
+    /// Starts a countdown identified by `key`, unless one with the same key is still running.
+    ///
+    /// A key becomes reusable as soon as its countdown has closed.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - Identifies the countdown, e.g. the action that triggered it.
+    /// * `duration` - The duration of the countdown, to the millisecond.
+    ///
+    /// # Returns
+    ///
+    /// A [`Result`] that is:
+    ///
+    /// * `Ok((receiver, handle))` - A [`ChannelReceiver`] of the time remaining, and a [`CountdownHandle`] to
+    ///   the task running it. If a countdown with the same key was already running and
+    ///   [`DuplicateStart::ReturnExisting`] is set, both are of that countdown: its values are shared with the other
+    ///   receivers of that countdown rather than copied to each of them, and the handle is to its task.
+    /// * `Err(err)` - The countdown could not be started, or it is already running and [`DuplicateStart::Reject`] is set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use libtomatillo::countdown::{AsyncCountdown, CloseReason, Receiver, Response};
+    ///
+    /// # #[tokio::main(flavor = "current_thread", start_paused = true)]
+    /// # async fn main() {
+    /// let timer = AsyncCountdown::try_new(Duration::from_millis(100)).expect("should have created the timer");
+    /// let (rx, _) = timer.start_with_key("focus", Duration::from_millis(1000)).await.expect("should have started the countdown");
+    /// let (same, handle) = timer.start_with_key("focus", Duration::from_millis(1000)).await.expect("should have returned the running countdown");
+    ///
+    /// assert_eq!(same.recv().await, Ok(Response::Value(Duration::from_millis(1000))));
+    /// handle.abort();
+    /// assert_eq!(rx.recv().await, Ok(Response::Closed(CloseReason::Cancelled)));
+    /// # }
+    /// ```
+    pub async fn start_with_key(
+        &self,
+        key: &str,
+        duration: Duration,
+    ) -> std::result::Result<(ChannelReceiver<Duration>, CountdownHandle), StartError> {
+        self.validate(duration)?;
+        let duration_millis = millis(duration);
+
+        let existing = {
+            let mut tasks = self.tasks();
+            match tasks.iter().find(|task| task.is_running_as(key)) {
+                Some(task) => (task.receiver.clone(), task.handle(self.direction)),
+                None => return Ok(self.spawn(&mut tasks, Some(key), duration_millis, duration_millis)),
+            }
+        };
+
+        match self.duplicate_start {
+            DuplicateStart::ReturnExisting => Ok(existing),
+            DuplicateStart::Reject => {
+                Err(StartError::AlreadyRunning { key: key.to_owned(), remaining: existing.0.latest().await })
+            }
         }
+    }
+
+    /// Cancels every countdown this timer started that is still running, then starts a new one.
+    ///
+    /// The receivers of the cancelled countdowns observe [`super::Response::Closed`] with
+    /// [`super::CloseReason::Cancelled`], and the new receiver only ever sees values of the new countdown.
+    ///
+    /// # Arguments
+    ///
+    /// * `duration` - The duration of the new countdown, to the millisecond.
+    ///
+    /// # Returns
+    ///
+    /// A [`std::result::Result`] that is:
+    ///
+    /// * `Ok((receiver, handle))` - The new countdown has started: a [`ChannelReceiver`] of the time it has remaining,
+    ///   and a [`CountdownHandle`] to the task running it.
+    /// * `Err(err)` - The new countdown could not be started, see [`AsyncCountdown::validate`]. The running countdowns
+    ///   are left running.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use libtomatillo::countdown::{AsyncCountdown, CloseReason, Countdown, Receiver, Response};
+    ///
+    /// # #[tokio::main(flavor = "current_thread", start_paused = true)]
+    /// # async fn main() {
+    /// let timer = AsyncCountdown::try_new(Duration::from_millis(100)).expect("should have created the timer");
+    /// let (first, _) = timer.start(Duration::from_millis(1000)).await.expect("should have started the countdown");
+    ///
+    /// let (second, _) = timer.restart(Duration::from_millis(500)).await.expect("should have restarted the countdown");
+    ///
+    /// assert_eq!(first.recv().await, Ok(Response::Closed(CloseReason::Cancelled)));
+    /// assert_eq!(second.recv().await, Ok(Response::Value(Duration::from_millis(500))));
+    /// # }
+    /// ```
+    pub async fn restart(&self, duration: Duration) -> std::result::Result<(ChannelReceiver<Duration>, CountdownHandle), StartError> {
+        self.validate(duration)?;
+        let duration_millis = millis(duration);
 
-        let period = self.interval.lock().await.period();
-        if period > Duration::from_millis(duration) {
-            return Err(TimerError::InvalidDuration(InvalidDuration::DurationSmallerThanPeriod{duration: Duration::from_millis(duration), period: period.clone()}).into());
+        let mut tasks = self.tasks();
+        for task in tasks.drain(..) {
+            task.abort();
         }
-    
-        Ok(())
+
+        Ok(self.spawn(&mut tasks, None, duration_millis, duration_millis))
     }
-}
 
-impl Countdown<u64> for AsyncCountdown {
-    /// Starts the countdown.
+    /// Starts a countdown part way through, such as one resumed after it was interrupted.
+    ///
+    /// The receiver gets `remaining` straight away and counts down from there like [`Countdown::start`], while
+    /// [`ChannelReceiver::recv_progress`] still measures progress against the whole `duration`.
     ///
     /// # Arguments
     ///
-    /// * `duration` - The duration of the countdown.
+    /// * `duration` - The duration of the whole countdown, to the millisecond.
+    /// * `remaining` - The time left to count down, at most `duration`.
     ///
     /// # Returns
     ///
-    /// A [`Result`] that is:
+    /// A [`std::result::Result`] that is:
+    ///
+    /// * `Ok((receiver, handle))` - The countdown has started: a [`ChannelReceiver`] of the time remaining, and a
+    ///   [`CountdownHandle`] to the task running it.
+    /// * `Err(err)` - The countdown could not be started: `duration` is invalid, see [`AsyncCountdown::validate`], or
+    ///   `remaining` is zero or greater than `duration`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
     ///
-    /// * `Ok(watcher)` - The countdown has started, and a [`ChannelReceiver`] is returned.
-    /// * `Err(err)` - The countdown could not be started.
-    async fn start(&self, duration_millis: u64) -> Result<ChannelReceiver<u64>> {
-        self.validate_duration(duration_millis).await?;
-        
-        let (tx, rx) = Channel::new(duration_millis);   
-        tokio::spawn(countdown(self.interval.clone(), tx, duration_millis));
+    /// use libtomatillo::countdown::{AsyncCountdown, Progress, Response};
+    ///
+    /// # #[tokio::main(flavor = "current_thread", start_paused = true)]
+    /// # async fn main() {
+    /// let timer = AsyncCountdown::try_new(Duration::from_millis(100)).expect("should have created the timer");
+    /// let (rx, _) = timer.start_from(Duration::from_millis(1000), Duration::from_millis(400)).await.expect("should have resumed the countdown");
+    ///
+    /// let progress = rx.recv_progress().await.expect("should have received the remaining time");
+    /// let (remaining, total) = (Duration::from_millis(400), Duration::from_millis(1000));
+    /// assert_eq!(progress, Response::Value(Progress { remaining, total, tick_index: 0 }));
+    /// # }
+    /// ```
+    pub async fn start_from(
+        &self,
+        duration: Duration,
+        remaining: Duration,
+    ) -> std::result::Result<(ChannelReceiver<Duration>, CountdownHandle), StartError> {
+        self.validate(duration)?;
+        validation::validate_remaining(remaining, duration)?;
 
-        Ok(rx)
+        Ok(self.spawn(&mut self.tasks(), None, millis(duration), millis(remaining)))
     }
-}
 
-async fn countdown(interval: Arc<Mutex<Interval>>, tx: impl Sender<u64>, duration: u64) {
-    let period = &interval.lock().await.period();
-    let intervals = calc_intervals(Duration::from_millis(duration), period);
-    let period_ms = period.as_millis() as u64;
+    /// Starts a countdown to `deadline` on the wall clock, such as the end of a meeting, rather than for a duration.
+    ///
+    /// The countdown lasts as long as is left until `deadline` when it starts, and runs once whatever
+    /// [`AsyncCountdownBuilder::repeat`] says. Every tick then reads the time left from the wall clock, so that a clock
+    /// set forward or back, or a host that was suspended, changes what is left rather than when the countdown ends. A
+    /// clock set back can make the time left go up, which [`ChannelReceiver::strict`] rejects.
+    ///
+    /// # Arguments
+    ///
+    /// * `deadline` - When the countdown reaches zero.
+    ///
+    /// # Returns
+    ///
+    /// A [`std::result::Result`] that is:
+    ///
+    /// * `Ok((receiver, handle))` - The countdown has started: a [`ChannelReceiver`] of the time remaining, and a
+    ///   [`CountdownHandle`] to the task running it.
+    /// * `Err(err)` - The countdown could not be started: `deadline` is not in the future, or is too close or too far
+    ///   away for a countdown of the time until it, see [`AsyncCountdown::validate`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::{Duration, SystemTime};
+    ///
+    /// use libtomatillo::countdown::{AsyncCountdown, InvalidDuration, StartError};
+    ///
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// let timer = AsyncCountdown::try_new(Duration::from_millis(1000)).expect("should have created the timer");
+    /// let (_rx, handle) = timer.start_until(SystemTime::now() + Duration::from_secs(90)).await
+    ///     .expect("should have started the countdown");
+    /// handle.abort();
+    ///
+    /// let error = timer.start_until(SystemTime::now() - Duration::from_secs(90)).await.map(drop)
+    ///     .expect_err("should have rejected a deadline in the past");
+    /// assert!(matches!(error, StartError::InvalidDuration(InvalidDuration::DeadlineInPast(passed)) if passed >= Duration::from_secs(90)));
+    /// # }
+    /// ```
+    pub async fn start_until(
+        &self,
+        deadline: SystemTime,
+    ) -> std::result::Result<(ChannelReceiver<Duration>, CountdownHandle), StartError> {
+        let now = (self.wall_clock)();
+        let duration = match deadline.duration_since(now) {
+            Ok(duration) if duration.as_millis() > 0 => duration,
+            Ok(_) => return Err(InvalidDuration::DeadlineInPast(Duration::ZERO).into()),
+            Err(passed) => return Err(InvalidDuration::DeadlineInPast(passed.duration()).into()),
+        };
+        self.validate(duration)?;
+
+        let duration_millis = millis(duration);
+        let wall_clock = WallClock { now: self.wall_clock, started: now };
+        let cycle = Cycle { wall_clock: Some(wall_clock), ..Cycle::new(duration_millis, duration_millis) };
+        Ok(self.spawn_cycles(&mut self.tasks(), None, cycle, 0, iter::empty(), None))
+    }
+
+    /// Checks that a countdown of `duration` could be started, without starting it.
+    ///
+    /// This neither blocks nor waits on a running countdown, so it is suitable for validating input as it is entered. A
+    /// countdown that counts up, see [`AsyncCountdownBuilder::direction`], also accepts a duration of zero, meaning no
+    /// cap, unless it repeats.
+    ///
+    /// # Returns
+    ///
+    /// A [`std::result::Result`] that is:
+    ///
+    /// * `Ok(())` - [`Countdown::start`] would accept `duration`.
+    /// * `Err(err)` - The reason [`Countdown::start`] would reject `duration`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use libtomatillo::countdown::{AsyncCountdown, InvalidDuration};
+    ///
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// let timer = AsyncCountdown::try_new(Duration::from_millis(1000)).expect("should have created the timer");
+    ///
+    /// assert_eq!(timer.validate(Duration::from_secs(5)), Ok(()));
+    /// assert_eq!(
+    ///     timer.validate(Duration::from_millis(500)),
+    ///     Err(InvalidDuration::DurationSmallerThanPeriod { duration: Duration::from_millis(500), period: Duration::from_secs(1) }),
+    /// );
+    /// # }
+    /// ```
+    pub fn validate(&self, duration: Duration) -> std::result::Result<(), InvalidDuration> {
+        if self.direction == Direction::Up && duration.is_zero() {
+            return match self.repeat.cycles_after_first() {
+                0 => Ok(()),
+                _ => Err(InvalidDuration::UncappedRepeat),
+            };
+        }
+
+        validation::validate_duration(duration, self.period)?;
+        validation::validate_within_maximum(duration, self.max_duration)
+    }
 
-    for i in 0..=intervals {
-        interval.lock().await.tick().await;
+    fn channel_timeout_ms(&self) -> u32 {
+        u32::try_from(self.channel_timeout.as_millis()).unwrap_or(u32::MAX)
+    }
 
-        tx.send(duration - (period_ms * i as u64)).await.expect("unexpected error sending value");
+    fn ack_strategy(&self) -> AckStrategy {
+        if self.time_scale.real(self.period) < HANDSHAKE_MIN_PERIOD {
+            AckStrategy::FireAndForget
+        } else {
+            AckStrategy::Handshake
+        }
     }
 
-    tx.close().await.expect("unexpected error closing channel");
-}
+    /// Returns how long after starting a countdown its first tick is due.
+    fn first_tick(&self) -> Duration {
+        if !self.align_to_wall_clock {
+            return self.period;
+        }
 
-fn validate_period(period: u64) -> Result<()> {
-    if period == 0u64 {
-        return Err(TimerError::InvalidCountdown(InvalidCountdown::ZeroInterval).into());
+        // A wall clock set before the epoch has no boundaries to speak of, so the countdown ticks as if on one.
+        let since_epoch = (self.wall_clock)().duration_since(UNIX_EPOCH).unwrap_or_default();
+        schedule::until_boundary(since_epoch, self.period)
     }
 
-    if period > 3600 * 1000 {
-        return Err(TimerError::InvalidCountdown(InvalidCountdown::IntervalGreaterThanOneHour(Duration::from_millis(period))).into());
+    pub(super) fn tasks(&self) -> sync::MutexGuard<'_, Vec<CountdownTask>> {
+        self.tasks.lock().unwrap_or_else(PoisonError::into_inner)
     }
 
-    Ok(())
-}
+    fn spawn(
+        &self,
+        tasks: &mut Vec<CountdownTask>,
+        key: Option<&str>,
+        duration_millis: u64,
+        remaining_millis: u64,
+    ) -> (ChannelReceiver<Duration>, CountdownHandle) {
+        // Only a count-up validates with a duration of zero, which it counts up to for as long as it is allowed.
+        let (duration_millis, remaining_millis) = match duration_millis {
+            0 => {
+                let cap = self.max_duration.map_or(UNCAPPED, millis);
+                (cap, cap)
+            },
+            duration_millis => (duration_millis, remaining_millis),
+        };
+        let repeats = iter::repeat_n(duration_millis, self.repeat.cycles_after_first());
+        self.spawn_cycles(tasks, key, Cycle::new(duration_millis, remaining_millis), 0, repeats, None)
+    }
+
+    /// Spawns a task counting down the `first` cycle, then each of `next_cycles` in turn, as if `cycles_completed`
+    /// cycles had already run. A count-up sends the time elapsed of each cycle instead. The receiver names each cycle
+    /// with `labels`, if given, see [`ChannelReceiver::segment_label`].
+    pub(super) fn spawn_cycles(
+        &self,
+        tasks: &mut Vec<CountdownTask>,
+        key: Option<&str>,
+        first: Cycle,
+        cycles_completed: u32,
+        next_cycles: impl Iterator<Item = u64> + Send + 'static,
+        labels: Option<Arc<[String]>>,
+    ) -> (ChannelReceiver<Duration>, CountdownHandle) {
+        let init = self.direction.value(first.duration, first.remaining);
+        let (tx, rx) = Channel::new_with_options(Duration::from_millis(init), [
+            channel::with_timeout(self.channel_timeout_ms()),
+            channel::with_ack_strategy(self.ack_strategy()),
+            channel::with_total(Duration::from_millis(first.duration)),
+            channel::with_cycles_completed(cycles_completed),
+        ]
+        .into_iter()
+        .chain(self.max_duration.map(channel::with_max_duration))
+        .chain(self.lag_warning.map(|(window, threshold_percent)| channel::with_lag_warning(window, threshold_percent)))
+        .chain(labels.map(channel::with_labels)));
+        let closer = tx.closer();
+        let handle_closer = tx.closer();
+        let on_panic = tx.closer();
+        let tx = SnapshotSender { tx, latest: Arc::new(AtomicU64::new(init)), hooks: self.hooks.spawn() };
+        tx.run_hooks(HookEvent::Tick(init));
+        let handle_tx = tx.clone();
+        // A countdown to a deadline reads the time left from the wall clock, which runs in real time.
+        let time_scale = if first.wall_clock.is_some() { TimeScale::default() } else { self.time_scale };
+        let interval = interval(time_scale.real(self.period), self.missed_ticks);
+        let first_tick = time_scale.real(self.first_tick());
+        #[cfg(feature = "demo_mode")]
+        let (demo, controls) = {
+            let (tx, controls) = mpsc::unbounded_channel();
+            let keeps_to_wall_clock = first.wall_clock.is_some() || self.align_to_wall_clock;
+            let period = (!keeps_to_wall_clock).then_some(self.period);
+            (DemoControls { tx, period, timeout: self.channel_timeout }, controls)
+        };
+        let pacing = Pacing {
+            pace: Pace { at: Instant::now(), ticked: 0, time_scale },
+            #[cfg(feature = "demo_mode")]
+            controls,
+        };
+        let counting = count_cycles(tx, next_cycles, self.direction, first, interval, first_tick, pacing);
+        let task = tokio::spawn(close_on_panic(on_panic, counting));
+        closer.set_producer(task.abort_handle());
+        let task = Arc::new(SharedTask::new(task));
 
-fn calc_intervals(duration: Duration, period: &Duration) -> u32 {
-    (duration.as_secs_f64() / period.as_secs_f64()).ceil() as u32
+        tasks.retain(|task| !task.task.is_finished());
+        tasks.push(CountdownTask {
+            task: Arc::clone(&task),
+            closer,
+            key: key.map(str::to_owned),
+            receiver: rx.clone(),
+            tx: handle_tx.clone(),
+            #[cfg(feature = "demo_mode")]
+            demo: demo.clone(),
+        });
+
+        let handle = CountdownHandle {
+            task,
+            closer: handle_closer,
+            tx: handle_tx,
+            direction: self.direction,
+            #[cfg(feature = "demo_mode")]
+            demo,
+        };
+        (rx, handle)
+    }
 }
 
-#[cfg(test)]
-mod tests {
-    use tokio::time::Duration;
+impl AsyncCountdownBuilder {
+    /// Sets how often the timer ticks. Defaults to one second.
+    ///
+    /// # Arguments
+    ///
+    /// * `period` - The interval at which the timer should be updated, from 10ms to one hour.
+    #[must_use]
+    pub fn period(mut self, period: Duration) -> Self {
+        self.period = period;
+        self
+    }
 
-    use crate::countdown::{Receiver, Response};
+    /// Sets what the interval of each countdown started does about the ticks it missed. Defaults to
+    /// [`MissedTicks::Skip`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use libtomatillo::countdown::{AsyncCountdown, Countdown, MissedTicks, Receiver, Response};
+    ///
+    /// # #[tokio::main(flavor = "current_thread", start_paused = true)]
+    /// # async fn main() {
+    /// let timer = AsyncCountdown::builder()
+    ///     .period(Duration::from_millis(100))
+    ///     .missed_ticks(MissedTicks::Delay)
+    ///     .build()
+    ///     .expect("should have created the timer");
+    /// let (rx, _) = timer.start(Duration::from_millis(1000)).await.expect("should have started the countdown");
+    ///
+    /// assert_eq!(rx.recv().await, Ok(Response::Value(Duration::from_millis(1000))));
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn missed_ticks(mut self, missed_ticks: MissedTicks) -> Self {
+        self.missed_ticks = missed_ticks;
+        self
+    }
 
-    use super::*;
+    /// Sets how long a receiver waits for the next value, and a sender for its acknowledgement, before timing out.
+    /// Defaults to three periods, and no less than one second.
+    ///
+    /// # Arguments
+    ///
+    /// * `channel_timeout` - The timeout, which must be longer than the period.
+    #[must_use]
+    pub fn channel_timeout(mut self, channel_timeout: Duration) -> Self {
+        self.channel_timeout = Some(channel_timeout);
+        self
+    }
 
-    const HOUR_MS: u64 = 60u64 * 60 * 1000;
-    const DAY_MS: u64 = 24u64 * 60 * 60 * 1000;
+    /// Sets the longest countdown the timer starts, including any time added to it once running. Defaults to the
+    /// longest countdown supported.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_duration` - The longest duration, which must itself be a valid duration of a countdown.
+    #[must_use]
+    pub fn max_duration(mut self, max_duration: Duration) -> Self {
+        self.max_duration = Some(max_duration);
+        self
+    }
 
-    #[tokio::test]
-    async fn should_fail_to_create_a_countdown_given_a_period_of_zero() {
-        let error = AsyncCountdown::try_new(0).expect_err("should have failed");
-        assert_eq!(error, TimerError::InvalidCountdown(InvalidCountdown::ZeroInterval).into());
+    /// Runs every countdown the timer starts `time_scale` times faster than real time, such as to demo a 25 minute
+    /// countdown in 25 seconds at a scale of 60. Defaults to real time.
+    ///
+    /// The timer really ticks every period divided by the scale, but still sends the time left as if it ticked every
+    /// period, so receivers and the view see the same values they would in real time, only sooner. The channel timeout
+    /// stays in real time. A countdown to a deadline, see [`AsyncCountdown::start_until`], always runs in real time.
+    ///
+    /// # Arguments
+    ///
+    /// * `time_scale` - How many times faster than real time to run, finite and greater than zero, and such that the
+    ///   period divided by it is still from 10ms to one hour. A scale below one runs slower than real time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use libtomatillo::countdown::{AsyncCountdown, CloseReason, Countdown, Receiver, Response};
+    /// use tokio::time::Instant;
+    ///
+    /// # #[tokio::main(flavor = "current_thread", start_paused = true)]
+    /// # async fn main() {
+    /// let timer = AsyncCountdown::builder()
+    ///     .period(Duration::from_secs(60))
+    ///     .time_scale(60.0)
+    ///     .build()
+    ///     .expect("should have created the timer");
+    /// let started = Instant::now();
+    /// let (rx, _) = timer.start(Duration::from_millis(25 * 60 * 1000)).await.expect("should have started the countdown");
+    ///
+    /// assert_eq!(rx.recv().await, Ok(Response::Value(Duration::from_millis(25 * 60 * 1000))));
+    /// assert_eq!(rx.recv().await, Ok(Response::Value(Duration::from_millis(24 * 60 * 1000))));
+    /// while let Ok(Response::Value(_)) = rx.recv().await {}
+    ///
+    /// assert_eq!(started.elapsed(), Duration::from_secs(25));
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn time_scale(mut self, time_scale: f64) -> Self {
+        self.time_scale = Some(time_scale);
+        self
     }
 
-    #[tokio::test]
-    async fn should_fail_to_create_a_countdown_given_a_period_of_greater_than_one_hour() {
-        let result = AsyncCountdown::try_new(HOUR_MS + 1).expect_err("should have failed");
-        assert_eq!(result, TimerError::InvalidCountdown(InvalidCountdown::IntervalGreaterThanOneHour(Duration::from_millis(HOUR_MS + 1))).into());
+    /// Lets running countdowns carry on to completion when the [`AsyncCountdown`] built is dropped, instead of aborting
+    /// them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use libtomatillo::countdown::{AsyncCountdown, Countdown, Receiver, Response};
+    ///
+    /// # #[tokio::main(flavor = "current_thread", start_paused = true)]
+    /// # async fn main() {
+    /// let timer = AsyncCountdown::builder()
+    ///     .period(Duration::from_millis(100))
+    ///     .detach_on_drop()
+    ///     .build()
+    ///     .expect("should have created the timer");
+    /// let (rx, _) = timer.start(Duration::from_millis(200)).await.expect("should have started the countdown");
+    /// drop(timer);
+    ///
+    /// let mut last = None;
+    /// while let Ok(Response::Value(left)) = rx.recv().await {
+    ///     last = Some(left);
+    /// }
+    ///
+    /// assert_eq!(last, Some(Duration::ZERO));
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn detach_on_drop(mut self) -> Self {
+        self.detach_on_drop = true;
+        self
     }
 
-    #[tokio::test]
-    async fn should_fail_to_start_a_countdown_given_an_duration_smaller_than_the_interval() {
-        let error = AsyncCountdown::try_new(2000).expect("unexpected error creating a countdown")
-            .start(1000).await.expect_err("should have failed to start");
-        assert_eq!(error, TimerError::InvalidDuration(InvalidDuration::DurationSmallerThanPeriod{duration: Duration::from_millis(1000), period: Duration::from_millis(2000)}).into());
+    /// Sets what [`AsyncCountdown::start_with_key`] does when a countdown with the same key is still running.
+    /// Defaults to [`DuplicateStart::ReturnExisting`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use libtomatillo::countdown::{AsyncCountdown, DuplicateStart, StartError};
+    ///
+    /// # #[tokio::main(flavor = "current_thread", start_paused = true)]
+    /// # async fn main() {
+    /// let timer = AsyncCountdown::builder()
+    ///     .period(Duration::from_millis(100))
+    ///     .duplicate_start(DuplicateStart::Reject)
+    ///     .build()
+    ///     .expect("should have created the timer");
+    /// timer.start_with_key("focus", Duration::from_millis(1000)).await.expect("should have started the countdown");
+    ///
+    /// let error = timer.start_with_key("focus", Duration::from_millis(1000)).await.expect_err("should have rejected the second start");
+    /// assert!(matches!(error, StartError::AlreadyRunning { .. }));
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn duplicate_start(mut self, duplicate_start: DuplicateStart) -> Self {
+        self.duplicate_start = duplicate_start;
+        self
     }
 
-    #[tokio::test]
-    async fn should_fail_to_start_a_countdown_given_a_duration_of_zero() {
-        let error = AsyncCountdown::try_new(100).expect("unexpected error creating a countdown")
-            .start(0).await.expect_err("should have failed to start");
-        assert_eq!(error, TimerError::InvalidDuration(InvalidDuration::ZeroDuration).into());
+    /// Ticks on the wall clock's boundaries of the period, e.g. on each whole second for a period of one second, so that
+    /// the time left changes in step with a clock on the wall.
+    ///
+    /// The first value after the full duration arrives at the next boundary, however far into the period the countdown
+    /// starts, with the time that is really left. Every value after it arrives a whole period later. It cannot be
+    /// combined with a time scale, or with [`MissedTicks::Delay`], which would both move the ticks off the boundaries.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use libtomatillo::countdown::{AsyncCountdown, Countdown, Receiver, Response};
+    ///
+    /// # #[tokio::main(flavor = "current_thread", start_paused = true)]
+    /// # async fn main() {
+    /// let timer = AsyncCountdown::builder()
+    ///     .period(Duration::from_millis(1000))
+    ///     .align_to_wall_clock()
+    ///     .build()
+    ///     .expect("should have created the timer");
+    /// let (rx, _) = timer.start(Duration::from_millis(3000)).await.expect("should have started the countdown");
+    /// assert_eq!(rx.recv().await, Ok(Response::Value(Duration::from_millis(3000))));
+    ///
+    /// // However far into the second the countdown started, the next value comes at the next whole second.
+    /// let Ok(Response::Value(left)) = rx.recv().await else { panic!("should have received a value") };
+    /// assert!((Duration::from_secs(2)..Duration::from_secs(3)).contains(&left));
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn align_to_wall_clock(mut self) -> Self {
+        self.align_to_wall_clock = true;
+        self
     }
 
-    #[tokio::test]
-    async fn should_fail_to_start_a_countdown_given_a_duration_of_greater_than_one_day() {
-        let error = AsyncCountdown::try_new(100).expect("unexpected error creating a countdown")
-            .start(DAY_MS + 1).await.expect_err("should have failed to start");
-        assert_eq!(error, TimerError::InvalidDuration(InvalidDuration::DurationGreaterThanOneDay(Duration::from_millis(DAY_MS + 1))).into());
+    /// Sets how many times each countdown runs. Defaults to [`Repeat::Once`].
+    ///
+    /// A countdown that repeats starts its whole duration again as soon as it reaches zero and the receiver has
+    /// acknowledged it, with no time added. Its receiver gets [`super::Response::CycleCompleted`] between the zero of
+    /// one cycle and the full duration of the next, and [`super::Response::Closed`] once the last cycle has run out.
+    /// The first tick of every cycle after the first is due a whole period after it starts. A receiver that acknowledges
+    /// nothing, see [`ChannelReceiver::ack_strategy`], is not waited for, and may miss the zero of a cycle it was slow
+    /// to receive.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use libtomatillo::countdown::{AsyncCountdown, CloseReason, Countdown, Receiver, Repeat, Response};
+    ///
+    /// # #[tokio::main(flavor = "current_thread", start_paused = true)]
+    /// # async fn main() {
+    /// let timer = AsyncCountdown::builder()
+    ///     .period(Duration::from_millis(100))
+    ///     .repeat(Repeat::Cycles(2))
+    ///     .build()
+    ///     .expect("should have created the timer");
+    /// let (rx, _) = timer.start(Duration::from_millis(100)).await.expect("should have started the countdown");
+    ///
+    /// let mut responses = Vec::new();
+    /// while let Ok(response) = rx.recv().await {
+    ///     let closed = matches!(response, Response::Closed(_));
+    ///     responses.push(response);
+    ///     if closed {
+    ///         break;
+    ///     }
+    /// }
+    ///
+    /// assert_eq!(responses, [
+    ///     Response::Value(Duration::from_millis(100)),
+    ///     Response::Value(Duration::ZERO),
+    ///     Response::CycleCompleted(1),
+    ///     Response::Value(Duration::from_millis(100)),
+    ///     Response::Value(Duration::ZERO),
+    ///     Response::Closed(CloseReason::Completed),
+    /// ]);
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn repeat(mut self, repeat: Repeat) -> Self {
+        self.repeat = repeat;
+        self
     }
 
-    #[tokio::test]
-    async fn should_countdown_to_zero() {
-        time::pause();
-        let timer = AsyncCountdown::try_new(100).expect("should have created countdown");
-        let mut expectations = [1000u64, 900u64, 800u64, 700u64, 600u64, 500u64, 400u64, 300u64, 200u64, 100u64, 0u64].iter().rev().cloned().collect::<Vec<_>>();
-        let num_expect = expectations.len();
+    /// Sets which way each countdown counts. Defaults to [`Direction::Down`].
+    ///
+    /// A countdown that counts up ticks just like one that counts down, but sends the time elapsed rather than
+    /// left, so that [`crate::run`] and the view show them increasing. It closes with
+    /// [`super::CloseReason::Completed`] once it reaches its duration, and never if it has no cap. Its receiver still
+    /// expects values to count down: [`ChannelReceiver::recv_progress`] reports the time elapsed as
+    /// [`super::Progress::remaining`], and [`ChannelReceiver::strict`] rejects every value after the first.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use libtomatillo::countdown::{AsyncCountdown, CloseReason, Countdown, Direction, Receiver, Response};
+    ///
+    /// # #[tokio::main(flavor = "current_thread", start_paused = true)]
+    /// # async fn main() {
+    /// let timer = AsyncCountdown::builder()
+    ///     .period(Duration::from_millis(100))
+    ///     .direction(Direction::Up)
+    ///     .build()
+    ///     .expect("should have created the timer");
+    /// let (rx, _) = timer.start(Duration::from_millis(200)).await.expect("should have started the countdown");
+    ///
+    /// assert_eq!(rx.recv().await, Ok(Response::Value(Duration::ZERO)));
+    /// assert_eq!(rx.recv().await, Ok(Response::Value(Duration::from_millis(100))));
+    /// assert_eq!(rx.recv().await, Ok(Response::Value(Duration::from_millis(200))));
+    /// assert_eq!(rx.recv().await, Ok(Response::Closed(CloseReason::Completed)));
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn direction(mut self, direction: Direction) -> Self {
+        self.direction = direction;
+        self
+    }
 
-        let rx = timer.start(1000).await.expect("unexpected countdown failure");
+    /// Sets when the receiver of each countdown raises a [`super::LagWarning`], taken with
+    /// [`ChannelReceiver::take_lag_warning`] or seen as [`crate::event_loop::AppEvent::Lagging`]. Defaults to more than
+    /// half of the values skipped over the last 10 received.
+    ///
+    /// # Arguments
+    ///
+    /// * `window` - How many of the last values received the share skipped is measured over. Zero never warns.
+    /// * `threshold_percent` - The share of the values sent over the window, as a percentage, above which the receiver
+    ///   warns.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use libtomatillo::countdown::{AsyncCountdown, Countdown, LagWarning, Receiver, Response};
+    /// use tokio::time;
+    ///
+    /// # #[tokio::main(flavor = "current_thread", start_paused = true)]
+    /// # async fn main() {
+    /// let timer = AsyncCountdown::builder()
+    ///     .period(Duration::from_millis(100))
+    ///     .lag_warning(2, 50)
+    ///     .build()
+    ///     .expect("should have created the timer");
+    /// let (rx, _) = timer.start(Duration::from_millis(1000)).await.expect("should have started the countdown");
+    ///
+    /// let mut warning = None;
+    /// while let Ok(Response::Value(_)) = rx.recv().await {
+    ///     warning = warning.or(rx.take_lag_warning());
+    ///     time::sleep(Duration::from_millis(330)).await;
+    /// }
+    ///
+    /// assert_eq!(warning, Some(LagWarning { skipped: 4, window: 2 }));
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn lag_warning(mut self, window: u16, threshold_percent: u8) -> Self {
+        self.lag_warning = Some((window, threshold_percent));
+        self
+    }
 
-        while let Some(expect) = expectations.pop() {
-            if let Ok(Response::Value(millis_left)) = rx.recv().await {
-                assert_eq!(expect, millis_left, "[{:?}] expected {:?}, but got {:?}", num_expect - expectations.len(), expect, millis_left);
-            }
-            time::advance(Duration::from_millis(100u64)).await;
-        }
+    /// Adds a hook that each countdown calls with every value it sends, starting with the first, such as to play a
+    /// sound with a minute left.
+    ///
+    /// Hooks never hold up a countdown: each countdown with hooks calls them from a dedicated thread, outside the
+    /// runtime, in the order they were added, one value after another, while the countdown carries on ticking. A hook
+    /// that blocks for long only delays the hooks after it, even on a `current_thread` runtime. A hook that panics is
+    /// skipped for that value, and neither the countdown nor the other hooks notice.
+    ///
+    /// # Arguments
+    ///
+    /// * `hook` - Called with each value sent: the time left, or elapsed for a count-up.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use libtomatillo::countdown::{AsyncCountdown, Countdown, Receiver, Response};
+    /// use tokio::sync::mpsc;
+    ///
+    /// # #[tokio::main(flavor = "current_thread", start_paused = true)]
+    /// # async fn main() {
+    /// let (minutes, mut seen) = mpsc::unbounded_channel();
+    /// let timer = AsyncCountdown::builder()
+    ///     .period(Duration::from_secs(30))
+    ///     .on_tick(move |left: Duration| {
+    ///         if left.as_secs() % 60 == 0 {
+    ///             let _ = minutes.send(left.as_secs() / 60);
+    ///         }
+    ///     })
+    ///     .build()
+    ///     .expect("should have created the timer");
+    /// let (rx, _) = timer.start(Duration::from_secs(120)).await.expect("should have started the countdown");
+    /// while let Ok(Response::Value(_)) = rx.recv().await {}
+    /// drop(timer);
+    ///
+    /// let mut minutes_left = Vec::new();
+    /// while let Some(minutes) = seen.recv().await {
+    ///     minutes_left.push(minutes);
+    /// }
+    /// assert_eq!(minutes_left, [2, 1, 0]);
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn on_tick(mut self, hook: impl Fn(Duration) + Send + 'static) -> Self {
+        self.hooks.tick.push(Arc::new(sync::Mutex::new(hook)));
+        self
+    }
 
-        assert_eq!(expectations.len(), 0, "unmet expectations: {:?}", expectations.iter().rev().collect::<Vec<_>>());
+    /// Adds a hook that each countdown calls once it has run to the end, or been skipped to it with
+    /// [`CountdownHandle::skip`], after the hooks added with [`AsyncCountdownBuilder::on_tick`] have seen its last
+    /// value.
+    ///
+    /// The countdown queues the hook before it closes its channel, but does not wait for it, so receivers may see it
+    /// close before the hook has run. A countdown that is cancelled or fails never calls it. Hooks run like those added
+    /// with [`AsyncCountdownBuilder::on_tick`], on a thread of their own, and a hook that panics does not affect the
+    /// countdown.
+    ///
+    /// # Arguments
+    ///
+    /// * `hook` - Called when a countdown completes.
+    #[must_use]
+    pub fn on_complete(mut self, hook: impl Fn() + Send + 'static) -> Self {
+        self.hooks.complete.push(Arc::new(sync::Mutex::new(hook)));
+        self
     }
-}
\ No newline at end of file
+
+    /// Creates the timer, once every option has been checked.
+    ///
+    /// # Returns
+    ///
+    /// A [`Result`] that is:
+    ///
+    /// * `Ok(timer)` - The countdown timer has been created.
+    /// * `Err(err)` - An option is invalid: a [`TimerError::InvalidCountdown`] for the period or the channel timeout, a
+    ///   [`TimerError::InvalidMaxDuration`] for the maximum duration, or a [`TimerError::InvalidTimeScale`] or
+    ///   [`TimerError::InvalidScaledPeriod`] for the time scale, or a [`TimerError::IncompatibleOptions`] for
+    ///   [`AsyncCountdownBuilder::align_to_wall_clock`] along with a time scale or [`MissedTicks::Delay`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use libtomatillo::countdown::{AsyncCountdown, CountdownError, InvalidCountdown, TimerError};
+    ///
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// let error = AsyncCountdown::builder()
+    ///     .period(Duration::from_secs(1))
+    ///     .channel_timeout(Duration::from_millis(500))
+    ///     .build()
+    ///     .expect_err("should have rejected the timeout");
+    ///
+    /// assert_eq!(
+    ///     error,
+    ///     CountdownError::TimerError(TimerError::InvalidCountdown(InvalidCountdown::TimeoutNotLongerThanInterval {
+    ///         timeout: Duration::from_millis(500),
+    ///         period: Duration::from_secs(1),
+    ///     })),
+    /// );
+    /// # }
+    /// ```
+    pub fn build(self) -> Result<AsyncCountdown> {
+        validation::validate_period(self.period).map_err(TimerError::from)?;
+
+        let channel_timeout = self.channel_timeout.unwrap_or_else(|| default_channel_timeout(self.period));
+        validation::validate_timeout(channel_timeout, self.period).map_err(TimerError::from)?;
+
+        if let Some(max_duration) = self.max_duration {
+            validation::validate_duration(max_duration, self.period).map_err(TimerError::InvalidMaxDuration)?;
+        }
+
+        if let Some(time_scale) = self.time_scale {
+            validation::validate_time_scale(time_scale, self.period)?;
+        }
+
+        // Ticks on the wall clock's boundaries only stay on them in real time, and with a schedule that never moves.
+        if self.align_to_wall_clock {
+            let conflicts_with = match (self.time_scale, self.missed_ticks) {
+                (Some(_), _) => Some("run at a time scale"),
+                (None, MissedTicks::Delay) => Some("delay missed ticks"),
+                (None, MissedTicks::Burst | MissedTicks::Skip) => None,
+            };
+            if let Some(conflicts_with) = conflicts_with {
+                return Err(TimerError::IncompatibleOptions { option: "align to the wall clock", conflicts_with }.into());
+            }
+        }
+
+        Ok(AsyncCountdown {
+            period: self.period,
+            missed_ticks: self.missed_ticks,
+            tasks: sync::Mutex::new(Vec::new()),
+            detach_on_drop: self.detach_on_drop,
+            duplicate_start: self.duplicate_start,
+            align_to_wall_clock: self.align_to_wall_clock,
+            wall_clock: SystemTime::now,
+            channel_timeout,
+            max_duration: self.max_duration,
+            repeat: self.repeat,
+            direction: self.direction,
+            hooks: self.hooks,
+            time_scale: TimeScale(self.time_scale),
+            lag_warning: self.lag_warning,
+        })
+    }
+}
+
+impl Countdown<Duration> for AsyncCountdown {
+    /// Starts the countdown.
+    ///
+    /// The receiver gets the full duration straight away, then each following value a whole period after the one
+    /// before, however far into the current period the countdown starts. A countdown of exactly one period receives its
+    /// duration, then zero one period later. A duration that is not a whole number of periods receives what is left of
+    /// it at each tick, then zero as soon as it runs out rather than at the tick after. See
+    /// [`AsyncCountdownBuilder::align_to_wall_clock`] to tick on the wall clock's boundaries instead.
+    ///
+    /// # Arguments
+    ///
+    /// * `duration` - The duration of the countdown, to the millisecond.
+    ///
+    /// # Returns
+    ///
+    /// A [`std::result::Result`] that is:
+    ///
+    /// * `Ok((receiver, handle))` - The countdown has started: a [`ChannelReceiver`] of the time remaining, and a
+    ///   [`CountdownHandle`] to the task running it.
+    /// * `Err(err)` - The countdown could not be started, see [`AsyncCountdown::validate`].
+    async fn start(&self, duration: Duration) -> std::result::Result<(ChannelReceiver<Duration>, CountdownHandle), StartError> {
+        self.validate(duration)?;
+        let duration_millis = millis(duration);
+
+        Ok(self.spawn(&mut self.tasks(), None, duration_millis, duration_millis))
+    }
+}
+
+impl Drop for AsyncCountdown {
+    fn drop(&mut self) {
+        if self.detach_on_drop {
+            return;
+        }
+
+        let tasks = self.tasks.get_mut().unwrap_or_else(PoisonError::into_inner);
+        for task in tasks.drain(..) {
+            task.abort();
+        }
+    }
+}
+
+impl CountdownHandle {
+    /// Stops the countdown: its receivers get [`CloseReason::Cancelled`] and no more values.
+    pub fn abort(&self) {
+        self.task.abort.abort();
+        self.closer.close(CloseReason::Cancelled);
+    }
+
+    /// Skips straight to the end of the countdown, such as when the task it times is done early: the receivers get
+    /// the last value, zero, then [`CloseReason::Skipped`], and no more values.
+    ///
+    /// A countdown that repeats ends there, whatever cycle it was on. A count-up sends its duration, with any time
+    /// added, as the last value instead, or the time elapsed if it has no cap. The hooks see the last value and then
+    /// the countdown complete, see [`AsyncCountdownBuilder::on_complete`]. Skipping a countdown that has already
+    /// closed, whichever way it closed, does nothing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use libtomatillo::countdown::{AsyncCountdown, CloseReason, Countdown, Receiver, Response};
+    ///
+    /// # #[tokio::main(flavor = "current_thread", start_paused = true)]
+    /// # async fn main() {
+    /// let timer = AsyncCountdown::try_new(Duration::from_millis(1000)).expect("should have created the timer");
+    /// let (rx, handle) = timer.start(Duration::from_millis(25 * 60 * 1000)).await.expect("should have started the countdown");
+    /// assert_eq!(rx.recv().await, Ok(Response::Value(Duration::from_millis(25 * 60 * 1000))));
+    ///
+    /// handle.skip().await;
+    ///
+    /// assert_eq!(rx.recv().await, Ok(Response::Value(Duration::ZERO)));
+    /// assert_eq!(rx.recv().await, Ok(Response::Closed(CloseReason::Skipped)));
+    /// assert_eq!(handle.wait().await, Ok(()));
+    /// # }
+    /// ```
+    pub async fn skip(&self) {
+        self.tx.skip(self.direction).await;
+    }
+
+    /// Returns whether the task running the countdown has finished, whether it ran to the end, failed, panicked or was
+    /// aborted.
+    #[must_use]
+    pub fn is_finished(&self) -> bool {
+        self.task.is_finished()
+    }
+
+    /// Returns the last value the countdown sent, the time left of it, or elapsed for a count-up, straight away and
+    /// without taking a lock. Reading it neither receives nor acknowledges the value, so receivers get every value
+    /// they would have otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use libtomatillo::countdown::{AsyncCountdown, Countdown};
+    ///
+    /// # #[tokio::main(flavor = "current_thread", start_paused = true)]
+    /// # async fn main() {
+    /// let timer = AsyncCountdown::try_new(Duration::from_millis(100)).expect("should have created the timer");
+    /// let (_rx, handle) = timer.start(Duration::from_secs(60)).await.expect("should have started the countdown");
+    /// assert_eq!(handle.remaining(), Duration::from_secs(60));
+    ///
+    /// tokio::time::sleep(Duration::from_millis(250)).await;
+    ///
+    /// assert_eq!(handle.remaining(), Duration::from_millis(59_800));
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn remaining(&self) -> Duration {
+        Duration::from_millis(self.tx.latest.load(Ordering::Relaxed))
+    }
+
+    /// Runs the countdown `speed` times faster than real time from now on, such as to record a demo of it, like
+    /// [`AsyncCountdownBuilder::time_scale`] does from the start.
+    ///
+    /// The countdown ticks every period divided by `speed`, and still sends the time left as if it had ticked every
+    /// period, so receivers get the values they would have otherwise, only sooner or later. The tick due next stays as
+    /// far into the countdown as it was, and the countdown keeps its speed from one cycle to the next.
+    ///
+    /// # Arguments
+    ///
+    /// * `speed` - How many times faster than real time to run, finite and greater than zero, such that the period
+    ///   divided by it is from 10ms to one hour, and shorter than the channel timeout. A speed of one runs in real
+    ///   time.
+    ///
+    /// # Returns
+    ///
+    /// A [`Result`] that is:
+    ///
+    /// * `Ok(())` - The countdown runs at `speed` from now on.
+    /// * `Err(err)` - `speed` was invalid, the countdown keeps to the wall clock, which runs in real time, or it has
+    ///   closed, see [`TimerError`] and [`super::ChannelError::Closed`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use libtomatillo::countdown::{AsyncCountdown, Countdown, Receiver, Response};
+    /// use tokio::time::Instant;
+    ///
+    /// # #[tokio::main(flavor = "current_thread", start_paused = true)]
+    /// # async fn main() {
+    /// let timer = AsyncCountdown::try_new(Duration::from_secs(1)).expect("should have created the timer");
+    /// let (rx, handle) = timer.start(Duration::from_secs(60)).await.expect("should have started the countdown");
+    /// assert_eq!(rx.recv().await, Ok(Response::Value(Duration::from_secs(60))));
+    ///
+    /// let started_at = Instant::now();
+    /// handle.set_speed(10.0).expect("should have sped the countdown up");
+    ///
+    /// assert_eq!(rx.recv().await, Ok(Response::Value(Duration::from_secs(59))));
+    /// assert_eq!(started_at.elapsed(), Duration::from_millis(100));
+    /// # }
+    /// ```
+    #[cfg(feature = "demo_mode")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "demo_mode")))]
+    pub fn set_speed(&self, speed: f64) -> Result<()> {
+        let Some(period) = self.demo.period else {
+            return Err(TimerError::IncompatibleOptions { option: "set_speed", conflicts_with: "the wall clock" }.into());
+        };
+        let scaled = validation::validate_time_scale(speed, period)?;
+        validation::validate_timeout(self.demo.timeout, scaled)
+            .map_err(|source| TimerError::InvalidScaledPeriod { scale: speed, source })?;
+
+        self.control(DemoControl::Speed { scale: speed, period: scaled })
+    }
+
+    /// Jumps the countdown to `remaining` left of the cycle it is on, such as to show how it ends in a demo without
+    /// waiting for it.
+    ///
+    /// The receivers get `remaining` straight away, then a period less each period from then on, as if the cycle had
+    /// started with `remaining` left. A count-up jumps to `remaining` before its duration and sends the time elapsed
+    /// there instead. A countdown resumed with [`AsyncCountdown::start_from`] jumps back no further than the time it
+    /// had left when it was resumed. Jumping forward sends a value further from the end than the last one, which a
+    /// [`ChannelReceiver::strict`] receiver rejects.
+    ///
+    /// # Arguments
+    ///
+    /// * `remaining` - The time left to jump to, at most the countdown's duration with any time added.
+    ///
+    /// # Returns
+    ///
+    /// A [`Result`] that is:
+    ///
+    /// * `Ok(())` - The countdown jumps to `remaining` straight away.
+    /// * `Err(err)` - `remaining` was more than the countdown's duration, the countdown counts up without a cap, so
+    ///   has no time left to jump to, or it has closed, see [`InvalidDuration::RemainingGreaterThanDuration`],
+    ///   [`TimerError::IncompatibleOptions`] and [`super::ChannelError::Closed`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use libtomatillo::countdown::{AsyncCountdown, CloseReason, Countdown, Receiver, Response};
+    ///
+    /// # #[tokio::main(flavor = "current_thread", start_paused = true)]
+    /// # async fn main() {
+    /// let timer = AsyncCountdown::try_new(Duration::from_secs(1)).expect("should have created the timer");
+    /// let (rx, handle) = timer.start(Duration::from_secs(25 * 60)).await.expect("should have started the countdown");
+    /// assert_eq!(rx.recv().await, Ok(Response::Value(Duration::from_secs(25 * 60))));
+    ///
+    /// handle.set_remaining(Duration::from_secs(1)).expect("should have jumped the countdown");
+    ///
+    /// assert_eq!(rx.recv().await, Ok(Response::Value(Duration::from_secs(1))));
+    /// assert_eq!(rx.recv().await, Ok(Response::Value(Duration::ZERO)));
+    /// assert_eq!(rx.recv().await, Ok(Response::Closed(CloseReason::Completed)));
+    /// # }
+    /// ```
+    #[cfg(feature = "demo_mode")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "demo_mode")))]
+    pub fn set_remaining(&self, remaining: Duration) -> Result<()> {
+        let total = self.tx.tx.total();
+        if self.direction == Direction::Up && millis(total) == UNCAPPED {
+            return Err(TimerError::IncompatibleOptions { option: "set_remaining", conflicts_with: "an uncapped count-up" }.into());
+        }
+        let duration = total.saturating_add(Duration::from_millis(self.closer.added_time().millis()));
+        if remaining > duration {
+            return Err(InvalidDuration::RemainingGreaterThanDuration { remaining, duration }.into());
+        }
+
+        self.control(DemoControl::Remaining(millis(remaining)))
+    }
+
+    /// Tells the task running the countdown to follow `control`, unless the countdown has closed.
+    #[cfg(feature = "demo_mode")]
+    fn control(&self, control: DemoControl) -> Result<()> {
+        let closed = || super::ChannelError::Closed(self.closer.close_reason().unwrap_or(CloseReason::Completed));
+        if self.closer.is_closed() {
+            return Err(closed().into());
+        }
+
+        self.demo.tx.send(control).map_err(|_| closed().into())
+    }
+
+    /// Returns whether the countdown is still running: it has neither closed its channel, whether it ran to the end,
+    /// failed or was cancelled, nor panicked.
+    #[must_use]
+    pub fn is_running(&self) -> bool {
+        !self.closer.is_closed()
+    }
+
+    /// Waits for the task running the countdown to finish. Every handle to the same task, such as those returned for a
+    /// key already running by [`AsyncCountdown::start_with_key`], returns the same outcome.
+    ///
+    /// # Returns
+    ///
+    /// A [`std::result::Result`] that is:
+    ///
+    /// * `Ok(())` - The countdown ran to the end and closed its channel, or was skipped to the end with
+    ///   [`CountdownHandle::skip`].
+    /// * `Err(err)` - The task failed to send a value or to close its channel, e.g. because the receiver did not
+    ///   acknowledge the last value in time, panicked, or was aborted before it finished.
+    pub async fn wait(self) -> std::result::Result<(), TaskError> {
+        self.task.wait(&self.closer).await
+    }
+}
+
+impl SharedTask {
+    fn new(task: JoinHandle<Result<()>>) -> Self {
+        Self { abort: task.abort_handle(), outcome: async_sync::Mutex::new(TaskOutcome::Running(task)) }
+    }
+
+    fn is_finished(&self) -> bool {
+        self.abort.is_finished()
+    }
+
+    /// Waits for the task to finish, the first time it is called, and returns how it finished every time.
+    async fn wait(&self, closer: &ChannelCloser<Duration>) -> std::result::Result<(), TaskError> {
+        let mut outcome = self.outcome.lock().await;
+        let finished = match &mut *outcome {
+            TaskOutcome::Finished(finished) => return finished.clone(),
+            TaskOutcome::Running(task) => match task.await {
+                Ok(counted) => counted.map_err(TaskError::Failed),
+                Err(err) => match err.try_into_panic() {
+                    Ok(panic) => Err(TaskError::Panicked(crate::panic_message(panic))),
+                    // Skipping stops the task, having sent the last value and closed the channel in its place.
+                    Err(_) if closer.close_reason() == Some(CloseReason::Skipped) => Ok(()),
+                    Err(_) => Err(TaskError::Aborted),
+                },
+            },
+        };
+        *outcome = TaskOutcome::Finished(finished.clone());
+
+        finished
+    }
+}
+
+impl Repeat {
+    /// Returns how many cycles run after the first.
+    fn cycles_after_first(self) -> usize {
+        match self {
+            Self::Once => 0,
+            Self::Cycles(cycles) => usize::try_from(cycles.saturating_sub(1)).unwrap_or(usize::MAX),
+            // As good as forever, at one cycle every 10ms at the least.
+            Self::Forever => usize::MAX,
+        }
+    }
+}
+
+impl Direction {
+    /// Returns the value to send when `remaining` is left of a cycle of `duration`.
+    fn value(self, duration: u64, remaining: u64) -> u64 {
+        match self {
+            Self::Down => remaining,
+            Self::Up => duration.saturating_sub(remaining),
+        }
+    }
+}
+
+impl Cycle {
+    /// Returns a cycle of `duration` milliseconds with `remaining` left of it, counted on tokio's clock.
+    pub(super) fn new(duration: u64, remaining: u64) -> Self {
+        Self { duration, remaining, wall_clock: None }
+    }
+}
+
+impl TimeScale {
+    /// Returns how long `duration` of a countdown takes in real time.
+    fn real(self, duration: Duration) -> Duration {
+        self.0.map_or(duration, |scale| Duration::try_from_secs_f64(duration.as_secs_f64() / scale).unwrap_or(Duration::MAX))
+    }
+
+    /// Returns how much of a countdown `real` time counts for.
+    fn scaled(self, real: Duration) -> Duration {
+        self.0.map_or(real, |scale| Duration::try_from_secs_f64(real.as_secs_f64() * scale).unwrap_or(Duration::MAX))
+    }
+}
+
+impl Pace {
+    /// Returns how many milliseconds of the cycle have been counted by `at`.
+    fn ticked(self, at: Instant) -> u64 {
+        self.ticked.saturating_add(millis(self.time_scale.scaled(at.saturating_duration_since(self.at))))
+    }
+
+    /// Returns when `ticked` milliseconds of the cycle will have been counted, unless that is too far off to tell.
+    fn instant(self, ticked: u64) -> Option<Instant> {
+        self.at.checked_add(self.time_scale.real(Duration::from_millis(ticked.saturating_sub(self.ticked))))
+    }
+}
+
+impl WallClock {
+    /// Returns how many milliseconds the wall clock has gone ahead of tokio's clock since the cycle started counting at
+    /// `started_at`, or fallen behind it if negative. Time spent before the cycle started counting counts as ahead.
+    fn drift(self, started_at: Instant) -> i64 {
+        let wall = match (self.now)().duration_since(self.started) {
+            Ok(elapsed) => signed_millis(elapsed),
+            Err(set_back) => signed_millis(set_back.duration()).saturating_neg(),
+        };
+        wall.saturating_sub(signed_millis(started_at.elapsed()))
+    }
+}
+
+impl SnapshotSender {
+    /// Starts the next cycle, like [`ChannelSender::next_cycle`].
+    async fn next_cycle(&self, total: u64, value: u64) -> Result<()> {
+        self.tx.next_cycle(Duration::from_millis(total), Duration::from_millis(value)).await?;
+        self.sent(value);
+        Ok(())
+    }
+
+    /// Keeps `value` as the last one sent, and passes it on to the hooks.
+    fn sent(&self, value: u64) {
+        self.latest.store(value, Ordering::Relaxed);
+        self.run_hooks(HookEvent::Tick(value));
+    }
+
+    /// Stops the task counting down, then sends the last value a countdown counting in `direction` would send and
+    /// closes the channel, unless it has already closed.
+    async fn skip(&self, direction: Direction) {
+        let last = match direction {
+            Direction::Down => 0,
+            // A count-up without a cap has no end to skip to, so it stops where it is.
+            Direction::Up => match millis(self.tx.total()) {
+                UNCAPPED => self.latest.load(Ordering::Relaxed),
+                total => total.saturating_add(self.tx.closer().added_time().millis()),
+            },
+        };
+
+        if self.tx.skip(Duration::from_millis(last)).await {
+            // The channel only sends the last value again if it was not already the last one sent.
+            if self.latest.swap(last, Ordering::Relaxed) != last {
+                self.run_hooks(HookEvent::Tick(last));
+            }
+            self.run_hooks(HookEvent::Complete);
+        }
+    }
+
+    /// Queues `event` for the hooks, without waiting for them to run.
+    fn run_hooks(&self, event: HookEvent) {
+        if let Some(hooks) = &self.hooks {
+            // The thread running the hooks catches their panics, so it only stops once every sender has gone.
+            let _ = hooks.send(event);
+        }
+    }
+}
+
+impl Sender<u64> for SnapshotSender {
+    async fn send(&self, value: u64) -> Result<()> {
+        self.tx.send(Duration::from_millis(value)).await?;
+        self.sent(value);
+        Ok(())
+    }
+
+    async fn close(&self) -> Result<()> {
+        self.tx.close().await
+    }
+}
+
+impl Hooks {
+    /// Starts the thread that runs the hooks on each event it is sent, in turn, until every sender has gone. Returns
+    /// `None` without starting anything if there are no hooks to run.
+    ///
+    /// The hooks run outside the runtime, so that a hook that blocks cannot stall the countdown sending the events.
+    fn spawn(&self) -> Option<mpsc::UnboundedSender<HookEvent>> {
+        if self.tick.is_empty() && self.complete.is_empty() {
+            return None;
+        }
+
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        let hooks = self.clone();
+        thread::spawn(move || {
+            while let Some(event) = rx.blocking_recv() {
+                hooks.run(event);
+            }
+        });
+        Some(tx)
+    }
+
+    fn run(&self, event: HookEvent) {
+        match event {
+            HookEvent::Tick(value) => {
+                for hook in &self.tick {
+                    run_hook(hook, |hook| hook(Duration::from_millis(value)));
+                }
+            },
+            HookEvent::Complete => {
+                for hook in &self.complete {
+                    run_hook(hook, |hook| hook());
+                }
+            },
+        }
+    }
+}
+
+impl fmt::Debug for Hooks {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Hooks").field("tick", &self.tick.len()).field("complete", &self.complete.len()).finish()
+    }
+}
+
+impl CountdownTask {
+    fn is_running_as(&self, key: &str) -> bool {
+        self.key.as_deref() == Some(key) && !self.closer.is_closed()
+    }
+
+    /// Returns another handle to the task running this countdown.
+    fn handle(&self, direction: Direction) -> CountdownHandle {
+        CountdownHandle {
+            task: Arc::clone(&self.task),
+            closer: self.tx.tx.closer(),
+            tx: self.tx.clone(),
+            direction,
+            #[cfg(feature = "demo_mode")]
+            demo: self.demo.clone(),
+        }
+    }
+
+    fn abort(self) {
+        self.task.abort.abort();
+        self.closer.close(CloseReason::Cancelled);
+    }
+}
+
+/// Counts the `first` cycle, then each of `next_cycles` in turn, closing the channel once the last cycle has run out.
+async fn count_cycles(
+    tx: SnapshotSender,
+    next_cycles: impl Iterator<Item = u64>,
+    direction: Direction,
+    first: Cycle,
+    mut interval: Interval,
+    first_tick: Duration,
+    mut pacing: Pacing,
+) -> Result<()> {
+    let added = tx.tx.closer().added_time();
+    countdown(&mut interval, tx.clone(), direction, first, first_tick, &mut pacing, Arc::clone(&added)).await?;
+
+    for duration in next_cycles {
+        tx.next_cycle(duration, direction.value(duration, duration)).await?;
+        let cycle = Cycle::new(duration, duration);
+        // The period may have changed with the countdown's speed since the last cycle.
+        let period = interval.period();
+        countdown(&mut interval, tx.clone(), direction, cycle, period, &mut pacing, Arc::clone(&added)).await?;
+    }
+
+    tx.run_hooks(HookEvent::Complete);
+    // A receiver that does not acknowledge the last value in time has already been told, the channel having closed
+    // with FailureCode::Unacknowledged, so the error only goes to whoever waits on the task.
+    tx.close().await
+}
+
+/// Counts one cycle down to zero, leaving the channel open. A count-up sends the time elapsed of the cycle instead.
+///
+/// `interval` and `first_tick` are in real time, while the cycle and the values sent are scaled by the time scale of
+/// `pacing`. With the `demo_mode` feature, the demo controls it hears may change that time scale, or jump the cycle,
+/// as it counts.
+async fn countdown(
+    interval: &mut Interval,
+    tx: impl Sender<u64>,
+    direction: Direction,
+    cycle: Cycle,
+    first_tick: Duration,
+    pacing: &mut Pacing,
+    added: Arc<AddedTime>,
+) -> Result<()> {
+    let Cycle { duration, remaining, wall_clock } = cycle;
+    // The channel already holds the first value, so the first tick is due `first_tick` after the start rather than
+    // straight away, even when the countdown is no longer than one period.
+    let started = Instant::now();
+    interval.reset_at(started + first_tick);
+    let drift = || wall_clock.map_or(0, |wall_clock| wall_clock.drift(started));
+
+    pacing.pace = Pace { at: started, ticked: 0, ..pacing.pace };
+    let mut next_tick = started + first_tick;
+    let mut last_ticked = None;
+    let mut last_elapsed = None;
+    loop {
+        let added_millis = added.millis();
+        let total = remaining.saturating_add(added_millis);
+        if last_elapsed.is_some_and(|elapsed| elapsed >= total) {
+            if added.finish(added_millis) {
+                break;
+            }
+            continue;
+        }
+
+        // A duration that is not a whole number of periods runs out between two ticks: the last value is due when it
+        // runs out rather than at the tick after. The next tick is due a period after the last one at the earliest.
+        // A count-up without a cap never runs out, and a countdown to a deadline runs out by the wall clock.
+        let end = total.saturating_add_signed(drift().saturating_neg());
+        let waiting = wait_for_tick(interval, &mut next_tick, pacing.pace.instant(end));
+        #[cfg(feature = "demo_mode")]
+        let scheduled = tokio::select! {
+            scheduled = waiting => scheduled,
+            // Once every handle is dropped, no more controls can come and the countdown only waits for its ticks.
+            Some(control) = pacing.controls.recv() => {
+                let now = Instant::now();
+                match control {
+                    DemoControl::Speed { scale, period } => {
+                        // The tick due next stays as far into the cycle as it was, only coming sooner or later.
+                        let time_scale = TimeScale(Some(scale));
+                        let until_tick = time_scale.real(pacing.pace.time_scale.scaled(next_tick.saturating_duration_since(now)));
+                        pacing.pace = Pace { at: now, ticked: pacing.pace.ticked(now), time_scale };
+                        next_tick = now + until_tick;
+                        *interval = rescheduled(interval, next_tick, period);
+                    },
+                    DemoControl::Remaining(left) => {
+                        // A resumed cycle has no more to jump back to than it had left when it was resumed.
+                        let added_millis = added.millis();
+                        let left = left.min(remaining.saturating_add(added_millis));
+                        let elapsed = remaining.saturating_add(added_millis).saturating_sub(left);
+                        let ticked = elapsed.saturating_add_signed(drift().saturating_neg());
+                        pacing.pace = Pace { at: now, ticked, ..pacing.pace };
+                        last_ticked = Some(ticked);
+                        last_elapsed = Some(elapsed);
+                        // The countdown carries on a whole period after the jump, as if it had started there.
+                        next_tick = now + interval.period();
+                        interval.reset_at(next_tick);
+                        tx.send(direction.value(duration.saturating_add(added_millis), left)).await?;
+                    },
+                }
+                continue;
+            },
+        };
+        #[cfg(not(feature = "demo_mode"))]
+        let scheduled = waiting.await;
+        let now = Instant::now();
+
+        // A tick completes late when the host was suspended, or the receiver was slow to acknowledge the last value.
+        // Rather than send the time that was left when the tick was due, send the time really left before the deadline.
+        let at = if now.saturating_duration_since(scheduled) > TIMER_RESOLUTION { now } else { scheduled };
+        let ticked = pacing.pace.ticked(at);
+        if last_ticked.is_some_and(|last| ticked <= last) {
+            // Missed while a late tick was being sent, and already accounted for by it.
+            continue;
+        }
+        last_ticked = Some(ticked);
+
+        // The wall clock may have been set, or the host suspended, since the last tick.
+        let elapsed = ticked.saturating_add_signed(drift());
+        last_elapsed = Some(elapsed);
+
+        // Time added while waiting for the tick already counts towards it.
+        let added_millis = added.millis();
+        let left = remaining.saturating_add(added_millis).saturating_sub(elapsed);
+        tx.send(direction.value(duration.saturating_add(added_millis), left)).await?;
+    }
+
+    Ok(())
+}
+
+/// Waits for the next tick of `interval`, or for `deadline` if that comes before the tick due at `next_tick`, and returns
+/// when what it waited for was due. Moves `next_tick` on a period once the interval ticks.
+async fn wait_for_tick(interval: &mut Interval, next_tick: &mut Instant, deadline: Option<Instant>) -> Instant {
+    match deadline {
+        Some(deadline) if deadline < *next_tick => {
+            time::sleep_until(deadline).await;
+            deadline
+        },
+        _ => {
+            let scheduled = interval.tick().await;
+            *next_tick = scheduled + interval.period();
+            scheduled
+        },
+    }
+}
+
+/// Calls `hook` with `call`, catching a panic so that it neither stops the other hooks nor poisons the hook for the
+/// next call.
+fn run_hook<F: ?Sized>(hook: &sync::Mutex<F>, call: impl FnOnce(&F)) {
+    let hook = hook.lock().unwrap_or_else(PoisonError::into_inner);
+    let _ = panic::catch_unwind(AssertUnwindSafe(|| call(&hook)));
+}
+
+/// Returns `duration` in whole milliseconds, the unit countdowns count in, or as many as fit.
+fn millis(duration: Duration) -> u64 {
+    u64::try_from(duration.as_millis()).unwrap_or(u64::MAX)
+}
+
+fn signed_millis(duration: Duration) -> i64 {
+    i64::try_from(duration.as_millis()).unwrap_or(i64::MAX)
+}
+
+/// Runs the task sending a channel's values, closing the channel with [`FailureCode::Panicked`] if it panics, so that
+/// the receiver hears about it rather than waiting for values that will never come.
+async fn close_on_panic<T>(closer: ChannelCloser<Duration>, task: impl Future<Output = T>) -> T {
+    let _guard = CloseOnPanic(closer);
+    task.await
+}
+
+struct CloseOnPanic(ChannelCloser<Duration>);
+
+impl Drop for CloseOnPanic {
+    fn drop(&mut self) {
+        // An aborted task is dropped without panicking, and whoever aborted it closes the channel.
+        if thread::panicking() {
+            self.0.close(CloseReason::Failed(FailureCode::Panicked));
+        }
+    }
+}
+
+/// Returns how long a receiver of a countdown ticking every `period` waits by default before timing out.
+fn default_channel_timeout(period: Duration) -> Duration {
+    period.saturating_mul(TIMEOUT_PERIODS).max(Duration::from_millis(channel::DEFAULT_TIMEOUT_MS.into()))
+}
+
+/// Returns an interval that handles missed ticks like `interval`, but ticks every `period` from `start`.
+#[cfg(feature = "demo_mode")]
+fn rescheduled(interval: &Interval, start: Instant, period: Duration) -> Interval {
+    let mut rescheduled = time::interval_at(start, period);
+    rescheduled.set_missed_tick_behavior(interval.missed_tick_behavior());
+    rescheduled
+}
+
+fn interval(period: Duration, missed_ticks: MissedTicks) -> Interval {
+    let mut interval = time::interval(period);
+    interval.set_missed_tick_behavior(match missed_ticks {
+        MissedTicks::Burst => MissedTickBehavior::Burst,
+        MissedTicks::Delay => MissedTickBehavior::Delay,
+        MissedTicks::Skip => MissedTickBehavior::Skip,
+    });
+    interval
+}
+
+#[cfg(test)]
+mod tests {
+    use tokio::time::Duration;
+
+    use rstest::rstest;
+
+    use crate::countdown::{ChannelError, CountdownError, InvalidCountdown, Receiver, Response};
+
+    use super::*;
+
+    const HOUR_MS: u64 = 60u64 * 60 * 1000;
+    const DAY_MS: u64 = 24u64 * 60 * 60 * 1000;
+
+    #[tokio::test]
+    async fn should_fail_to_create_a_countdown_given_a_period_of_zero() {
+        let error = AsyncCountdown::try_new(Duration::ZERO).expect_err("should have failed");
+        assert_eq!(error, TimerError::InvalidCountdown(InvalidCountdown::ZeroInterval).into());
+    }
+
+    #[tokio::test]
+    async fn should_fail_to_create_a_countdown_given_a_period_of_greater_than_one_hour() {
+        let result = AsyncCountdown::try_new(Duration::from_millis(HOUR_MS + 1)).expect_err("should have failed");
+        assert_eq!(result, TimerError::InvalidCountdown(InvalidCountdown::IntervalGreaterThanOneHour(Duration::from_millis(HOUR_MS + 1))).into());
+    }
+
+    #[tokio::test]
+    async fn should_fail_to_start_a_countdown_given_an_duration_smaller_than_the_interval() {
+        let error = AsyncCountdown::try_new(Duration::from_millis(2000)).expect("unexpected error creating a countdown")
+            .start(Duration::from_millis(1000)).await.expect_err("should have failed to start");
+        assert_eq!(error, InvalidDuration::DurationSmallerThanPeriod{duration: Duration::from_millis(1000), period: Duration::from_millis(2000)}.into());
+    }
+
+    #[tokio::test]
+    async fn should_fail_to_start_a_countdown_given_a_duration_of_zero() {
+        let error = AsyncCountdown::try_new(Duration::from_millis(100)).expect("unexpected error creating a countdown")
+            .start(Duration::ZERO).await.expect_err("should have failed to start");
+        assert_eq!(error, InvalidDuration::ZeroDuration.into());
+    }
+
+    #[tokio::test]
+    async fn should_fail_to_start_a_countdown_given_a_duration_of_greater_than_one_day() {
+        let error = AsyncCountdown::try_new(Duration::from_millis(100)).expect("unexpected error creating a countdown")
+            .start(Duration::from_millis(DAY_MS + 1)).await.expect_err("should have failed to start");
+        assert_eq!(error, InvalidDuration::DurationGreaterThanOneDay(Duration::from_millis(DAY_MS + 1)).into());
+    }
+
+    #[rstest]
+    #[case::zero(0)]
+    #[case::smaller_than_the_period(50)]
+    #[case::one_period(100)]
+    #[case::several_periods(1000)]
+    #[case::greater_than_one_day(DAY_MS + 1)]
+    #[tokio::test]
+    async fn should_start_exactly_the_durations_that_validate(#[case] duration_millis: u64) {
+        time::pause();
+        let timer = AsyncCountdown::try_new(Duration::from_millis(100)).expect("should have created countdown");
+
+        let validated = timer.validate(Duration::from_millis(duration_millis));
+        let started = timer.start(Duration::from_millis(duration_millis)).await.map(drop);
+
+        assert_eq!(started, validated.map_err(StartError::from));
+    }
+
+    #[tokio::test]
+    async fn should_validate_without_waiting_for_the_interval() {
+        time::pause();
+        let timer = AsyncCountdown::try_new(Duration::from_millis(100)).expect("should have created countdown");
+        let _running = timer.start(Duration::from_millis(1000)).await.expect("unexpected countdown failure");
+
+        assert_eq!(timer.validate(Duration::from_millis(1000)), Ok(()));
+        assert_eq!(timer.validate(Duration::ZERO), Err(InvalidDuration::ZeroDuration));
+    }
+
+    #[tokio::test]
+    async fn should_countdown_to_zero() {
+        time::pause();
+        let timer = AsyncCountdown::try_new(Duration::from_millis(100)).expect("should have created countdown");
+        let mut expectations = [1000u64, 900u64, 800u64, 700u64, 600u64, 500u64, 400u64, 300u64, 200u64, 100u64, 0u64].iter().rev().copied().map(Duration::from_millis).collect::<Vec<_>>();
+        let num_expect = expectations.len();
+
+        let (rx, _) = timer.start(Duration::from_millis(1000)).await.expect("unexpected countdown failure");
+
+        while let Some(expect) = expectations.pop() {
+            if let Ok(Response::Value(left)) = rx.recv().await {
+                assert_eq!(expect, left, "[{:?}] expected {:?}, but got {:?}", num_expect - expectations.len(), expect, left);
+            }
+            time::advance(Duration::from_millis(100u64)).await;
+        }
+
+        assert_eq!(expectations.len(), 0, "unmet expectations: {:?}", expectations.iter().rev().collect::<Vec<_>>());
+    }
+
+    #[tokio::test]
+    async fn should_resume_a_countdown_from_its_remaining_time() {
+        time::pause();
+        let timer = AsyncCountdown::try_new(Duration::from_millis(100)).expect("should have created countdown");
+
+        let (rx, _) = timer.start_from(Duration::from_millis(1000), Duration::from_millis(400)).await.expect("unexpected countdown failure");
+
+        let mut received = Vec::new();
+        while let Response::Value(progress) = rx.recv_progress().await.expect("unexpected error receiving progress") {
+            received.push((progress.remaining.as_millis(), progress.total.as_millis(), progress.percent_complete()));
+        }
+
+        assert_eq!(received, [(400, 1000, 60), (300, 1000, 70), (200, 1000, 80), (100, 1000, 90), (0, 1000, 100)]);
+    }
+
+    #[tokio::test]
+    async fn should_repeat_a_countdown_then_close_once_every_cycle_has_run_out() {
+        time::pause();
+        let timer = AsyncCountdown::builder()
+            .period(Duration::from_millis(100))
+            .repeat(Repeat::Cycles(3))
+            .build()
+            .expect("should have created countdown");
+        let started_at = Instant::now();
+
+        let (rx, handle) = timer.start(Duration::from_millis(300)).await.expect("unexpected countdown failure");
+
+        let (mut responses, mut times) = (Vec::new(), Vec::new());
+        loop {
+            let response = rx.recv().await.expect("unexpected error receiving");
+            let closed = matches!(response, Response::Closed(_));
+            responses.push(response);
+            times.push(started_at.elapsed().as_millis() as u64);
+            if closed {
+                break;
+            }
+        }
+
+        let cycle = |started: u64| (0..=3).rev().map(move |tick| (Response::Value(Duration::from_millis(tick * 100)), started + 300 - tick * 100));
+        let (expected, due): (Vec<_>, Vec<_>) = cycle(0)
+            .chain([(Response::CycleCompleted(1), 300)])
+            .chain(cycle(300))
+            .chain([(Response::CycleCompleted(2), 600)])
+            .chain(cycle(600))
+            .chain([(Response::Closed(CloseReason::Completed), 900)])
+            .unzip();
+        assert_eq!(responses, expected);
+        // The paused clock rounds each timer up to the next whole millisecond, and every cycle starts where the one
+        // before it ran out, so the rounding adds up over the cycles.
+        for (elapsed, due) in times.iter().zip(&due) {
+            assert!(elapsed.abs_diff(*due) <= 3, "expected each response on schedule, but got them at {times:?}");
+        }
+        assert_eq!(handle.wait().await, Ok(()));
+    }
+
+    #[tokio::test]
+    async fn should_repeat_a_countdown_forever_until_cancelled() {
+        time::pause();
+        let timer = AsyncCountdown::builder()
+            .period(Duration::from_millis(100))
+            .repeat(Repeat::Forever)
+            .build()
+            .expect("should have created countdown");
+        let (rx, _) = timer.start(Duration::from_millis(100)).await.expect("unexpected countdown failure");
+        let canceller = rx.clone();
+        let rx = rx.strict();
+
+        let mut cycles = 0;
+        while cycles < 5 {
+            if let Response::CycleCompleted(completed) = rx.recv().await.expect("the countdown should follow the protocol") {
+                cycles = completed;
+            }
+        }
+        canceller.cancel();
+
+        assert_eq!(rx.recv().await, Ok(Response::Closed(CloseReason::Cancelled)));
+    }
+
+    #[rstest]
+    #[case::once(Repeat::Once, 1)]
+    #[case::cycles(Repeat::Cycles(3), 3)]
+    #[case::zero_cycles(Repeat::Cycles(0), 1)]
+    #[tokio::test]
+    async fn should_run_as_many_cycles_as_repeated(#[case] repeat: Repeat, #[case] expected: u32) {
+        time::pause();
+        let timer = AsyncCountdown::builder()
+            .period(Duration::from_millis(100))
+            .repeat(repeat)
+            .build()
+            .expect("should have created countdown");
+        let (rx, _) = timer.start(Duration::from_millis(200)).await.expect("unexpected countdown failure");
+
+        let mut cycles = 1;
+        loop {
+            match rx.recv().await.expect("unexpected error receiving") {
+                Response::Value(_) => {},
+                Response::CycleCompleted(completed) => cycles = completed + 1,
+                Response::Closed(reason) => {
+                    assert_eq!(reason, CloseReason::Completed);
+                    break;
+                },
+            }
+        }
+
+        assert_eq!(cycles, expected);
+    }
+
+    #[tokio::test]
+    async fn should_deliver_ten_updates_a_second_to_a_receiver_that_acknowledges_promptly() {
+        time::pause();
+        let timer = AsyncCountdown::try_new(Duration::from_millis(100)).expect("should have created countdown");
+        let started_at = Instant::now();
+        let (rx, handle) = timer.start(Duration::from_millis(3000)).await.expect("unexpected countdown failure");
+
+        let mut shown = Vec::new();
+        loop {
+            let response = rx.recv().await.expect("unexpected error receiving");
+            match response.display_tenths() {
+                Some(tenths) => shown.push(tenths.to_string()),
+                None => break,
+            }
+        }
+
+        assert_eq!(shown.len(), 31, "expected every tenth from 3.0 down, but got {shown:?}");
+        assert_eq!(shown.first().map(String::as_str), Some("00:03.0"));
+        assert_eq!(shown[1..3], ["00:02.9", "00:02.8"]);
+        assert_eq!(shown.last().map(String::as_str), Some("00:00.0"));
+        assert!(started_at.elapsed().as_millis().abs_diff(3000) <= 1, "took {:?}", started_at.elapsed());
+        assert_eq!(handle.wait().await, Ok(()));
+    }
+
+    #[tokio::test]
+    async fn should_count_up_to_the_cap_then_close() {
+        time::pause();
+        let timer = AsyncCountdown::builder()
+            .period(Duration::from_millis(100))
+            .direction(Direction::Up)
+            .build()
+            .expect("should have created countdown");
+        let started_at = Instant::now();
+
+        let (rx, handle) = timer.start(Duration::from_millis(250)).await.expect("unexpected countdown failure");
+
+        let mut values = Vec::new();
+        let reason = loop {
+            match rx.recv().await.expect("unexpected error receiving") {
+                Response::Value(elapsed) => values.push(elapsed),
+                Response::CycleCompleted(_) => panic!("unexpected cycle completed"),
+                Response::Closed(reason) => break reason,
+            }
+        };
+
+        assert_eq!(values, [0, 100, 200, 250].map(Duration::from_millis));
+        assert_eq!(reason, CloseReason::Completed);
+        assert!(started_at.elapsed().as_millis().abs_diff(250) <= 1, "took {:?}", started_at.elapsed());
+        assert_eq!(handle.wait().await, Ok(()));
+    }
+
+    #[tokio::test]
+    async fn should_count_up_without_a_cap_until_cancelled() {
+        time::pause();
+        let timer = AsyncCountdown::builder()
+            .period(Duration::from_millis(60 * 60 * 1000))
+            .direction(Direction::Up)
+            .build()
+            .expect("should have created countdown");
+        let (rx, handle) = timer.start(Duration::ZERO).await.expect("unexpected countdown failure");
+        assert_eq!(rx.recv().await, Ok(Response::Value(Duration::ZERO)));
+
+        // Well past the day a countdown is limited to.
+        for hour in 1..=48 {
+            assert_eq!(rx.recv().await, Ok(Response::Value(Duration::from_secs(hour * 60 * 60))));
+        }
+
+        handle.abort();
+        assert_eq!(rx.recv().await, Ok(Response::Closed(CloseReason::Cancelled)));
+    }
+
+    #[tokio::test]
+    async fn should_count_up_from_the_time_already_elapsed_when_resumed() {
+        time::pause();
+        let timer = AsyncCountdown::builder()
+            .period(Duration::from_millis(100))
+            .direction(Direction::Up)
+            .build()
+            .expect("should have created countdown");
+        let (rx, _) = timer.start_from(Duration::from_millis(500), Duration::from_millis(200)).await.expect("unexpected countdown failure");
+
+        let mut values = Vec::new();
+        while let Response::Value(elapsed) = rx.recv().await.expect("unexpected error receiving") {
+            values.push(elapsed);
+        }
+
+        assert_eq!(values, [300, 400, 500].map(Duration::from_millis));
+    }
+
+    #[tokio::test]
+    async fn should_count_every_cycle_of_a_repeating_count_up_from_zero() {
+        time::pause();
+        let timer = AsyncCountdown::builder()
+            .period(Duration::from_millis(100))
+            .direction(Direction::Up)
+            .repeat(Repeat::Cycles(2))
+            .build()
+            .expect("should have created countdown");
+        let (rx, _) = timer.start(Duration::from_millis(200)).await.expect("unexpected countdown failure");
+
+        let mut responses = Vec::new();
+        loop {
+            let response = rx.recv().await.expect("unexpected error receiving");
+            let closed = matches!(response, Response::Closed(_));
+            responses.push(response);
+            if closed {
+                break;
+            }
+        }
+
+        assert_eq!(responses, [
+            Response::Value(Duration::ZERO),
+            Response::Value(Duration::from_millis(100)),
+            Response::Value(Duration::from_millis(200)),
+            Response::CycleCompleted(1),
+            Response::Value(Duration::ZERO),
+            Response::Value(Duration::from_millis(100)),
+            Response::Value(Duration::from_millis(200)),
+            Response::Closed(CloseReason::Completed),
+        ]);
+    }
+
+    #[rstest]
+    #[case::zero_down(Direction::Down, Repeat::Once, 0, Err(InvalidDuration::ZeroDuration))]
+    #[case::zero_up(Direction::Up, Repeat::Once, 0, Ok(()))]
+    #[case::zero_up_repeated(Direction::Up, Repeat::Cycles(2), 0, Err(InvalidDuration::UncappedRepeat))]
+    #[case::zero_up_once_of_one_cycle(Direction::Up, Repeat::Cycles(1), 0, Ok(()))]
+    #[case::within_the_period_up(Direction::Up, Repeat::Once, 50, Err(InvalidDuration::DurationSmallerThanPeriod {
+        duration: Duration::from_millis(50),
+        period: Duration::from_millis(100),
+    }))]
+    #[case::over_one_day_up(Direction::Up, Repeat::Once, 24 * 60 * 60 * 1000 + 1, Err(InvalidDuration::DurationGreaterThanOneDay(
+        Duration::from_millis(24 * 60 * 60 * 1000 + 1),
+    )))]
+    fn should_only_take_zero_for_no_cap_when_counting_up_once(
+        #[case] direction: Direction,
+        #[case] repeat: Repeat,
+        #[case] duration_millis: u64,
+        #[case] expected: std::result::Result<(), InvalidDuration>,
+    ) {
+        let timer = AsyncCountdown::builder()
+            .period(Duration::from_millis(100))
+            .direction(direction)
+            .repeat(repeat)
+            .build()
+            .expect("should have created countdown");
+
+        assert_eq!(timer.validate(Duration::from_millis(duration_millis)), expected);
+    }
+
+    #[tokio::test]
+    async fn should_count_up_to_the_maximum_duration_without_a_cap() {
+        time::pause();
+        let timer = AsyncCountdown::builder()
+            .period(Duration::from_millis(100))
+            .max_duration(Duration::from_millis(300))
+            .direction(Direction::Up)
+            .build()
+            .expect("should have created countdown");
+        let (rx, _) = timer.start(Duration::ZERO).await.expect("unexpected countdown failure");
+
+        let mut values = Vec::new();
+        while let Response::Value(elapsed) = rx.recv().await.expect("unexpected error receiving") {
+            values.push(elapsed);
+        }
+
+        assert_eq!(values, [0, 100, 200, 300].map(Duration::from_millis));
+    }
+
+    #[rstest]
+    #[case::nothing_remaining(1000, 0, InvalidDuration::ZeroDuration)]
+    #[case::more_than_the_duration(1000, 1001, InvalidDuration::RemainingGreaterThanDuration { remaining: Duration::from_millis(1001), duration: Duration::from_millis(1000) })]
+    #[case::invalid_duration(0, 0, InvalidDuration::ZeroDuration)]
+    #[tokio::test]
+    async fn should_fail_to_resume_a_countdown_given_an_invalid_remaining_time(
+        #[case] duration_millis: u64,
+        #[case] remaining_millis: u64,
+        #[case] expected: InvalidDuration,
+    ) {
+        let timer = AsyncCountdown::try_new(Duration::from_millis(100)).expect("should have created countdown");
+
+        let error = timer.start_from(Duration::from_millis(duration_millis), Duration::from_millis(remaining_millis)).await.map(drop).expect_err("should have failed to resume");
+
+        assert_eq!(error, StartError::InvalidDuration(expected));
+    }
+
+    #[rstest]
+    #[case::one_period(0, 100, &[(100, 0), (0, 1)])]
+    #[case::just_over_one_period(0, 101, &[(101, 0), (1, 1), (0, 1)])]
+    #[case::one_period_started_mid_period(150, 100, &[(100, 0), (0, 1)])]
+    #[tokio::test]
+    async fn should_wait_a_whole_period_after_starting_before_the_first_tick(
+        #[case] idle_millis: u64,
+        #[case] duration_millis: u64,
+        #[case] expected_periods_elapsed: &[(u64, u32)],
+    ) {
+        time::pause();
+        let timer = AsyncCountdown::try_new(Duration::from_millis(100)).expect("should have created countdown");
+        time::sleep(Duration::from_millis(idle_millis)).await;
+
+        let started_at = Instant::now();
+        let (rx, _) = timer.start(Duration::from_millis(duration_millis)).await.expect("unexpected countdown failure");
+
+        let mut received = Vec::new();
+        while let Response::Value(left) = rx.recv().await.expect("unexpected error receiving") {
+            received.push((left, (started_at.elapsed().as_millis() / 100) as u32));
+        }
+
+        let expected = expected_periods_elapsed.iter().map(|&(millis_left, periods)| (Duration::from_millis(millis_left), periods));
+        assert_eq!(received, expected.collect::<Vec<_>>());
+    }
+
+    #[rstest]
+    #[case::one_period(100, &[(100, 0), (0, 100)])]
+    #[case::just_short_of_ten_periods(999, &[(999, 0), (899, 100), (799, 200), (699, 300), (599, 400), (499, 500), (399, 600), (299, 700), (199, 800), (99, 900), (0, 999)])]
+    #[case::half_way_through_a_period(1050, &[(1050, 0), (950, 100), (850, 200), (750, 300), (650, 400), (550, 500), (450, 600), (350, 700), (250, 800), (150, 900), (50, 1000), (0, 1050)])]
+    #[tokio::test]
+    async fn should_send_the_last_value_when_the_duration_runs_out(#[case] duration_millis: u64, #[case] expected: &[(u64, u64)]) {
+        time::pause();
+        let timer = AsyncCountdown::try_new(Duration::from_millis(100)).expect("should have created countdown");
+        let started_at = Instant::now();
+        let (rx, _) = timer.start(Duration::from_millis(duration_millis)).await.expect("unexpected countdown failure");
+
+        let mut received = Vec::new();
+        while let Response::Value(left) = rx.recv().await.expect("unexpected error receiving") {
+            received.push((left, started_at.elapsed().as_millis() as u64));
+        }
+
+        // The paused clock rounds each deadline up to the next millisecond.
+        let left = received.iter().map(|&(left, _)| left).collect::<Vec<_>>();
+        assert_eq!(left, expected.iter().map(|&(millis_left, _)| Duration::from_millis(millis_left)).collect::<Vec<_>>());
+        for (&(left, received_at), &(_, due)) in received.iter().zip(expected) {
+            assert!(received_at.abs_diff(due) <= 1, "expected {left:?} at {due}ms, but got it at {received_at}ms");
+        }
+    }
+
+    #[rstest]
+    #[case::just_past_a_second(|| UNIX_EPOCH + Duration::from_millis(50_607_001), &[(3000, 0), (2001, 999), (1001, 1999), (1, 2999), (0, 3000)])]
+    #[case::most_of_a_second_in(|| UNIX_EPOCH + Duration::from_millis(50_607_750), &[(3000, 0), (2750, 250), (1750, 1250), (750, 2250), (0, 3000)])]
+    #[case::on_a_second(|| UNIX_EPOCH + Duration::from_millis(50_607_000), &[(3000, 0), (2000, 1000), (1000, 2000), (0, 3000)])]
+    #[tokio::test]
+    async fn should_tick_on_the_wall_clock_boundaries_when_aligned(
+        #[case] wall_clock: fn() -> SystemTime,
+        #[case] expected_millis_elapsed: &[(u64, u64)],
+    ) {
+        time::pause();
+        let mut timer = AsyncCountdown::builder()
+            .period(Duration::from_millis(1000))
+            .align_to_wall_clock()
+            .build()
+            .expect("should have created countdown");
+        timer.wall_clock = wall_clock;
+
+        let started_at = Instant::now();
+        let (rx, _) = timer.start(Duration::from_millis(3000)).await.expect("unexpected countdown failure");
+
+        let mut received = Vec::new();
+        while let Response::Value(left) = rx.recv().await.expect("unexpected error receiving") {
+            received.push((left, started_at.elapsed().as_millis() as u64));
+        }
+
+        assert_eq!(received.len(), expected_millis_elapsed.len(), "expected {expected_millis_elapsed:?}, but got {received:?}");
+        for (&(left, elapsed), &(expected_left, expected_elapsed)) in received.iter().zip(expected_millis_elapsed) {
+            assert_eq!(left, Duration::from_millis(expected_left), "expected {expected_millis_elapsed:?}, but got {received:?}");
+            // The paused clock rounds each timer up to the next whole millisecond.
+            assert!(elapsed.abs_diff(expected_elapsed) <= 1, "expected {expected_millis_elapsed:?}, but got {received:?}");
+        }
+    }
+
+    thread_local! {
+        /// What the fake wall clock read, and when on tokio's clock it read it.
+        static FAKE_WALL_CLOCK: std::cell::Cell<Option<(Instant, SystemTime)>> = const { std::cell::Cell::new(None) };
+    }
+
+    /// A wall clock that keeps time with tokio's, from wherever [`set_fake_wall_clock`] last set it.
+    fn fake_wall_clock() -> SystemTime {
+        let (set_at, wall) = FAKE_WALL_CLOCK.get().expect("the fake wall clock should have been set");
+        wall + set_at.elapsed()
+    }
+
+    fn set_fake_wall_clock(wall: SystemTime) {
+        FAKE_WALL_CLOCK.set(Some((Instant::now(), wall)));
+    }
+
+    #[tokio::test]
+    async fn should_count_down_to_a_deadline() {
+        time::pause();
+        set_fake_wall_clock(UNIX_EPOCH + Duration::from_secs(1_000_000));
+        let mut timer = AsyncCountdown::try_new(Duration::from_millis(100)).expect("should have created countdown");
+        timer.wall_clock = fake_wall_clock;
+        let started_at = Instant::now();
+
+        let (rx, handle) = timer.start_until(fake_wall_clock() + Duration::from_millis(350)).await.expect("unexpected countdown failure");
+
+        let mut values = Vec::new();
+        while let Response::Value(left) = rx.recv().await.expect("unexpected error receiving") {
+            values.push(left);
+        }
+
+        assert_eq!(values, [350, 250, 150, 50, 0].map(Duration::from_millis));
+        assert!(started_at.elapsed().as_millis().abs_diff(350) <= 1, "took {:?}", started_at.elapsed());
+        assert_eq!(handle.wait().await, Ok(()));
+    }
+
+    #[rstest]
+    #[case::set_forward(500, &[1000, 400, 300, 200, 100, 0], 500)]
+    #[case::set_back(-50, &[1000, 950, 850, 750, 650, 550, 450, 350, 250, 150, 50, 0], 1050)]
+    #[case::set_past_the_deadline(2000, &[1000, 0], 0)]
+    #[tokio::test]
+    async fn should_count_down_to_a_deadline_by_the_wall_clock_when_it_is_set(
+        #[case] set_by_millis: i64,
+        #[case] expected: &[u64],
+        #[case] expected_millis_taken: u128,
+    ) {
+        time::pause();
+        set_fake_wall_clock(UNIX_EPOCH + Duration::from_secs(1_000_000));
+        let mut timer = AsyncCountdown::try_new(Duration::from_millis(100)).expect("should have created countdown");
+        timer.wall_clock = fake_wall_clock;
+        let started_at = Instant::now();
+        let (rx, _) = timer.start_until(fake_wall_clock() + Duration::from_secs(1)).await.expect("unexpected countdown failure");
+        let mut values = vec![rx.recv().await.expect("unexpected error receiving")];
+
+        let set_by = Duration::from_millis(set_by_millis.unsigned_abs());
+        set_fake_wall_clock(if set_by_millis < 0 { fake_wall_clock() - set_by } else { fake_wall_clock() + set_by });
+        loop {
+            let response = rx.recv().await.expect("unexpected error receiving");
+            let closed = matches!(response, Response::Closed(_));
+            values.push(response);
+            if closed {
+                break;
+            }
+        }
+
+        let mut expected = expected.iter().copied().map(|millis_left| Response::Value(Duration::from_millis(millis_left))).collect::<Vec<_>>();
+        expected.push(Response::Closed(CloseReason::Completed));
+        assert_eq!(values, expected);
+        assert!(started_at.elapsed().as_millis().abs_diff(expected_millis_taken) <= 1, "took {:?}", started_at.elapsed());
+    }
+
+    #[rstest]
+    #[case::in_the_past(-90_000, InvalidDuration::DeadlineInPast(Duration::from_secs(90)))]
+    #[case::now(0, InvalidDuration::DeadlineInPast(Duration::ZERO))]
+    #[case::within_the_period(50, InvalidDuration::DurationSmallerThanPeriod {
+        duration: Duration::from_millis(50),
+        period: Duration::from_millis(100),
+    })]
+    #[case::more_than_a_day_away(DAY_MS as i64 + 1, InvalidDuration::DurationGreaterThanOneDay(Duration::from_millis(DAY_MS + 1)))]
+    #[tokio::test]
+    async fn should_fail_to_count_down_to_an_invalid_deadline(#[case] millis_away: i64, #[case] expected: InvalidDuration) {
+        time::pause();
+        set_fake_wall_clock(UNIX_EPOCH + Duration::from_secs(1_000_000));
+        let mut timer = AsyncCountdown::try_new(Duration::from_millis(100)).expect("should have created countdown");
+        timer.wall_clock = fake_wall_clock;
+
+        let away = Duration::from_millis(millis_away.unsigned_abs());
+        let deadline = if millis_away < 0 { fake_wall_clock() - away } else { fake_wall_clock() + away };
+
+        assert_eq!(timer.start_until(deadline).await.map(drop), Err(expected.into()));
+    }
+
+    #[tokio::test]
+    async fn should_send_no_more_values_once_cancelled() {
+        time::pause();
+        let timer = AsyncCountdown::try_new(Duration::from_millis(100)).expect("should have created countdown");
+        let (rx, _) = timer.start(Duration::from_millis(1000)).await.expect("unexpected countdown failure");
+        assert_eq!(rx.recv().await.expect("unexpected error receiving"), Response::Value(Duration::from_millis(1000)));
+        assert_eq!(rx.recv().await.expect("unexpected error receiving"), Response::Value(Duration::from_millis(900)));
+
+        let pending = tokio::spawn({
+            let rx = rx.clone();
+            async move { (rx.recv().await, Instant::now()) }
+        });
+        time::sleep(Duration::from_millis(50)).await;
+        let cancelled_at = Instant::now();
+        rx.cancel();
+
+        let (response, received_at) = pending.await.expect("the receive should have finished");
+        assert_eq!(response.expect("unexpected error receiving"), Response::Closed(CloseReason::Cancelled));
+        assert_eq!(received_at, cancelled_at);
+
+        time::sleep(Duration::from_secs(2)).await;
+        assert_eq!(rx.recv().await.expect("unexpected error receiving"), Response::Closed(CloseReason::Cancelled));
+        assert!(timer.tasks().iter().all(|task| task.task.is_finished()), "the countdown task should have stopped");
+    }
+
+    #[tokio::test]
+    async fn should_tick_concurrent_countdowns_independently() {
+        time::pause();
+        let timer = AsyncCountdown::try_new(Duration::from_millis(100)).expect("should have created countdown");
+        let started_at = Instant::now();
+
+        let (short, _) = timer.start(Duration::from_millis(500)).await.expect("unexpected countdown failure");
+        let (long, _) = timer.start(Duration::from_millis(1000)).await.expect("unexpected countdown failure");
+
+        let receive_all = |rx: ChannelReceiver<Duration>| async move {
+            let mut values = Vec::new();
+            while let Response::Value(left) = rx.recv().await.expect("unexpected error receiving") {
+                values.push((left, started_at.elapsed().as_millis()));
+            }
+            values
+        };
+        let (short, long) = tokio::join!(receive_all(short), receive_all(long));
+
+        for (values, duration) in [(short, 500), (long, 1000)] {
+            let left = values.iter().map(|&(left, _)| left).collect::<Vec<_>>();
+            assert_eq!(left, (0..=duration / 100).rev().map(|tick| Duration::from_millis(tick * 100)).collect::<Vec<_>>());
+
+            // The paused clock rounds each deadline up to the next millisecond.
+            for &(left, received_at) in &values {
+                let due = u128::from(duration) - left.as_millis();
+                assert!(received_at.abs_diff(due) <= 1, "expected {left:?} at {due}ms, but got it at {received_at}ms");
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn should_count_down_the_time_added_to_a_running_countdown() {
+        time::pause();
+        let timer = AsyncCountdown::try_new(Duration::from_millis(100)).expect("should have created countdown");
+        let (rx, _) = timer.start(Duration::from_millis(1000)).await.expect("unexpected countdown failure");
+        assert_eq!(rx.recv().await.expect("unexpected error receiving"), Response::Value(Duration::from_millis(1000)));
+        assert_eq!(rx.recv().await.expect("unexpected error receiving"), Response::Value(Duration::from_millis(900)));
+
+        rx.add(Duration::from_millis(500)).await.expect("should have added time");
+
+        let mut values = Vec::new();
+        while let Response::Value(left) = rx.recv().await.expect("unexpected error receiving") {
+            values.push(left);
+        }
+        assert_eq!(values, (0..=13).rev().map(|tick| Duration::from_millis(tick * 100)).collect::<Vec<_>>());
+    }
+
+    #[tokio::test]
+    async fn should_not_add_time_beyond_the_longest_countdown() {
+        time::pause();
+        let timer = AsyncCountdown::try_new(Duration::from_millis(100)).expect("should have created countdown");
+        let (rx, _) = timer.start(Duration::from_millis(1000)).await.expect("unexpected countdown failure");
+        assert_eq!(rx.recv().await.expect("unexpected error receiving"), Response::Value(Duration::from_millis(1000)));
+
+        let error = rx.add(Duration::from_millis(DAY_MS)).await.expect_err("should have rejected the time added");
+
+        assert_eq!(error, InvalidDuration::DurationGreaterThanOneDay(Duration::from_millis(DAY_MS + 1000)).into());
+    }
+
+    #[tokio::test]
+    async fn should_not_add_time_to_a_count_up_beyond_the_maximum_duration() {
+        time::pause();
+        let timer = AsyncCountdown::builder()
+            .period(Duration::from_millis(100))
+            .max_duration(Duration::from_millis(1000))
+            .direction(Direction::Up)
+            .build()
+            .expect("should have built countdown");
+        let (rx, _) = timer.start(Duration::from_millis(500)).await.expect("unexpected countdown failure");
+        for expected in [0, 100, 200, 300].map(Duration::from_millis) {
+            assert_eq!(rx.recv().await.expect("unexpected error receiving"), Response::Value(expected));
+        }
+        let too_long = InvalidDuration::DurationGreaterThanMaximum {
+            duration: Duration::from_millis(1001),
+            maximum: Duration::from_millis(1000),
+        };
+
+        rx.add(Duration::from_millis(400)).await.expect("should have added time");
+        assert_eq!(rx.add(Duration::from_millis(101)).await, Err(too_long.into()));
+        rx.add(Duration::from_millis(100)).await.expect("should have added time up to the maximum");
+
+        let mut values = Vec::new();
+        while let Response::Value(elapsed) = rx.recv().await.expect("unexpected error receiving") {
+            values.push(elapsed);
+        }
+        assert_eq!(values, (4..=10).map(|tick| Duration::from_millis(tick * 100)).collect::<Vec<_>>());
+    }
+
+    #[rstest]
+    #[case::completed(false, CloseReason::Completed)]
+    #[case::cancelled(true, CloseReason::Cancelled)]
+    #[tokio::test]
+    async fn should_fail_to_add_time_once_closed(#[case] cancel: bool, #[case] reason: CloseReason) {
+        time::pause();
+        let timer = AsyncCountdown::try_new(Duration::from_millis(100)).expect("should have created countdown");
+        let (rx, _) = timer.start(Duration::from_millis(200)).await.expect("unexpected countdown failure");
+        if cancel {
+            rx.cancel();
+        }
+        while let Response::Value(_) = rx.recv().await.expect("unexpected error receiving") {}
+
+        let error = rx.add(Duration::from_millis(500)).await.expect_err("should have failed to add time");
+
+        assert_eq!(error, crate::countdown::ChannelError::Closed(reason).into());
+    }
+
+    #[tokio::test]
+    async fn should_only_send_values_of_the_new_countdown_once_restarted() {
+        time::pause();
+        let timer = AsyncCountdown::try_new(Duration::from_millis(100)).expect("should have created countdown");
+        let (first, _) = timer.start(Duration::from_millis(1000)).await.expect("unexpected countdown failure");
+        for expected in [1000, 900, 800].map(Duration::from_millis) {
+            assert_eq!(first.recv().await.expect("unexpected error receiving"), Response::Value(expected));
+        }
+
+        let (second, handle) = timer.restart(Duration::from_millis(500)).await.expect("unexpected restart failure");
+
+        let mut values = Vec::new();
+        while let Response::Value(left) = second.recv().await.expect("unexpected error receiving") {
+            values.push(left);
+        }
+        assert_eq!(values, [500, 400, 300, 200, 100, 0].map(Duration::from_millis));
+        assert_eq!(first.recv().await.expect("unexpected error receiving"), Response::Closed(CloseReason::Cancelled));
+        assert_eq!(handle.wait().await, Ok(()));
+    }
+
+    #[tokio::test]
+    async fn should_abort_a_restarted_countdown_from_its_handle() {
+        time::pause();
+        let timer = AsyncCountdown::try_new(Duration::from_millis(100)).expect("should have created countdown");
+        let (rx, handle) = timer.restart(Duration::from_millis(1000)).await.expect("unexpected restart failure");
+        assert_eq!(rx.recv().await.expect("unexpected error receiving"), Response::Value(Duration::from_millis(1000)));
+
+        handle.abort();
+
+        assert_eq!(rx.recv().await.expect("unexpected error receiving"), Response::Closed(CloseReason::Cancelled));
+        assert_eq!(handle.wait().await, Err(TaskError::Aborted));
+    }
+
+    #[tokio::test]
+    async fn should_abort_running_countdowns_when_dropped() {
+        time::pause();
+        let timer = AsyncCountdown::try_new(Duration::from_millis(100)).expect("should have created countdown");
+        let (rx, handle) = timer.start(Duration::from_millis(1000)).await.expect("unexpected countdown failure");
+        assert_eq!(rx.recv().await.expect("unexpected error awaiting initial value"), Response::Value(Duration::from_millis(1000)));
+
+        drop(timer);
+
+        loop {
+            match rx.recv().await.expect("unexpected error after dropping the timer") {
+                Response::Value(left) => assert_eq!(left, Duration::from_millis(1000), "received a tick after the timer was dropped"),
+                Response::CycleCompleted(cycles) => panic!("unexpected cycle {cycles} completed"),
+                Response::Closed(reason) => {
+                    assert_eq!(reason, CloseReason::Cancelled);
+                    break;
+                },
+            }
+        }
+        tokio::task::yield_now().await;
+        assert!(handle.is_finished(), "countdown task outlived its timer");
+    }
+
+    #[tokio::test]
+    async fn should_let_running_countdowns_complete_when_detached_on_drop() {
+        time::pause();
+        let timer = AsyncCountdown::builder()
+            .period(Duration::from_millis(100))
+            .detach_on_drop()
+            .build()
+            .expect("should have created countdown");
+
+        let (rx, _) = timer.start(Duration::from_millis(300)).await.expect("unexpected countdown failure");
+
+        let rx = rx.strict();
+        drop(timer);
+
+        let mut last_value = None;
+        loop {
+            match rx.recv().await.expect("unexpected error receiving from a detached countdown") {
+                Response::Value(left) => last_value = Some(left),
+                Response::CycleCompleted(cycles) => panic!("unexpected cycle {cycles} completed"),
+                Response::Closed(reason) => {
+                    assert_eq!(reason, CloseReason::Completed);
+                    break;
+                },
+            }
+        }
+
+        assert_eq!(last_value, Some(Duration::ZERO));
+    }
+
+    #[tokio::test]
+    async fn should_account_for_ticks_skipped_by_a_slow_consumer() {
+        time::pause();
+        let timer = AsyncCountdown::try_new(Duration::from_millis(100)).expect("should have created countdown");
+        let (rx, _) = timer.start(Duration::from_millis(6000)).await.expect("unexpected countdown failure");
+
+        let mut warnings = 0;
+        while let Response::Value(_) = rx.recv().await.expect("unexpected error receiving from a slow consumer") {
+            warnings += usize::from(rx.take_lag_warning().is_some());
+            time::sleep(Duration::from_millis(500)).await;
+        }
+
+        assert!(rx.skipped_total() >= 40, "expected most ticks to be skipped, but only {} were", rx.skipped_total());
+        assert_eq!(warnings, 1);
+    }
+
+    #[tokio::test]
+    async fn should_return_the_running_countdown_when_started_twice_with_the_same_key() {
+        time::pause();
+        let timer = AsyncCountdown::try_new(Duration::from_millis(100)).expect("should have created countdown");
+
+        let (first, first_handle) = timer.start_with_key("focus", Duration::from_millis(1000)).await.expect("unexpected countdown failure");
+        let (second, second_handle) = timer.start_with_key("focus", Duration::from_millis(500)).await.expect("should have returned the running countdown");
+
+        assert_eq!(timer.tasks().len(), 1);
+        assert_eq!(second.recv().await.expect("unexpected error receiving"), Response::Value(Duration::from_millis(1000)));
+        assert_eq!(second_handle.remaining(), first_handle.remaining());
+
+        let mut closed = 0;
+        for rx in [&first, &second] {
+            while let Response::Value(_) = rx.recv().await.expect("unexpected error receiving") {}
+            closed += 1;
+        }
+        assert_eq!(closed, 2);
+        assert_eq!(first_handle.wait().await, Ok(()));
+        assert_eq!(second_handle.wait().await, Ok(()));
+    }
+
+    #[tokio::test]
+    async fn should_abort_the_running_countdown_from_the_handle_returned_for_its_key() {
+        time::pause();
+        let timer = AsyncCountdown::try_new(Duration::from_millis(100)).expect("should have created countdown");
+        let (rx, first_handle) = timer.start_with_key("focus", Duration::from_millis(1000)).await.expect("unexpected countdown failure");
+        let (_, second_handle) = timer.start_with_key("focus", Duration::from_millis(1000)).await.expect("should have returned the running countdown");
+        assert_eq!(rx.recv().await.expect("unexpected error receiving"), Response::Value(Duration::from_millis(1000)));
+
+        second_handle.abort();
+
+        assert_eq!(rx.recv().await.expect("unexpected error receiving"), Response::Closed(CloseReason::Cancelled));
+        assert!(!first_handle.is_running());
+        assert_eq!(first_handle.wait().await, Err(TaskError::Aborted));
+        assert_eq!(second_handle.wait().await, Err(TaskError::Aborted));
+    }
+
+    #[tokio::test]
+    async fn should_reject_a_second_start_with_the_same_key_when_configured_to() {
+        time::pause();
+        let timer = AsyncCountdown::builder()
+            .period(Duration::from_millis(100))
+            .duplicate_start(DuplicateStart::Reject)
+            .build()
+            .expect("should have created countdown");
+
+        let _started = timer.start_with_key("focus", Duration::from_millis(1000)).await.expect("unexpected countdown failure");
+        let error = timer.start_with_key("focus", Duration::from_millis(1000)).await.expect_err("should have rejected the second start");
+
+        assert_eq!(error, StartError::AlreadyRunning { key: "focus".to_owned(), remaining: Duration::from_millis(1000) });
+    }
+
+    #[tokio::test]
+    async fn should_start_countdowns_with_different_keys_independently() {
+        time::pause();
+        let timer = AsyncCountdown::builder()
+            .period(Duration::from_millis(100))
+            .duplicate_start(DuplicateStart::Reject)
+            .build()
+            .expect("should have created countdown");
+
+        let (focus, _) = timer.start_with_key("focus", Duration::from_millis(1000)).await.expect("unexpected countdown failure");
+        let (rest, _) = timer.start_with_key("rest", Duration::from_millis(300)).await.expect("unexpected countdown failure");
+
+        assert_eq!(focus.recv().await.expect("unexpected error receiving"), Response::Value(Duration::from_millis(1000)));
+        assert_eq!(rest.recv().await.expect("unexpected error receiving"), Response::Value(Duration::from_millis(300)));
+    }
+
+    #[tokio::test]
+    async fn should_reuse_a_key_once_its_countdown_completed() {
+        time::pause();
+        let timer = AsyncCountdown::builder()
+            .period(Duration::from_millis(100))
+            .duplicate_start(DuplicateStart::Reject)
+            .build()
+            .expect("should have created countdown");
+
+        let (first, _) = timer.start_with_key("focus", Duration::from_millis(200)).await.expect("unexpected countdown failure");
+        while let Response::Value(_) = first.recv().await.expect("unexpected error receiving") {}
+
+        let (second, _) = timer.start_with_key("focus", Duration::from_millis(300)).await.expect("should have reused the key");
+        assert_eq!(second.recv().await.expect("unexpected error receiving"), Response::Value(Duration::from_millis(300)));
+    }
+
+    #[tokio::test]
+    async fn should_fail_to_create_a_countdown_given_a_period_below_10ms() {
+        let error = AsyncCountdown::try_new(Duration::from_millis(9)).expect_err("should have failed");
+        assert_eq!(error, TimerError::InvalidCountdown(InvalidCountdown::IntervalSmallerThanMinimum(Duration::from_millis(9))).into());
+    }
+
+    #[tokio::test]
+    async fn should_derive_the_channel_timeout_from_the_period() {
+        assert_eq!(AsyncCountdown::try_new(Duration::from_millis(10)).expect("should have created countdown").channel_timeout_ms(), 1000);
+        assert_eq!(AsyncCountdown::try_new(Duration::from_millis(1000)).expect("should have created countdown").channel_timeout_ms(), 3000);
+        assert_eq!(AsyncCountdown::try_new(Duration::from_millis(HOUR_MS)).expect("should have created countdown").channel_timeout_ms(), 3 * 3_600_000);
+    }
+
+    #[rstest]
+    #[case::fast(10, AckStrategy::FireAndForget)]
+    #[case::just_below_the_threshold(99, AckStrategy::FireAndForget)]
+    #[case::at_the_threshold(100, AckStrategy::Handshake)]
+    #[case::slow(1000, AckStrategy::Handshake)]
+    #[tokio::test]
+    async fn should_choose_the_ack_strategy_from_the_period(#[case] period_millis: u64, #[case] expected: AckStrategy) {
+        time::pause();
+        let timer = AsyncCountdown::try_new(Duration::from_millis(period_millis)).expect("should have created countdown");
+        let (rx, _) = timer.start(Duration::from_millis(1000)).await.expect("unexpected countdown failure");
+
+        assert_eq!(rx.ack_strategy(), expected);
+    }
+
+    #[tokio::test]
+    async fn should_wait_longer_than_the_period_before_timing_out_given_a_long_period() {
+        time::pause();
+        let timer = AsyncCountdown::try_new(Duration::from_millis(2000)).expect("should have created countdown");
+        let (rx, _) = timer.start(Duration::from_millis(4000)).await.expect("unexpected countdown failure");
+        let rx = rx.strict();
+
+        let mut values = Vec::new();
+        while let Response::Value(left) = rx.recv().await.expect("unexpected error receiving a slow countdown") {
+            values.push(left);
+        }
+
+        assert_eq!(values.last(), Some(&Duration::ZERO));
+    }
+
+    /// A sender that records every value sent to it.
+    #[derive(Clone, Default)]
+    struct Recorder(Arc<sync::Mutex<Vec<u64>>>);
+
+    impl Recorder {
+        fn values(&self) -> Vec<u64> {
+            self.0.lock().unwrap().clone()
+        }
+    }
+
+    impl Sender<u64> for Recorder {
+        async fn send(&self, value: u64) -> Result<()> {
+            self.0.lock().unwrap().push(value);
+            Ok(())
+        }
+
+        async fn close(&self) -> Result<()> {
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn should_send_a_single_corrected_value_when_the_interval_catches_up_on_missed_ticks() {
+        time::pause();
+        let mut interval = time::interval(Duration::from_millis(100));
+        interval.set_missed_tick_behavior(MissedTickBehavior::Burst);
+        let sent = Recorder::default();
+        let started_at = Instant::now();
+        let handle = tokio::spawn({
+            let sent = sent.clone();
+            async move { countdown(&mut interval, sent, Direction::Down, Cycle::new(2000, 2000), Duration::from_millis(100), &mut real_time(), Arc::default()).await }
+        });
+
+        time::sleep(Duration::from_millis(350)).await;
+        assert_eq!(sent.values(), [1900, 1800, 1700]);
+
+        // Jump past ten ticks at once, as a host waking from suspend would.
+        time::advance(Duration::from_millis(1000)).await;
+        let woke_after = started_at.elapsed().as_millis() as u64;
+        time::sleep(Duration::from_millis(10)).await;
+        assert_eq!(sent.values(), [1900, 1800, 1700, 2000 - woke_after]);
+
+        handle.await.expect("countdown task failed").expect("unexpected error counting down");
+        assert_eq!(sent.values()[3..], [2000 - woke_after, 600, 500, 400, 300, 200, 100, 0]);
+    }
+
+    /// A sender that takes `delay` to send each value, as a receiver slow to acknowledge them would, recording each value
+    /// with how long after `started_at` it was sent.
+    #[derive(Clone)]
+    struct SlowRecorder {
+        sent: Arc<sync::Mutex<Vec<(u64, u64)>>>,
+        started_at: Instant,
+        delay: Duration,
+    }
+
+    impl Sender<u64> for SlowRecorder {
+        async fn send(&self, value: u64) -> Result<()> {
+            self.sent.lock().unwrap().push((value, self.started_at.elapsed().as_millis() as u64));
+            time::sleep(self.delay).await;
+            Ok(())
+        }
+
+        async fn close(&self) -> Result<()> {
+            Ok(())
+        }
+    }
+
+    #[rstest]
+    #[case::burst(MissedTicks::Burst)]
+    #[case::delay(MissedTicks::Delay)]
+    #[case::skip(MissedTicks::Skip)]
+    #[tokio::test]
+    async fn should_send_the_time_really_left_when_sending_delays_the_ticks(#[case] missed_ticks: MissedTicks) {
+        time::pause();
+        let sender = SlowRecorder { sent: Arc::default(), started_at: Instant::now(), delay: Duration::from_millis(150) };
+        let mut interval = interval(Duration::from_millis(100), missed_ticks);
+
+        countdown(&mut interval, sender.clone(), Direction::Down, Cycle::new(1000, 1000), Duration::from_millis(100), &mut real_time(), Arc::default()).await.expect("unexpected error counting down");
+
+        let sent = sender.sent.lock().unwrap().clone();
+        assert_eq!(sent.last().map(|&(value, _)| value), Some(0));
+        assert!(sent.len() < 10, "expected the delayed ticks to be skipped, but got {sent:?}");
+        for &(value, elapsed) in &sent[..sent.len() - 1] {
+            // The paused clock rounds each timer up to the next whole millisecond.
+            assert!(value.abs_diff(1000 - elapsed) <= 1, "expected the time left when each value was sent, but got {sent:?}");
+        }
+        assert!(sent.windows(2).all(|pair| pair[1].0 < pair[0].0), "expected no value to be replayed, but got {sent:?}");
+    }
+
+    #[tokio::test]
+    async fn should_build_a_countdown_with_the_default_options() {
+        let timer = AsyncCountdown::builder().build().expect("should have built countdown");
+
+        assert_eq!(timer.period, DEFAULT_PERIOD);
+        assert_eq!(timer.missed_ticks, MissedTicks::Skip);
+        assert_eq!(timer.channel_timeout_ms(), 3000);
+        assert_eq!(timer.max_duration, None);
+        assert_eq!(timer.time_scale.0, None);
+        assert!(!timer.detach_on_drop);
+        assert_eq!(timer.duplicate_start, DuplicateStart::ReturnExisting);
+        assert!(!timer.align_to_wall_clock);
+        assert_eq!(timer.repeat, Repeat::Once);
+        assert_eq!(timer.direction, Direction::Down);
+        assert!(timer.hooks.tick.is_empty());
+        assert!(timer.hooks.complete.is_empty());
+        assert_eq!(timer.lag_warning, None);
+        assert_eq!(AsyncCountdown::try_new(Duration::from_millis(100)).expect("should have created countdown").missed_ticks, MissedTicks::Skip);
+    }
+
+    #[tokio::test]
+    async fn should_build_a_countdown_with_every_option() {
+        let timer = AsyncCountdown::builder()
+            .period(Duration::from_millis(200))
+            .missed_ticks(MissedTicks::Burst)
+            .channel_timeout(Duration::from_secs(2))
+            .max_duration(Duration::from_secs(60))
+            .time_scale(2.0)
+            .detach_on_drop()
+            .duplicate_start(DuplicateStart::Reject)
+            .repeat(Repeat::Cycles(3))
+            .direction(Direction::Up)
+            .on_tick(|_| {})
+            .on_complete(|| {})
+            .lag_warning(4, 75)
+            .build()
+            .expect("should have built countdown");
+
+        assert_eq!(timer.period, Duration::from_millis(200));
+        assert_eq!(timer.missed_ticks, MissedTicks::Burst);
+        assert_eq!(timer.channel_timeout_ms(), 2000);
+        assert_eq!(timer.max_duration, Some(Duration::from_secs(60)));
+        assert_eq!(timer.time_scale.0, Some(2.0));
+        assert!(timer.detach_on_drop);
+        assert_eq!(timer.duplicate_start, DuplicateStart::Reject);
+        assert_eq!(timer.repeat, Repeat::Cycles(3));
+        assert_eq!(timer.direction, Direction::Up);
+        assert_eq!(timer.hooks.tick.len(), 1);
+        assert_eq!(timer.hooks.complete.len(), 1);
+        assert_eq!(timer.lag_warning, Some((4, 75)));
+    }
+
+    #[rstest]
+    #[case::burst(MissedTicks::Burst)]
+    #[case::skip(MissedTicks::Skip)]
+    #[tokio::test]
+    async fn should_build_a_countdown_aligned_to_the_wall_clock(#[case] missed_ticks: MissedTicks) {
+        let timer = AsyncCountdown::builder()
+            .missed_ticks(missed_ticks)
+            .align_to_wall_clock()
+            .build()
+            .expect("should have built countdown");
+
+        assert!(timer.align_to_wall_clock);
+        assert_eq!(timer.missed_ticks, missed_ticks);
+    }
+
+    #[tokio::test]
+    async fn should_build_a_countdown_with_the_period() {
+        let timer = AsyncCountdown::builder()
+            .period(Duration::from_millis(250))
+            .build()
+            .expect("should have built countdown");
+
+        assert_eq!(timer.period, Duration::from_millis(250));
+        assert_eq!(interval(timer.period, timer.missed_ticks).period(), Duration::from_millis(250));
+    }
+
+    #[rstest]
+    #[case::burst(MissedTicks::Burst, MissedTickBehavior::Burst)]
+    #[case::delay(MissedTicks::Delay, MissedTickBehavior::Delay)]
+    #[case::skip(MissedTicks::Skip, MissedTickBehavior::Skip)]
+    #[tokio::test]
+    async fn should_build_a_countdown_with_the_missed_tick_behavior(#[case] missed_ticks: MissedTicks, #[case] expected: MissedTickBehavior) {
+        let timer = AsyncCountdown::builder().missed_ticks(missed_ticks).build().expect("should have built countdown");
+
+        assert_eq!(interval(timer.period, timer.missed_ticks).missed_tick_behavior(), expected);
+    }
+
+    #[tokio::test]
+    async fn should_build_a_countdown_with_the_channel_timeout() {
+        time::pause();
+        let timer = AsyncCountdown::builder()
+            .period(Duration::from_millis(100))
+            .channel_timeout(Duration::from_millis(150))
+            .build()
+            .expect("should have built countdown");
+        assert_eq!(timer.channel_timeout_ms(), 150);
+
+        let (rx, _) = timer.start(Duration::from_millis(1000)).await.expect("unexpected countdown failure");
+        assert_eq!(rx.recv().await.expect("unexpected error awaiting initial value"), Response::Value(Duration::from_millis(1000)));
+
+        assert_eq!(rx.timeout_remaining(), Some(Duration::from_millis(150)));
+    }
+
+    #[tokio::test]
+    async fn should_build_a_countdown_with_the_max_duration() {
+        time::pause();
+        let timer = AsyncCountdown::builder()
+            .period(Duration::from_millis(100))
+            .max_duration(Duration::from_millis(1000))
+            .build()
+            .expect("should have built countdown");
+        let too_long = InvalidDuration::DurationGreaterThanMaximum { duration: Duration::from_millis(1001), maximum: Duration::from_millis(1000) };
+
+        assert_eq!(timer.start(Duration::from_millis(1001)).await.map(drop), Err(StartError::InvalidDuration(too_long.clone())));
+
+        let (rx, _) = timer.start(Duration::from_millis(1000)).await.expect("unexpected countdown failure");
+        assert_eq!(rx.recv().await.expect("unexpected error awaiting initial value"), Response::Value(Duration::from_millis(1000)));
+        assert_eq!(rx.add(Duration::from_millis(1)).await, Err(too_long.into()));
+    }
+
+    #[rstest]
+    #[case::zero_period(
+        AsyncCountdown::builder().period(Duration::ZERO),
+        TimerError::InvalidCountdown(InvalidCountdown::ZeroInterval),
+    )]
+    #[case::timeout_within_the_period(
+        AsyncCountdown::builder().period(Duration::from_millis(100)).channel_timeout(Duration::from_millis(100)),
+        TimerError::InvalidCountdown(InvalidCountdown::TimeoutNotLongerThanInterval { timeout: Duration::from_millis(100), period: Duration::from_millis(100) }),
+    )]
+    #[case::max_duration_shorter_than_the_period(
+        AsyncCountdown::builder().period(Duration::from_millis(100)).max_duration(Duration::from_millis(99)),
+        TimerError::InvalidMaxDuration(InvalidDuration::DurationSmallerThanPeriod { duration: Duration::from_millis(99), period: Duration::from_millis(100) }),
+    )]
+    #[case::time_scale_of_zero(
+        AsyncCountdown::builder().time_scale(0.0),
+        TimerError::InvalidTimeScale(0.0),
+    )]
+    #[case::period_scaled_below_the_minimum(
+        AsyncCountdown::builder().period(Duration::from_millis(100)).time_scale(20.0),
+        TimerError::InvalidScaledPeriod { scale: 20.0, source: InvalidCountdown::IntervalSmallerThanMinimum(Duration::from_millis(5)) },
+    )]
+    #[case::wall_clock_at_a_time_scale(
+        AsyncCountdown::builder().align_to_wall_clock().time_scale(2.0),
+        TimerError::IncompatibleOptions { option: "align to the wall clock", conflicts_with: "run at a time scale" },
+    )]
+    #[case::wall_clock_at_real_time_scale(
+        AsyncCountdown::builder().time_scale(1.0).align_to_wall_clock(),
+        TimerError::IncompatibleOptions { option: "align to the wall clock", conflicts_with: "run at a time scale" },
+    )]
+    #[case::wall_clock_delaying_missed_ticks(
+        AsyncCountdown::builder().missed_ticks(MissedTicks::Delay).align_to_wall_clock(),
+        TimerError::IncompatibleOptions { option: "align to the wall clock", conflicts_with: "delay missed ticks" },
+    )]
+    #[tokio::test]
+    async fn should_fail_to_build_a_countdown_given_an_invalid_option(#[case] builder: AsyncCountdownBuilder, #[case] expected: TimerError) {
+        assert_eq!(builder.build().expect_err("should have failed to build countdown"), expected.into());
+    }
+
+    #[tokio::test]
+    async fn should_close_the_channel_when_the_countdown_task_panics() {
+        let (tx, rx) = Channel::new(Duration::from_millis(1000));
+        let handle = tokio::spawn(close_on_panic(tx.closer(), async move {
+            let _tx = tx;
+            panic!("injected panic");
+        }));
+
+        let error = handle.await.expect_err("should have panicked");
+        assert!(error.is_panic());
+        assert_eq!(rx.recv_forever().await.expect("unexpected error receiving"), Response::Value(Duration::from_millis(1000)));
+        assert_eq!(
+            rx.recv_forever().await.expect("unexpected error receiving"),
+            Response::Closed(CloseReason::Failed(FailureCode::Panicked)),
+        );
+    }
+
+    #[tokio::test]
+    async fn should_wait_for_the_countdown_task_to_finish() {
+        time::pause();
+        let timer = AsyncCountdown::try_new(Duration::from_millis(100)).expect("should have created countdown");
+        let (rx, handle) = timer.start(Duration::from_millis(300)).await.expect("unexpected countdown failure");
+
+        while let Response::Value(_) = rx.recv().await.expect("unexpected error receiving") {}
+
+        assert_eq!(handle.wait().await, Ok(()));
+    }
+
+    #[tokio::test]
+    async fn should_end_the_task_with_an_error_when_the_last_value_is_never_acknowledged() {
+        time::pause();
+        let timer = AsyncCountdown::try_new(Duration::from_millis(100)).expect("should have created countdown");
+        let (rx, handle) = timer.start(Duration::from_millis(300)).await.expect("unexpected countdown failure");
+
+        let error = handle.wait().await.expect_err("should have failed to close the channel");
+
+        assert_eq!(error, TaskError::Failed(ChannelError::Timeout(Duration::from_millis(1000)).into()));
+        assert_eq!(rx.recv().await.expect("unexpected error receiving"), Response::Value(Duration::ZERO));
+        assert_eq!(rx.recv().await.expect("unexpected error receiving"), Response::Closed(CloseReason::Failed(FailureCode::Unacknowledged)));
+    }
+
+    #[tokio::test]
+    async fn should_return_the_panic_message_when_waiting_for_a_task_that_panicked() {
+        let (tx, _rx) = Channel::new(Duration::from_millis(1000));
+        let closer = tx.closer();
+        let snapshots = SnapshotSender { tx: tx.clone(), latest: Arc::default(), hooks: None };
+        let task = tokio::spawn(close_on_panic(tx.closer(), async move {
+            let _tx = tx;
+            panic!("injected panic");
+        }));
+
+        let handle = CountdownHandle {
+            task: Arc::new(SharedTask::new(task)),
+            closer,
+            tx: snapshots,
+            direction: Direction::Down,
+            #[cfg(feature = "demo_mode")]
+            demo: DemoControls { tx: mpsc::unbounded_channel().0, period: None, timeout: Duration::from_millis(1000) },
+        };
+
+        assert_eq!(handle.wait().await, Err(TaskError::Panicked("injected panic".to_string())));
+    }
+
+    #[tokio::test]
+    async fn should_cancel_the_countdown_when_its_task_is_aborted() {
+        time::pause();
+        let timer = AsyncCountdown::try_new(Duration::from_millis(100)).expect("should have created countdown");
+        let (rx, handle) = timer.start(Duration::from_millis(1000)).await.expect("unexpected countdown failure");
+
+        handle.abort();
+        time::sleep(Duration::from_millis(300)).await;
+
+        assert!(handle.is_finished());
+        assert_eq!(rx.recv().await.expect("unexpected error receiving"), Response::Closed(CloseReason::Cancelled));
+        assert_eq!(handle.wait().await, Err(TaskError::Aborted));
+    }
+
+    #[tokio::test]
+    async fn should_snapshot_the_time_left_while_a_consumer_receives_every_value() {
+        time::pause();
+        let timer = AsyncCountdown::try_new(Duration::from_millis(100)).expect("should have created countdown");
+        let (rx, handle) = timer.start(Duration::from_millis(500)).await.expect("unexpected countdown failure");
+        let consumer = tokio::spawn(async move {
+            let mut values = Vec::new();
+            while let Response::Value(left) = rx.recv().await.expect("unexpected error receiving") {
+                values.push(left);
+            }
+            values
+        });
+
+        let mut snapshots = vec![(handle.remaining().as_millis(), handle.is_running())];
+        // Half way between two ticks, so that each snapshot is of the tick before.
+        time::sleep(Duration::from_millis(50)).await;
+        for _ in 0..6 {
+            snapshots.push((handle.remaining().as_millis(), handle.is_running()));
+            time::sleep(Duration::from_millis(100)).await;
+        }
+
+        assert_eq!(consumer.await.expect("the consumer should not have panicked"), [500, 400, 300, 200, 100, 0].map(Duration::from_millis));
+        assert_eq!(snapshots, [(500, true), (500, true), (400, true), (300, true), (200, true), (100, true), (0, false)]);
+        assert_eq!(handle.wait().await, Ok(()));
+    }
+
+    #[tokio::test]
+    async fn should_snapshot_the_first_value_of_each_cycle() {
+        time::pause();
+        let timer = AsyncCountdown::builder()
+            .period(Duration::from_millis(100))
+            .repeat(Repeat::Cycles(2))
+            .build()
+            .expect("should have created countdown");
+        let (rx, handle) = timer.start(Duration::from_millis(200)).await.expect("unexpected countdown failure");
+
+        let mut snapshots = Vec::new();
+        loop {
+            match rx.recv().await.expect("unexpected error receiving") {
+                Response::Value(left) => snapshots.push((left.as_millis(), handle.remaining().as_millis())),
+                Response::CycleCompleted(_) => {},
+                Response::Closed(_) => break,
+            }
+        }
+
+        assert_eq!(snapshots, [(200, 200), (100, 100), (0, 0), (200, 200), (100, 100), (0, 0)]);
+        assert!(!handle.is_running());
+    }
+
+    #[tokio::test]
+    async fn should_stop_running_once_aborted() {
+        time::pause();
+        let timer = AsyncCountdown::try_new(Duration::from_millis(100)).expect("should have created countdown");
+        let (_rx, handle) = timer.start(Duration::from_millis(1000)).await.expect("unexpected countdown failure");
+        assert!(handle.is_running());
+
+        handle.abort();
+
+        assert!(!handle.is_running());
+        assert_eq!(handle.remaining(), Duration::from_millis(1000));
+    }
+
+    #[tokio::test]
+    async fn should_send_unscaled_values_at_the_scaled_period() {
+        time::pause();
+        let timer = AsyncCountdown::builder().period(Duration::from_millis(100)).time_scale(10.0).build()
+            .expect("should have built countdown");
+        let started = Instant::now();
+        let (rx, handle) = timer.start(Duration::from_millis(1000)).await.expect("unexpected countdown failure");
+
+        let mut values = Vec::new();
+        let mut received_at = Vec::new();
+        while let Response::Value(left) = rx.recv().await.expect("unexpected error receiving") {
+            values.push(left);
+            received_at.push(started.elapsed());
+        }
+
+        assert_eq!(values, [1000, 900, 800, 700, 600, 500, 400, 300, 200, 100, 0].map(Duration::from_millis));
+        for (tick, at) in (0..).zip(received_at) {
+            let due = Duration::from_millis(10) * tick;
+            assert!(at.abs_diff(due) <= TIMER_RESOLUTION, "expected tick {tick} at {due:?}, but it came at {at:?}");
+        }
+        assert_eq!(handle.wait().await, Ok(()));
+    }
+
+    #[tokio::test]
+    async fn should_finish_a_scaled_countdown_sooner_in_real_time() {
+        let timer = AsyncCountdown::builder().period(Duration::from_millis(100)).time_scale(10.0).build()
+            .expect("should have built countdown");
+        let started = std::time::Instant::now();
+        let (rx, _) = timer.start(Duration::from_millis(1000)).await.expect("unexpected countdown failure");
+
+        let mut last = None;
+        while let Response::Value(left) = rx.recv().await.expect("unexpected error receiving") {
+            last = Some(left);
+        }
+
+        assert_eq!(last, Some(Duration::ZERO));
+        let elapsed = started.elapsed();
+        assert!(elapsed >= Duration::from_millis(100), "expected the countdown to take 100ms, but it took {elapsed:?}");
+        assert!(elapsed < Duration::from_millis(1000), "expected the countdown to take 100ms, but it took {elapsed:?}");
+    }
+
+    #[cfg(feature = "demo_mode")]
+    #[rstest]
+    #[case::sped_up(10.0)]
+    #[case::slowed_down(0.5)]
+    #[tokio::test]
+    async fn should_send_the_same_values_at_the_new_speed_from_the_tick_due_next(#[case] speed: f64) {
+        time::pause();
+        let timer = AsyncCountdown::try_new(Duration::from_millis(100)).expect("should have created countdown");
+        let started = Instant::now();
+        let (rx, handle) = timer.start(Duration::from_millis(1000)).await.expect("unexpected countdown failure");
+        assert_eq!(rx.recv().await.expect("unexpected error receiving"), Response::Value(Duration::from_millis(1000)));
+        time::sleep(Duration::from_millis(50)).await;
+
+        handle.set_speed(speed).expect("should have changed the countdown's speed");
+
+        let mut values = Vec::new();
+        let mut received_at = Vec::new();
+        while let Response::Value(left) = rx.recv().await.expect("unexpected error receiving") {
+            values.push(left);
+            received_at.push(started.elapsed());
+        }
+
+        assert_eq!(values, [900, 800, 700, 600, 500, 400, 300, 200, 100, 0].map(Duration::from_millis));
+        for (value, at) in values.into_iter().zip(received_at) {
+            // Half of the first period went by at the old speed.
+            let due = Duration::from_millis(50) + Duration::from_secs_f64((Duration::from_millis(950) - value).as_secs_f64() / speed);
+            assert!(at.abs_diff(due) <= TIMER_RESOLUTION, "expected {value:?} at {due:?}, but it came at {at:?}");
+        }
+        assert_eq!(handle.wait().await, Ok(()));
+    }
+
+    #[cfg(feature = "demo_mode")]
+    #[tokio::test]
+    async fn should_keep_the_new_speed_from_one_cycle_to_the_next() {
+        time::pause();
+        let scaled = builder().repeat(Repeat::Cycles(2)).time_scale(10.0).build().expect("should have built countdown");
+        let started = Instant::now();
+        let (rx, _) = scaled.start(Duration::from_millis(300)).await.expect("unexpected countdown failure");
+        receive_until_closed(&rx).await;
+        let scaled_from_the_start = started.elapsed();
+
+        let timer = builder().repeat(Repeat::Cycles(2)).build().expect("should have built countdown");
+        let started = Instant::now();
+        let (rx, handle) = timer.start(Duration::from_millis(300)).await.expect("unexpected countdown failure");
+        handle.set_speed(10.0).expect("should have sped the countdown up");
+
+        assert_eq!(receive_until_closed(&rx).await, Response::Closed(CloseReason::Completed));
+        assert_eq!(started.elapsed(), scaled_from_the_start);
+    }
+
+    #[cfg(feature = "demo_mode")]
+    #[rstest]
+    #[case::forward(Direction::Down, Duration::from_millis(300), [300, 200, 100, 0])]
+    #[case::back(Direction::Down, Duration::from_millis(1000), [1000, 900, 800, 700, 600, 500, 400, 300, 200, 100, 0])]
+    #[case::counting_up(Direction::Up, Duration::from_millis(300), [700, 800, 900, 1000])]
+    #[tokio::test]
+    async fn should_jump_to_the_time_left_then_count_on_from_there<const N: usize>(
+        #[case] direction: Direction,
+        #[case] remaining: Duration,
+        #[case] expected: [u64; N],
+    ) {
+        time::pause();
+        let timer = builder().direction(direction).build().expect("should have built countdown");
+        let (rx, handle) = timer.start(Duration::from_millis(1000)).await.expect("unexpected countdown failure");
+        rx.recv().await.expect("unexpected error receiving");
+        rx.recv().await.expect("unexpected error receiving");
+        let jumped_at = Instant::now();
+
+        handle.set_remaining(remaining).expect("should have jumped the countdown");
+
+        let mut values = Vec::new();
+        let mut received_at = Vec::new();
+        while let Response::Value(value) = rx.recv().await.expect("unexpected error receiving") {
+            values.push(value);
+            received_at.push(jumped_at.elapsed());
+        }
+
+        assert_eq!(values, expected.map(Duration::from_millis));
+        for (tick, at) in (0..).zip(received_at) {
+            let due = Duration::from_millis(100) * tick;
+            assert!(at.abs_diff(due) <= TIMER_RESOLUTION, "expected tick {tick} at {due:?}, but it came at {at:?}");
+        }
+        assert_eq!(handle.wait().await, Ok(()));
+    }
+
+    #[cfg(feature = "demo_mode")]
+    #[tokio::test]
+    async fn should_show_the_time_jumped_to_as_remaining() {
+        time::pause();
+        let timer = AsyncCountdown::try_new(Duration::from_millis(100)).expect("should have created countdown");
+        let (rx, handle) = timer.start(Duration::from_millis(1000)).await.expect("unexpected countdown failure");
+        rx.recv().await.expect("unexpected error receiving");
+
+        handle.set_remaining(Duration::from_millis(250)).expect("should have jumped the countdown");
+
+        assert_eq!(rx.recv().await.expect("unexpected error receiving"), Response::Value(Duration::from_millis(250)));
+        assert_eq!(handle.remaining(), Duration::from_millis(250));
+    }
+
+    #[cfg(feature = "demo_mode")]
+    #[rstest]
+    #[case::stopped(0.0, TimerError::InvalidTimeScale(0.0))]
+    #[case::period_too_short(
+        100.0,
+        TimerError::InvalidScaledPeriod { scale: 100.0, source: InvalidCountdown::IntervalSmallerThanMinimum(Duration::from_millis(1)) },
+    )]
+    #[case::period_not_shorter_than_the_timeout(
+        0.1,
+        TimerError::InvalidScaledPeriod {
+            scale: 0.1,
+            source: InvalidCountdown::TimeoutNotLongerThanInterval { timeout: Duration::from_secs(1), period: Duration::from_secs(1) },
+        },
+    )]
+    #[tokio::test]
+    async fn should_fail_to_set_an_invalid_speed(#[case] speed: f64, #[case] expected: TimerError) {
+        time::pause();
+        let timer = AsyncCountdown::try_new(Duration::from_millis(100)).expect("should have created countdown");
+        let (_rx, handle) = timer.start(Duration::from_millis(1000)).await.expect("unexpected countdown failure");
+
+        assert_eq!(handle.set_speed(speed), Err(CountdownError::TimerError(expected)));
+    }
+
+    #[cfg(feature = "demo_mode")]
+    #[tokio::test]
+    async fn should_fail_to_change_the_speed_of_a_countdown_keeping_to_the_wall_clock() {
+        time::pause();
+        let timer = builder().align_to_wall_clock().build().expect("should have built countdown");
+        let (_rx, handle) = timer.start(Duration::from_millis(1000)).await.expect("unexpected countdown failure");
+
+        assert_eq!(
+            handle.set_speed(10.0),
+            Err(CountdownError::TimerError(TimerError::IncompatibleOptions { option: "set_speed", conflicts_with: "the wall clock" })),
+        );
+    }
+
+    #[cfg(feature = "demo_mode")]
+    #[tokio::test]
+    async fn should_fail_to_jump_past_the_start_of_the_countdown() {
+        time::pause();
+        let timer = AsyncCountdown::try_new(Duration::from_millis(100)).expect("should have created countdown");
+        let (_rx, handle) = timer.start(Duration::from_millis(1000)).await.expect("unexpected countdown failure");
+
+        assert_eq!(
+            handle.set_remaining(Duration::from_millis(1001)),
+            Err(CountdownError::InvalidDuration(InvalidDuration::RemainingGreaterThanDuration {
+                remaining: Duration::from_millis(1001),
+                duration: Duration::from_millis(1000),
+            })),
+        );
+    }
+
+    #[cfg(feature = "demo_mode")]
+    #[tokio::test]
+    async fn should_fail_to_jump_an_uncapped_count_up() {
+        time::pause();
+        let timer = builder().direction(Direction::Up).build().expect("should have built countdown");
+        let (_rx, handle) = timer.start(Duration::ZERO).await.expect("unexpected countdown failure");
+
+        assert_eq!(
+            handle.set_remaining(Duration::from_millis(100)),
+            Err(CountdownError::TimerError(TimerError::IncompatibleOptions {
+                option: "set_remaining",
+                conflicts_with: "an uncapped count-up",
+            })),
+        );
+    }
+
+    #[cfg(feature = "demo_mode")]
+    #[tokio::test]
+    async fn should_fail_to_control_a_countdown_that_has_closed() {
+        time::pause();
+        let timer = AsyncCountdown::try_new(Duration::from_millis(100)).expect("should have created countdown");
+        let (rx, handle) = timer.start(Duration::from_millis(100)).await.expect("unexpected countdown failure");
+        receive_until_closed(&rx).await;
+
+        let closed = Err(CountdownError::ChannelError(ChannelError::Closed(CloseReason::Completed)));
+        assert_eq!(handle.set_speed(10.0), closed);
+        assert_eq!(handle.set_remaining(Duration::ZERO), closed);
+    }
+
+    /// Records each value the tick hooks see as `Some` of its milliseconds, and each completion as `None`, until the timer has gone.
+    fn recording_hooks(builder: AsyncCountdownBuilder) -> (AsyncCountdown, mpsc::UnboundedReceiver<Option<u64>>) {
+        let (tx, rx) = mpsc::unbounded_channel();
+        let completed = tx.clone();
+        let timer = builder
+            .on_tick(move |value| tx.send(Some(millis(value))).expect("unexpected error recording a tick"))
+            .on_complete(move || completed.send(None).expect("unexpected error recording the completion"))
+            .build()
+            .expect("should have created countdown");
+        (timer, rx)
+    }
+
+    fn builder() -> AsyncCountdownBuilder {
+        AsyncCountdown::builder().period(Duration::from_millis(100))
+    }
+
+    /// Returns the pacing of a countdown counting in real time, which no demo controls can change.
+    fn real_time() -> Pacing {
+        Pacing {
+            pace: Pace { at: Instant::now(), ticked: 0, time_scale: TimeScale::default() },
+            #[cfg(feature = "demo_mode")]
+            controls: mpsc::unbounded_channel().1,
+        }
+    }
+
+    async fn receive_until_closed(rx: &ChannelReceiver<Duration>) -> Response<Duration> {
+        loop {
+            if let Response::Closed(reason) = rx.recv().await.expect("unexpected error receiving") {
+                return Response::Closed(reason);
+            }
+        }
+    }
+
+    async fn recorded(mut events: mpsc::UnboundedReceiver<Option<u64>>) -> Vec<Option<u64>> {
+        let mut recorded = Vec::new();
+        while let Some(event) = events.recv().await {
+            recorded.push(event);
+        }
+        recorded
+    }
+
+    #[tokio::test]
+    async fn should_call_the_hooks_with_every_value_then_on_completion() {
+        time::pause();
+        let (timer, events) = recording_hooks(builder());
+        let (rx, handle) = timer.start(Duration::from_millis(300)).await.expect("unexpected countdown failure");
+
+        assert_eq!(receive_until_closed(&rx).await, Response::Closed(CloseReason::Completed));
+        assert_eq!(handle.wait().await, Ok(()));
+        drop(timer);
+
+        assert_eq!(recorded(events).await, [Some(300), Some(200), Some(100), Some(0), None]);
+    }
+
+    #[tokio::test]
+    async fn should_call_the_hooks_with_the_first_value_of_each_cycle() {
+        time::pause();
+        let (timer, events) = recording_hooks(builder().repeat(Repeat::Cycles(2)));
+        let (rx, handle) = timer.start(Duration::from_millis(200)).await.expect("unexpected countdown failure");
+
+        assert_eq!(receive_until_closed(&rx).await, Response::Closed(CloseReason::Completed));
+        assert_eq!(handle.wait().await, Ok(()));
+        drop(timer);
+
+        assert_eq!(recorded(events).await, [Some(200), Some(100), Some(0), Some(200), Some(100), Some(0), None]);
+    }
+
+    #[tokio::test]
+    async fn should_carry_on_counting_when_a_hook_panics() {
+        time::pause();
+        let builder = builder()
+            .on_tick(|value| assert_ne!(value, Duration::from_millis(200), "injected panic"))
+            .on_complete(|| panic!("injected panic"));
+        let (timer, events) = recording_hooks(builder);
+        let (rx, handle) = timer.start(Duration::from_millis(300)).await.expect("unexpected countdown failure");
+
+        assert_eq!(receive_until_closed(&rx).await, Response::Closed(CloseReason::Completed));
+        assert_eq!(handle.wait().await, Ok(()));
+        drop(timer);
+
+        assert_eq!(recorded(events).await, [Some(300), Some(200), Some(100), Some(0), None]);
+    }
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn should_tick_on_schedule_while_a_hook_blocks() {
+        // In real time, since the hook blocks its thread for real.
+        let timer = builder()
+            .on_tick(|_| thread::sleep(Duration::from_millis(250)))
+            .build()
+            .expect("should have created countdown");
+        let started = Instant::now();
+        let (rx, _) = timer.start(Duration::from_millis(500)).await.expect("unexpected countdown failure");
+
+        let mut ticks = Vec::new();
+        while let Response::Value(left) = rx.recv().await.expect("unexpected error receiving") {
+            ticks.push((left, started.elapsed()));
+        }
+
+        assert_eq!(ticks.len(), 6);
+        assert_eq!(ticks.last().map(|&(left, _)| left), Some(Duration::ZERO));
+        for (tick, &(_, elapsed)) in (0..).zip(&ticks) {
+            assert!(elapsed < Duration::from_millis(tick * 100 + 80), "tick {tick} arrived late, after {elapsed:?}");
+        }
+    }
+
+    #[tokio::test]
+    async fn should_not_call_the_completion_hooks_of_a_cancelled_countdown() {
+        time::pause();
+        let (timer, events) = recording_hooks(builder());
+        let (_rx, handle) = timer.start(Duration::from_millis(1000)).await.expect("unexpected countdown failure");
+        time::sleep(Duration::from_millis(150)).await;
+
+        handle.abort();
+        drop((timer, handle));
+
+        assert_eq!(recorded(events).await, [Some(1000), Some(900)]);
+    }
+
+    #[tokio::test]
+    async fn should_send_zero_then_close_as_skipped_when_skipped_mid_run() {
+        time::pause();
+        let (timer, events) = recording_hooks(builder());
+        let (rx, handle) = timer.start(Duration::from_millis(1000)).await.expect("unexpected countdown failure");
+        assert_eq!(rx.recv().await.expect("unexpected error receiving"), Response::Value(Duration::from_millis(1000)));
+        assert_eq!(rx.recv().await.expect("unexpected error receiving"), Response::Value(Duration::from_millis(900)));
+
+        handle.skip().await;
+
+        let period = Duration::from_millis(100);
+        let skipped = async {
+            (rx.recv().await.expect("unexpected error receiving"), rx.recv().await.expect("unexpected error receiving"))
+        };
+        let received = time::timeout(period, skipped).await.expect("should have closed within one period");
+        assert_eq!(received, (Response::Value(Duration::ZERO), Response::Closed(CloseReason::Skipped)));
+        assert_eq!(handle.remaining(), Duration::ZERO);
+        assert!(!handle.is_running());
+        assert_eq!(handle.wait().await, Ok(()));
+        drop(timer);
+
+        assert_eq!(recorded(events).await, [Some(1000), Some(900), Some(0), None]);
+    }
+
+    #[rstest]
+    #[case::completed(CloseReason::Completed)]
+    #[case::cancelled(CloseReason::Cancelled)]
+    #[tokio::test]
+    async fn should_do_nothing_when_skipping_a_countdown_that_has_closed(#[case] reason: CloseReason) {
+        time::pause();
+        let timer = AsyncCountdown::try_new(Duration::from_millis(100)).expect("should have created countdown");
+        let (rx, handle) = timer.start(Duration::from_millis(200)).await.expect("unexpected countdown failure");
+        match reason {
+            CloseReason::Cancelled => handle.abort(),
+            _ => assert_eq!(receive_until_closed(&rx).await, Response::Closed(reason)),
+        }
+
+        handle.skip().await;
+
+        assert_eq!(rx.recv().await.expect("unexpected error receiving"), Response::Closed(reason));
+        assert!(!handle.is_running());
+    }
+
+    #[tokio::test]
+    async fn should_end_every_cycle_of_a_repeating_countdown_when_skipped() {
+        time::pause();
+        let timer = AsyncCountdown::builder()
+            .period(Duration::from_millis(100))
+            .repeat(Repeat::Forever)
+            .build()
+            .expect("should have created countdown");
+        let (rx, handle) = timer.start(Duration::from_millis(300)).await.expect("unexpected countdown failure");
+        assert_eq!(rx.recv().await.expect("unexpected error receiving"), Response::Value(Duration::from_millis(300)));
+
+        handle.skip().await;
+
+        assert_eq!(rx.recv().await.expect("unexpected error receiving"), Response::Value(Duration::ZERO));
+        assert_eq!(rx.recv().await.expect("unexpected error receiving"), Response::Closed(CloseReason::Skipped));
+        assert_eq!(handle.wait().await, Ok(()));
+    }
+
+    #[rstest]
+    #[case::capped(1000, vec![Response::Value(Duration::from_millis(1000)), Response::Closed(CloseReason::Skipped)])]
+    #[case::uncapped(0, vec![Response::Closed(CloseReason::Skipped)])]
+    #[tokio::test]
+    async fn should_skip_a_count_up_to_its_duration(#[case] duration_millis: u64, #[case] expected: Vec<Response<Duration>>) {
+        time::pause();
+        let timer = AsyncCountdown::builder()
+            .period(Duration::from_millis(100))
+            .direction(Direction::Up)
+            .build()
+            .expect("should have created countdown");
+        let (rx, handle) = timer.start(Duration::from_millis(duration_millis)).await.expect("unexpected countdown failure");
+        assert_eq!(rx.recv().await.expect("unexpected error receiving"), Response::Value(Duration::ZERO));
+        assert_eq!(rx.recv().await.expect("unexpected error receiving"), Response::Value(Duration::from_millis(100)));
+
+        handle.skip().await;
+
+        let mut received = Vec::new();
+        for _ in 0..expected.len() {
+            received.push(rx.recv().await.expect("unexpected error receiving"));
+        }
+        assert_eq!(received, expected);
+    }
+
+    #[tokio::test]
+    async fn should_resume_from_the_deadline_after_missing_ticks() {
+        time::pause();
+        let timer = AsyncCountdown::try_new(Duration::from_millis(100)).expect("should have created countdown");
+        let (rx, _) = timer.start(Duration::from_millis(2000)).await.expect("unexpected countdown failure");
+        let rx = rx.strict();
+
+        let started_at = Instant::now();
+        time::sleep(Duration::from_millis(350)).await;
+        assert_eq!(rx.recv().await.expect("unexpected error"), Response::Value(Duration::from_millis(1700)));
+
+        time::advance(Duration::from_millis(1000)).await;
+        let woke_after = started_at.elapsed().as_millis() as u64;
+
+        let mut values = Vec::new();
+        while let Response::Value(left) = rx.recv().await.expect("unexpected error after missing ticks") {
+            values.push(left);
+        }
+
+        assert_eq!(values, [2000 - woke_after, 600, 500, 400, 300, 200, 100, 0].map(Duration::from_millis));
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn should_deliver_most_ticks_of_a_10ms_countdown_in_real_time() {
+        for run in 0..3 {
+            let timer = AsyncCountdown::try_new(Duration::from_millis(10)).expect("should have created countdown");
+            let (rx, _) = timer.start(Duration::from_millis(1000)).await.expect("unexpected countdown failure");
+
+            let mut values = Vec::new();
+            while let Response::Value(left) = rx.recv().await.expect("unexpected error receiving a fast countdown") {
+                values.push(left);
+            }
+            values.dedup();
+
+            assert_eq!(values.last(), Some(&Duration::ZERO), "[{run}] the final zero was not delivered");
+            assert!(values.len() >= 91, "[{run}] only {} of 101 ticks were delivered", values.len());
+        }
+    }
+}
@@ -0,0 +1,462 @@
+//! A ready-made loop over everything the consumer of a countdown waits on: its ticks, the keys pressed, the terminal
+//! being resized and a request to shut down, joined into one stream of [`AppEvent`]s by [`EventLoop::next_event`].
+//!
+//! Keys and sizes come from whatever reads the terminal, sent over a [`mpsc`] channel, so the loop does not tie
+//! embedders to one terminal library.
+
+use std::{fmt, future::{self, Future}, pin::Pin, time::Duration};
+
+use tokio::sync::{mpsc, oneshot};
+
+use crate::countdown::{ChannelReceiver, CloseReason, LagWarning, Response, Result};
+
+type TickFuture = Pin<Box<dyn Future<Output = Result<Response<Duration>>> + Send>>;
+type TickSource = Box<dyn FnMut() -> TickFuture + Send>;
+
+/// Something the consumer of a countdown has to respond to, returned by [`EventLoop::next_event`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AppEvent<K> {
+    /// The countdown sent a value: the time left, or elapsed for one that counts up.
+    Tick(Duration),
+    /// A repeating countdown has completed this many cycles, and the next tick is the first of the next one.
+    PhaseChange(u32),
+    /// A [`crate::countdown::Sequence`] has moved on to the segment at `index`, named `label`, and the next tick is the
+    /// first of it. Returned instead of [`AppEvent::PhaseChange`] for a sequence.
+    SegmentStarted {
+        /// The index of the segment, in the order the segments run.
+        index: u32,
+        /// The label the segment was given.
+        label: String,
+    },
+    /// The countdown has closed for this reason, and sends no more ticks.
+    Closed(CloseReason),
+    /// The consumer has skipped too many ticks, returned once right after the tick that raised it. See
+    /// [`crate::countdown::AsyncCountdownBuilder::lag_warning`].
+    Lagging(LagWarning),
+    /// A key was pressed.
+    Key(K),
+    /// The terminal was resized to this many columns and rows.
+    Resize(u16, u16),
+    /// Shutting down was requested.
+    Shutdown,
+}
+
+/// Owns the sources a consumer of a countdown waits on, and returns whichever has something first from
+/// [`EventLoop::next_event`].
+///
+/// The countdown is the only source an [`EventLoop`] needs: [`EventLoop::keys`], [`EventLoop::resizes`] and
+/// [`EventLoop::shutdown`] add the others.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+///
+/// use libtomatillo::{countdown::{AsyncCountdown, CloseReason, Countdown}, event_loop::{AppEvent, EventLoop}};
+/// use tokio::sync::mpsc;
+///
+/// # #[tokio::main(flavor = "current_thread", start_paused = true)]
+/// # async fn main() {
+/// let timer = AsyncCountdown::try_new(Duration::from_millis(100)).expect("should have created the timer");
+/// let (rx, _) = timer.start(Duration::from_millis(300)).await.expect("should have started the countdown");
+/// let (keys, pressed) = mpsc::channel(8);
+/// let mut events = EventLoop::new(rx).keys(pressed);
+///
+/// keys.send('q').await.expect("the event loop should be receiving keys");
+/// loop {
+///     match events.next_event().await.expect("should have received every tick") {
+///         AppEvent::Key('q') | AppEvent::Shutdown => break,
+///         AppEvent::Closed(reason) => panic!("expected the countdown to be quit, but it {reason}"),
+///         _ => {},
+///     }
+/// }
+/// # }
+/// ```
+pub struct EventLoop<K> {
+    next_tick: TickSource,
+    tick: Option<TickFuture>,
+    countdown: Option<ChannelReceiver<Duration>>,
+    lagging: Option<LagWarning>,
+    keys: Option<mpsc::Receiver<K>>,
+    resizes: Option<mpsc::Receiver<(u16, u16)>>,
+    shutdown: Option<oneshot::Receiver<()>>,
+}
+
+impl<K> fmt::Debug for EventLoop<K> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("EventLoop")
+            .field("ticking", &self.tick.is_some())
+            .field("lagging", &self.lagging)
+            .field("keys", &self.keys.is_some())
+            .field("resizes", &self.resizes.is_some())
+            .field("shutdown", &self.shutdown.is_some())
+            .finish()
+    }
+}
+
+impl<K> EventLoop<K> {
+    /// Creates an event loop over the ticks of a countdown.
+    ///
+    /// Ticks are received with [`ChannelReceiver::recv_forever`], so a coarse period never times out between them.
+    ///
+    /// # Arguments
+    ///
+    /// * `countdown` - The receiver returned when the countdown was started.
+    #[must_use]
+    pub fn new(countdown: ChannelReceiver<Duration>) -> Self {
+        let receiver = countdown.clone();
+        let events = Self::from_ticks(move || {
+            let countdown = countdown.clone();
+            Box::pin(async move { countdown.recv_forever().await })
+        });
+
+        Self { countdown: Some(receiver), ..events }
+    }
+
+    fn from_ticks(next_tick: impl FnMut() -> TickFuture + Send + 'static) -> Self {
+        let mut next_tick: TickSource = Box::new(next_tick);
+        let tick = Some(next_tick());
+
+        Self { next_tick, tick, countdown: None, lagging: None, keys: None, resizes: None, shutdown: None }
+    }
+
+    /// Sets where the keys pressed come from, returned as [`AppEvent::Key`]. Once every sender has been dropped, no
+    /// more keys are waited for.
+    ///
+    /// # Arguments
+    ///
+    /// * `keys` - The receiving half of the channel the keys are sent over.
+    #[must_use]
+    pub fn keys(mut self, keys: mpsc::Receiver<K>) -> Self {
+        self.keys = Some(keys);
+        self
+    }
+
+    /// Sets where the new sizes of the terminal come from, as columns and rows, returned as [`AppEvent::Resize`]. Once
+    /// every sender has been dropped, no more sizes are waited for.
+    ///
+    /// # Arguments
+    ///
+    /// * `resizes` - The receiving half of the channel the sizes are sent over.
+    #[must_use]
+    pub fn resizes(mut self, resizes: mpsc::Receiver<(u16, u16)>) -> Self {
+        self.resizes = Some(resizes);
+        self
+    }
+
+    /// Sets the request to shut down, returned once as [`AppEvent::Shutdown`] when it is sent. Dropping the sender
+    /// instead means shutting down is never requested.
+    ///
+    /// # Arguments
+    ///
+    /// * `shutdown` - The receiving half of the channel the request is sent over.
+    #[must_use]
+    pub fn shutdown(mut self, shutdown: oneshot::Receiver<()>) -> Self {
+        self.shutdown = Some(shutdown);
+        self
+    }
+
+    /// Waits for the next event from any source.
+    ///
+    /// Sources are checked in order: shutting down first, then ticks, resizes and keys, so keys pressed faster than
+    /// they are handled never hold back a tick. Ticks arrive once per period at most, so they cannot hold back keys in
+    /// turn. Like every receive, ticks the consumer falls behind on are skipped and only the latest is returned, and
+    /// once too many have been, [`AppEvent::Lagging`] is returned before anything else.
+    ///
+    /// Calling this is cancel-safe: a tick that was being received when the call was dropped, for instance by a
+    /// `select!` of the caller's own, is returned by the next call rather than lost.
+    ///
+    /// Once the countdown has closed, and every other source has ended, this waits forever: stop calling it after
+    /// [`AppEvent::Closed`] or [`AppEvent::Shutdown`].
+    ///
+    /// # Returns
+    ///
+    /// A [`Result`] that is:
+    ///
+    /// * `Ok(event)` - The next event.
+    /// * `Err(err)` - A tick could not be acknowledged. No more ticks are waited for.
+    pub async fn next_event(&mut self) -> Result<AppEvent<K>> {
+        if let Some(warning) = self.lagging.take() {
+            return Ok(AppEvent::Lagging(warning));
+        }
+
+        loop {
+            tokio::select! {
+                biased;
+                requested = requested(&mut self.shutdown) => {
+                    self.shutdown = None;
+                    if requested {
+                        return Ok(AppEvent::Shutdown);
+                    }
+                },
+                response = next(&mut self.tick) => {
+                    let response = response.inspect_err(|_| self.tick = None)?;
+                    self.tick = match response {
+                        Response::Closed(_) => None,
+                        _ => Some((self.next_tick)()),
+                    };
+
+                    return Ok(match response {
+                        Response::Value(value) => {
+                            self.lagging = self.countdown.as_ref().and_then(ChannelReceiver::take_lag_warning);
+                            AppEvent::Tick(value)
+                        },
+                        Response::CycleCompleted(cycles) => match self.segment_label(cycles) {
+                            Some(label) => AppEvent::SegmentStarted { index: cycles, label },
+                            None => AppEvent::PhaseChange(cycles),
+                        },
+                        Response::Closed(reason) => AppEvent::Closed(reason),
+                    });
+                },
+                size = recv(&mut self.resizes) => match size {
+                    Some((columns, rows)) => return Ok(AppEvent::Resize(columns, rows)),
+                    None => self.resizes = None,
+                },
+                key = recv(&mut self.keys) => match key {
+                    Some(key) => return Ok(AppEvent::Key(key)),
+                    None => self.keys = None,
+                },
+            }
+        }
+    }
+
+    /// Returns the label of the segment at `index`, if the countdown is a sequence.
+    fn segment_label(&self, index: u32) -> Option<String> {
+        self.countdown.as_ref()?.segment_label(index).map(str::to_owned)
+    }
+}
+
+/// Waits for a shutdown request: `true` if it was sent, `false` if its sender was dropped.
+async fn requested(shutdown: &mut Option<oneshot::Receiver<()>>) -> bool {
+    match shutdown {
+        Some(shutdown) => shutdown.await.is_ok(),
+        None => future::pending().await,
+    }
+}
+
+/// Finishes the pending receive of a tick, which lives on in the event loop if this is dropped first.
+async fn next(tick: &mut Option<TickFuture>) -> Result<Response<Duration>> {
+    match tick {
+        Some(tick) => tick.as_mut().await,
+        None => future::pending().await,
+    }
+}
+
+async fn recv<T>(source: &mut Option<mpsc::Receiver<T>>) -> Option<T> {
+    match source {
+        Some(source) => source.recv().await,
+        None => future::pending().await,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::VecDeque, sync::{Arc, Mutex}, time::Duration};
+
+    use tokio::time;
+
+    use crate::countdown::{AsyncCountdown, Countdown, Sequence};
+
+    use super::*;
+
+    /// Ticks from a script, each taken from it ten milliseconds before being returned, like a receive that has read a
+    /// value but not yet acknowledged it.
+    fn scripted(script: impl IntoIterator<Item = Response<Duration>>) -> EventLoop<char> {
+        let script = Arc::new(Mutex::new(script.into_iter().collect::<VecDeque<_>>()));
+
+        EventLoop::from_ticks(move || {
+            let script = Arc::clone(&script);
+            Box::pin(async move {
+                let response = script.lock().unwrap().pop_front().unwrap_or(Response::Closed(CloseReason::Completed));
+                time::sleep(Duration::from_millis(10)).await;
+                Ok(response)
+            })
+        })
+    }
+
+    async fn events_until_closed<K>(events: &mut EventLoop<K>) -> Vec<AppEvent<K>> {
+        let mut received = Vec::new();
+        loop {
+            let event = events.next_event().await.expect("unexpected error receiving an event");
+            let closed = matches!(event, AppEvent::Closed(_));
+            received.push(event);
+            if closed {
+                return received;
+            }
+        }
+    }
+
+    fn ticks<K>(events: &[AppEvent<K>]) -> Vec<Duration> {
+        events.iter().filter_map(|event| match event {
+            AppEvent::Tick(value) => Some(*value),
+            _ => None,
+        }).collect()
+    }
+
+    #[tokio::test]
+    async fn should_return_each_tick_then_the_close() {
+        time::pause();
+        let mut events = scripted([Response::Value(Duration::from_millis(200)), Response::Value(Duration::from_millis(100)), Response::Value(Duration::ZERO)]);
+
+        let received = events_until_closed(&mut events).await;
+
+        assert_eq!(received, [AppEvent::Tick(Duration::from_millis(200)), AppEvent::Tick(Duration::from_millis(100)), AppEvent::Tick(Duration::ZERO), AppEvent::Closed(CloseReason::Completed)]);
+    }
+
+    #[tokio::test]
+    async fn should_return_a_completed_cycle_as_a_phase_change() {
+        time::pause();
+        let mut events = scripted([Response::Value(Duration::ZERO), Response::CycleCompleted(1), Response::Value(Duration::from_millis(100))]);
+
+        let received = events_until_closed(&mut events).await;
+
+        assert_eq!(received[..3], [AppEvent::Tick(Duration::ZERO), AppEvent::PhaseChange(1), AppEvent::Tick(Duration::from_millis(100))]);
+    }
+
+    #[tokio::test]
+    async fn should_return_each_segment_of_a_sequence_with_its_label() {
+        time::pause();
+        let timer = AsyncCountdown::try_new(Duration::from_millis(100)).expect("should have created timer");
+        let segments = [("work", Duration::from_millis(200)), ("break", Duration::from_millis(100))];
+        let sequence = Sequence::try_new(timer, segments).expect("should have created the sequence");
+        let (rx, _handle) = sequence.start(Duration::from_millis(300)).await.expect("should have started the sequence");
+        let mut events = EventLoop::<char>::new(rx);
+
+        let received = events_until_closed(&mut events).await;
+
+        assert_eq!(received, [
+            AppEvent::Tick(Duration::from_millis(200)),
+            AppEvent::Tick(Duration::from_millis(100)),
+            AppEvent::Tick(Duration::ZERO),
+            AppEvent::SegmentStarted { index: 1, label: "break".to_owned() },
+            AppEvent::Tick(Duration::from_millis(100)),
+            AppEvent::Tick(Duration::ZERO),
+            AppEvent::Closed(CloseReason::Completed),
+        ]);
+    }
+
+    #[tokio::test]
+    async fn should_keep_a_tick_being_received_when_a_key_arrives_first() {
+        time::pause();
+        let (keys, pressed) = mpsc::channel(8);
+        let mut events = scripted([Response::Value(Duration::from_millis(100)), Response::Value(Duration::ZERO)]).keys(pressed);
+
+        // Starts receiving the first tick, then gives up on it before it is returned.
+        assert!(time::timeout(Duration::from_millis(5), events.next_event()).await.is_err());
+        keys.send('a').await.expect("unexpected closed key channel");
+
+        let received = events_until_closed(&mut events).await;
+        assert_eq!(received, [AppEvent::Key('a'), AppEvent::Tick(Duration::from_millis(100)), AppEvent::Tick(Duration::ZERO), AppEvent::Closed(CloseReason::Completed)]);
+    }
+
+    #[tokio::test]
+    async fn should_lose_no_tick_while_keys_and_resizes_interleave() {
+        time::pause();
+        let (keys, pressed) = mpsc::channel(8);
+        let (resizes, resized) = mpsc::channel(8);
+        let mut events = scripted((0..5).rev().map(|millis| Response::Value(Duration::from_millis(millis)))).keys(pressed).resizes(resized);
+        tokio::spawn(async move {
+            for key in ['a', 'b', 'c'] {
+                time::sleep(Duration::from_millis(6)).await;
+                let _ = keys.send(key).await;
+                let _ = resizes.send((80, 24)).await;
+            }
+        });
+
+        let received = events_until_closed(&mut events).await;
+
+        assert_eq!(ticks(&received), (0..5).rev().map(Duration::from_millis).collect::<Vec<_>>());
+        assert_eq!(received.iter().filter(|event| matches!(event, AppEvent::Key(_))).count(), 3);
+        assert_eq!(received.iter().filter(|event| matches!(event, AppEvent::Resize(80, 24))).count(), 3);
+    }
+
+    #[tokio::test]
+    async fn should_keep_ticking_while_keys_flood() {
+        time::pause();
+        let timer = AsyncCountdown::try_new(Duration::from_millis(100)).expect("should have created timer");
+        let (rx, _handle) = timer.start(Duration::from_millis(1000)).await.expect("should have started the countdown");
+        let (keys, pressed) = mpsc::channel(1);
+        let mut events = EventLoop::new(rx).keys(pressed);
+        tokio::spawn(async move {
+            while keys.send('x').await.is_ok() {
+                time::sleep(Duration::from_millis(1)).await;
+            }
+        });
+
+        let received = events_until_closed(&mut events).await;
+
+        assert_eq!(ticks(&received), (0..=10).rev().map(|tick| Duration::from_millis(tick * 100)).collect::<Vec<_>>());
+        assert_eq!(received.last(), Some(&AppEvent::Closed(CloseReason::Completed)));
+        let pressed = received.iter().filter(|event| matches!(event, AppEvent::Key('x'))).count();
+        assert!(pressed > 500, "expected keys to keep arriving between ticks, but got {pressed}");
+    }
+
+    #[tokio::test]
+    async fn should_return_a_lag_warning_once_right_after_the_tick_that_raised_it() {
+        time::pause();
+        let timer = AsyncCountdown::builder()
+            .period(Duration::from_millis(100))
+            .lag_warning(2, 50)
+            .build()
+            .expect("should have created timer");
+        let (rx, _handle) = timer.start(Duration::from_millis(1000)).await.expect("should have started the countdown");
+        let mut events = EventLoop::<char>::new(rx);
+
+        let mut received = Vec::new();
+        loop {
+            let event = events.next_event().await.expect("unexpected error receiving an event");
+            let closed = matches!(event, AppEvent::Closed(_));
+            let ticked = matches!(event, AppEvent::Tick(_));
+            received.push(event);
+            if closed {
+                break;
+            }
+            if ticked {
+                time::sleep(Duration::from_millis(330)).await;
+            }
+        }
+
+        assert_eq!(received[..4], [AppEvent::Tick(Duration::from_millis(1000)), AppEvent::Tick(Duration::from_millis(700)), AppEvent::Tick(Duration::from_millis(400)), AppEvent::Lagging(LagWarning { skipped: 4, window: 2 })]);
+        assert_eq!(received.iter().filter(|event| matches!(event, AppEvent::Lagging(_))).count(), 1);
+    }
+
+    #[tokio::test]
+    async fn should_shut_down_before_anything_else_is_returned() {
+        time::pause();
+        let (keys, pressed) = mpsc::channel(8);
+        let (shutdown, requested) = oneshot::channel();
+        let mut events = scripted([Response::Value(Duration::from_millis(100))]).keys(pressed).shutdown(requested);
+        keys.send('a').await.expect("unexpected closed key channel");
+        shutdown.send(()).expect("unexpected closed shutdown channel");
+
+        assert_eq!(events.next_event().await, Ok(AppEvent::Shutdown));
+        assert_eq!(events.next_event().await, Ok(AppEvent::Key('a')));
+        assert_eq!(events.next_event().await, Ok(AppEvent::Tick(Duration::from_millis(100))));
+    }
+
+    #[tokio::test]
+    async fn should_never_shut_down_once_the_request_is_dropped() {
+        time::pause();
+        let (shutdown, requested) = oneshot::channel::<()>();
+        let mut events = scripted([Response::Value(Duration::ZERO)]).shutdown(requested);
+        drop(shutdown);
+
+        let received = events_until_closed(&mut events).await;
+
+        assert_eq!(received, [AppEvent::Tick(Duration::ZERO), AppEvent::Closed(CloseReason::Completed)]);
+    }
+
+    #[tokio::test]
+    async fn should_return_keys_once_the_countdown_has_closed() {
+        time::pause();
+        let (keys, pressed) = mpsc::channel(8);
+        let mut events = scripted([]).keys(pressed);
+        events_until_closed(&mut events).await;
+
+        keys.send('q').await.expect("unexpected closed key channel");
+        drop(keys);
+
+        assert_eq!(events.next_event().await, Ok(AppEvent::Key('q')));
+        assert!(time::timeout(Duration::from_secs(1), events.next_event()).await.is_err(), "expected no more events");
+    }
+}
@@ -1,26 +1,407 @@
-use countdown::{Countdown, Receiver, Response};
+#![cfg_attr(docsrs, feature(doc_cfg))]
+
+use std::io;
+#[cfg(feature = "runtime")]
+use std::{any::Any, convert::Infallible, io::Write, time::Duration};
+
+#[cfg(feature = "runtime")]
+use countdown::{ChannelReceiver, CloseReason, Countdown, CountdownHandle, DisplaySeconds, FailureCode, LagWarning, TaskError};
+#[cfg(feature = "runtime")]
+use event_loop::{AppEvent, EventLoop};
 use thiserror::Error;
+#[cfg(feature = "runtime")]
+use tokio::{sync::oneshot, task::JoinHandle};
+#[cfg(feature = "runtime")]
+use view::FrameWriter;
+
+mod core;
 
 pub mod view;
 pub mod countdown;
+#[cfg(feature = "runtime")]
+#[cfg_attr(docsrs, doc(cfg(feature = "runtime")))]
+pub mod event_loop;
 
+/// An error raised while running a countdown.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+///
+/// use libtomatillo::{countdown::ChannelError, TomatilloError};
+///
+/// let error = TomatilloError::from(ChannelError::Timeout(Duration::from_secs(1)));
+///
+/// assert_eq!(error.to_string(), "timed out after 1s waiting for update");
+/// ```
 #[derive(Debug, Error, PartialEq)]
 pub enum TomatilloError {
     #[error(transparent)]
     CountdownError(#[from] crate::countdown::CountdownError),
     #[error(transparent)]
+    StartError(#[from] crate::countdown::StartError),
+    #[error(transparent)]
     ChannelError(#[from] crate::countdown::ChannelError),
+    #[error("failed to write output: {0}")]
+    OutputError(io::ErrorKind),
+    /// A bug made the task running the countdown panic, with the panic's message.
+    #[error("the countdown panicked: {0}")]
+    Panicked(String),
+    /// The task running the countdown was cancelled before it ended, for instance by its runtime shutting down.
+    #[error("the countdown was cancelled before it ended")]
+    Cancelled,
+}
+
+impl From<io::Error> for TomatilloError {
+    fn from(error: io::Error) -> Self {
+        Self::OutputError(error.kind())
+    }
+}
+
+impl countdown::UserFacing for TomatilloError {
+    fn user_message(&self) -> String {
+        match self {
+            Self::CountdownError(err) => err.user_message(),
+            Self::StartError(err) => err.user_message(),
+            Self::ChannelError(err) => err.user_message(),
+            Self::OutputError(kind) => format!("could not write the countdown out: {kind}"),
+            Self::Panicked(message) => format!("the countdown panicked: {message}; this is a bug in tomatillo"),
+            Self::Cancelled => "the countdown was cancelled before it ended, as tomatillo was shutting down".to_owned(),
+        }
+    }
+}
+
+/// Which build of tomatillo is running, for bug reports.
+///
+/// # Examples
+///
+/// ```
+/// let info = libtomatillo::build_info();
+///
+/// assert_eq!(info.version, env!("CARGO_PKG_VERSION"));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct BuildInfo {
+    /// The version of the library crate.
+    pub version: &'static str,
+    /// The cargo features the library was built with.
+    pub features: &'static [&'static str],
+    /// The target triple the library was built for.
+    pub target: &'static str,
+    /// The short hash of the commit the library was built from, if it was built from a git checkout.
+    pub git_hash: Option<&'static str>,
+}
+
+/// Returns the [`BuildInfo`] of this build of the library.
+#[must_use]
+pub fn build_info() -> BuildInfo {
+    BuildInfo {
+        version: env!("CARGO_PKG_VERSION"),
+        features: &[
+            #[cfg(feature = "runtime")]
+            "runtime",
+            #[cfg(feature = "view")]
+            "view",
+            #[cfg(feature = "serde")]
+            "serde",
+            #[cfg(feature = "blocking")]
+            "blocking",
+            #[cfg(feature = "demo_mode")]
+            "demo_mode",
+        ],
+        target: env!("TOMATILLO_TARGET"),
+        git_hash: option_env!("TOMATILLO_GIT_HASH"),
+    }
+}
+
+/// How long writing a frame may block before [`run_with_output`] reports the output as stalled.
+#[cfg(feature = "runtime")]
+const OUTPUT_STALL_WARNING: Duration = Duration::from_secs(3);
+
+/// How a countdown started by [`run`] or [`run_with_output`] ended.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+///
+/// use libtomatillo::{countdown::{AsyncCountdown, CloseReason}, run_with_output, CountdownOutcome};
+///
+/// # #[tokio::main(flavor = "current_thread", start_paused = true)]
+/// # async fn main() {
+/// let timer = AsyncCountdown::try_new(Duration::from_millis(100)).expect("should have created the timer");
+/// let guard = run_with_output(timer, Duration::from_millis(300), std::io::sink()).await.expect("should have started the countdown");
+///
+/// let outcome = guard.wait().await.expect("should have run the countdown");
+/// assert_eq!(outcome, CountdownOutcome {
+///     reason: CloseReason::Completed,
+///     remaining: Duration::ZERO,
+///     lag_warning: None,
+///     output_stalled: false,
+/// });
+/// # }
+/// ```
+#[cfg(feature = "runtime")]
+#[cfg_attr(docsrs, doc(cfg(feature = "runtime")))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct CountdownOutcome {
+    /// Why the countdown ended: [`CloseReason::Cancelled`] if it was stopped with [`RunGuard::stop`].
+    pub reason: CloseReason,
+    /// The time that was left on the countdown when it ended, or the time elapsed for one that counts up, see
+    /// [`countdown::AsyncCountdownBuilder::direction`].
+    pub remaining: Duration,
+    /// The warning raised if the updates were received so late that too many of them were skipped, for instance on a
+    /// host too busy to keep up, see [`countdown::AsyncCountdownBuilder::lag_warning`].
+    pub lag_warning: Option<LagWarning>,
+    /// Whether writing the updates blocked for more than a few seconds at some point, so that the updates produced
+    /// meanwhile were dropped, see [`run_with_output`].
+    pub output_stalled: bool,
+}
+
+/// A handle to a countdown started by [`run`] or [`run_with_output`], which runs in the background until it completes
+/// or is stopped.
+///
+/// # Dropping
+///
+/// Dropping a [`RunGuard`] detaches the countdown: it carries on in the background, writing its updates, until it
+/// completes. Call [`RunGuard::stop`] to cancel it instead.
+#[cfg(feature = "runtime")]
+#[cfg_attr(docsrs, doc(cfg(feature = "runtime")))]
+#[derive(Debug)]
+pub struct RunGuard {
+    stop: Option<oneshot::Sender<()>>,
+    task: JoinHandle<Result<CountdownOutcome, TomatilloError>>,
+}
+
+#[cfg(feature = "runtime")]
+impl RunGuard {
+    /// Cancels the countdown, then waits for its last update to be written.
+    ///
+    /// # Returns
+    ///
+    /// A [`Result`] that is:
+    ///
+    /// * `Ok(outcome)` - The countdown has ended, with [`CloseReason::Cancelled`] unless it had already ended on its own.
+    /// * `Err(err)` - The countdown failed, or its output could not be written to.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use libtomatillo::{countdown::{AsyncCountdown, CloseReason}, run_with_output};
+    ///
+    /// # #[tokio::main(flavor = "current_thread", start_paused = true)]
+    /// # async fn main() {
+    /// let timer = AsyncCountdown::try_new(Duration::from_millis(100)).expect("should have created the timer");
+    /// let guard = run_with_output(timer, Duration::from_secs(60), std::io::sink()).await.expect("should have started the countdown");
+    ///
+    /// let outcome = guard.stop().await.expect("should have stopped the countdown");
+    /// assert_eq!(outcome.reason, CloseReason::Cancelled);
+    /// # }
+    /// ```
+    pub async fn stop(mut self) -> Result<CountdownOutcome, TomatilloError> {
+        if let Some(stop) = self.stop.take() {
+            // The countdown may have ended already, in which case there is nothing to stop.
+            let _ = stop.send(());
+        }
+
+        self.wait().await
+    }
+
+    /// Waits for the countdown to end on its own, and for its last update to be written.
+    ///
+    /// # Returns
+    ///
+    /// A [`Result`] that is:
+    ///
+    /// * `Ok(outcome)` - The countdown has ended.
+    /// * `Err(err)` - The countdown failed, or its output could not be written to. A panic while counting down, or
+    ///   while writing the output, is returned as [`TomatilloError::Panicked`], and the task being cancelled before it
+    ///   ended, for instance by the runtime shutting down, as [`TomatilloError::Cancelled`].
+    pub async fn wait(self) -> Result<CountdownOutcome, TomatilloError> {
+        let Self { stop, task } = self;
+        let outcome = task.await.unwrap_or_else(|err| match err.try_into_panic() {
+            Ok(panic) => Err(TomatilloError::Panicked(panic_message(panic))),
+            Err(_) => Err(TomatilloError::Cancelled),
+        });
+        drop(stop);
+
+        outcome
+    }
+}
+
+#[cfg(feature = "runtime")]
+pub(crate) fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    match payload.downcast::<String>() {
+        Ok(message) => *message,
+        Err(payload) => payload.downcast_ref::<&str>().map_or_else(|| "unknown panic".to_string(), |&message| message.to_string()),
+    }
 }
 
+/// Starts the countdown and prints each update to stdout until it completes or is stopped.
+///
+/// # Arguments
+///
+/// * `timer` - The countdown to run. It is dropped once the countdown ends.
+/// * `duration` - The duration of the countdown, to the millisecond.
+///
+/// # Returns
+///
+/// A [`Result`] that is:
+///
+/// * `Ok(guard)` - The countdown has started: use the [`RunGuard`] to wait for it or stop it.
+/// * `Err(err)` - The countdown could not be started.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+///
+/// use libtomatillo::{countdown::AsyncCountdown, run};
+///
+/// # #[tokio::main(flavor = "current_thread", start_paused = true)]
+/// # async fn main() {
+/// let timer = AsyncCountdown::try_new(Duration::from_millis(100)).expect("should have created the timer");
+///
+/// run(timer, Duration::from_millis(300)).await.expect("should have started the countdown").wait().await.expect("should have run the countdown");
+/// # }
+/// ```
+#[cfg(feature = "runtime")]
+#[cfg_attr(docsrs, doc(cfg(feature = "runtime")))]
 pub async fn run(
-    timer: impl Countdown<u64>,
-    duration_millis: u64,
-) {
-    let countdown = timer.start(duration_millis).await.unwrap();
+    timer: impl Countdown<Duration> + Send + 'static,
+    duration: Duration,
+) -> Result<RunGuard, TomatilloError> {
+    run_with_output(timer, duration, io::stdout()).await
+}
+
+/// Starts the countdown and writes each update to `output` until it completes or is stopped.
+///
+/// Each update is written on its own line as the [`DisplaySeconds`] left, e.g. `01:30`. A [`countdown::Sequence`]
+/// also writes the label of each segment after the first on a line of its own, along with the first update of it.
+///
+/// Updates are written from a [`FrameWriter`], so output that blocks never delays receiving the countdown: the
+/// updates produced meanwhile are dropped, and [`CountdownOutcome::output_stalled`] says so if the output stays blocked
+/// for more than a few seconds. The last update is always written once the output unblocks.
+///
+/// # Arguments
+///
+/// * `timer` - The countdown to run. It is dropped once the countdown ends.
+/// * `duration` - The duration of the countdown, to the millisecond.
+/// * `output` - Where to write the updates.
+///
+/// # Returns
+///
+/// A [`Result`] that is:
+///
+/// * `Ok(guard)` - The countdown has started: use the [`RunGuard`] to wait for it or stop it.
+/// * `Err(err)` - The countdown could not be started.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+///
+/// use libtomatillo::{countdown::AsyncCountdown, run_with_output};
+///
+/// # #[tokio::main(flavor = "current_thread", start_paused = true)]
+/// # async fn main() {
+/// let timer = AsyncCountdown::try_new(Duration::from_millis(100)).expect("should have created the timer");
+/// let guard = run_with_output(timer, Duration::from_millis(300), std::io::sink()).await.expect("should have started the countdown");
+///
+/// guard.wait().await.expect("should have run the countdown");
+/// # }
+/// ```
+#[cfg(feature = "runtime")]
+#[cfg_attr(docsrs, doc(cfg(feature = "runtime")))]
+pub async fn run_with_output(
+    timer: impl Countdown<Duration> + Send + 'static,
+    duration: Duration,
+    output: impl Write + Send + 'static,
+) -> Result<RunGuard, TomatilloError> {
+    run_stalling_after(timer, duration, output, OUTPUT_STALL_WARNING).await
+}
+
+/// Runs the countdown like [`run_with_output`], reporting the output as stalled once a write blocks for over
+/// `stall_after`.
+#[cfg(feature = "runtime")]
+async fn run_stalling_after(
+    timer: impl Countdown<Duration> + Send + 'static,
+    duration: Duration,
+    output: impl Write + Send + 'static,
+    stall_after: Duration,
+) -> Result<RunGuard, TomatilloError> {
+    let (countdown, producer) = timer.start(duration).await?;
+    let (stop, stopped) = oneshot::channel();
+    let frames = FrameWriter::spawn(output);
+    let task = tokio::spawn(write_countdown(timer, countdown, producer, duration, frames, stall_after, stopped));
+
+    Ok(RunGuard { stop: Some(stop), task })
+}
 
-    while let Ok(Response::Value(millis_left)) = countdown.recv().await {
-        println!("{:02}:{:02}", millis_left / 60, millis_left % 60);
+#[cfg(feature = "runtime")]
+async fn write_countdown(
+    timer: impl Countdown<Duration>,
+    countdown: ChannelReceiver<Duration>,
+    producer: CountdownHandle,
+    duration: Duration,
+    frames: FrameWriter,
+    stall_after: Duration,
+    stop: oneshot::Receiver<()>,
+) -> Result<CountdownOutcome, TomatilloError> {
+    // A dropped guard detaches the countdown rather than stopping it, as the event loop never shuts down then.
+    let mut events = EventLoop::<Infallible>::new(countdown).shutdown(stop);
+    let mut remaining = duration;
+    let mut output_stalled = false;
+    let mut lag_warning = None;
+    let mut segment = None;
+
+    let reason = loop {
+        let left = match events.next_event().await? {
+            AppEvent::Tick(left) => left,
+            AppEvent::PhaseChange(_) | AppEvent::Resize(..) => continue,
+            AppEvent::SegmentStarted { label, .. } => {
+                segment = Some(label);
+                continue;
+            },
+            AppEvent::Lagging(warning) => {
+                lag_warning = Some(warning);
+                continue;
+            },
+            AppEvent::Closed(reason) => break reason,
+            AppEvent::Shutdown => break CloseReason::Cancelled,
+            AppEvent::Key(never) => match never {},
+        };
+
+        remaining = left;
+        // The label goes out in the same frame as the first update of its segment, so that neither is dropped alone.
+        match segment.take() {
+            Some(label) => frames.write(format!("{label}\n{}", frame(left))),
+            None => frames.write(frame(left)),
+        }
+        output_stalled |= frames.stalled_for().is_some_and(|stalled| stalled > stall_after);
+    };
+
+    // The channel only says that the countdown panicked: the task running it has the message.
+    if reason == CloseReason::Failed(FailureCode::Panicked) {
+        if let Err(TaskError::Panicked(message)) = producer.wait().await {
+            return Err(TomatilloError::Panicked(message));
+        }
     }
+
+    drop(timer);
+    frames.finish().await?;
+
+    Ok(CountdownOutcome { reason, remaining, lag_warning, output_stalled })
+}
+
+#[cfg(feature = "runtime")]
+fn frame(left: Duration) -> String {
+    format!("{}\n", DisplaySeconds::from(left))
 }
 
 #[cfg(test)]
@@ -28,7 +409,7 @@ mod tests {
     // #[tokio::test(flavor = "multi_thread", worker_threads = 2)] TODO: Restore after we finish view
     // async fn should_display_countdown_as_it_changes() {
 
-    //     let timer = AsyncCountdown::try_new(1000).expect("should have created timer");
+    //     let timer = AsyncCountdown::try_new(Duration::from_millis(1000)).expect("should have created timer");
 
     //     let expectations: &str = "00:03\r00:02\r00:01\r00:00\r";
 
@@ -40,4 +421,230 @@ mod tests {
     //         expectations
     //     );
     // }
-}
\ No newline at end of file
+
+    use std::time::Duration;
+
+    #[cfg(feature = "runtime")]
+    use tokio::time;
+
+    use rstest::rstest;
+
+    use crate::{build_info, countdown::{ChannelError, UserFacing}};
+
+    #[cfg(feature = "runtime")]
+    use crate::{
+        countdown::{
+            AsyncCountdown, ChannelReceiver, CloseReason, Countdown, CountdownHandle, Direction, Response, Sequence, StartError,
+        },
+        frame,
+        run_stalling_after,
+        run_with_output,
+        view::writer::tests::{wait_until, GatedWriter},
+        CountdownOutcome,
+    };
+    use crate::TomatilloError;
+
+    #[test]
+    fn should_describe_the_build() {
+        let info = build_info();
+
+        assert!(!info.version.is_empty());
+        assert!(!info.target.is_empty());
+        assert_eq!(info.features.contains(&"runtime"), cfg!(feature = "runtime"));
+        assert_eq!(info.features.contains(&"view"), cfg!(feature = "view"));
+        assert_eq!(info.features.contains(&"demo_mode"), cfg!(feature = "demo_mode"));
+        assert!(info.git_hash.is_none_or(|hash| !hash.is_empty()));
+    }
+
+    #[rstest]
+    #[case::channel_error(
+        ChannelError::Timeout(Duration::from_secs(1)).into(),
+        "no value arrived from the countdown within 1s; it may be stuck, or the host too busy to run it",
+    )]
+    #[case::output_error(
+        TomatilloError::OutputError(std::io::ErrorKind::BrokenPipe),
+        "could not write the countdown out: broken pipe",
+    )]
+    #[case::panicked(
+        TomatilloError::Panicked("index out of bounds".to_owned()),
+        "the countdown panicked: index out of bounds; this is a bug in tomatillo",
+    )]
+    #[case::cancelled(
+        TomatilloError::Cancelled,
+        "the countdown was cancelled before it ended, as tomatillo was shutting down",
+    )]
+    fn should_describe_an_error_running_a_countdown_to_a_person(#[case] error: TomatilloError, #[case] expected: &str) {
+        assert_eq!(error.user_message(), expected);
+    }
+
+    #[cfg(feature = "runtime")]
+    #[rstest]
+    #[case::completed(0, "00:00\n")]
+    #[case::last_millisecond(1, "00:01\n")]
+    #[case::just_over_a_second(1001, "00:02\n")]
+    #[case::just_under_a_minute(59_001, "01:00\n")]
+    #[case::just_under_an_hour(3_599_001, "1:00:00\n")]
+    fn should_write_the_same_seconds_as_every_other_consumer(#[case] millis_left: u64, #[case] expected: &str) {
+        let left = Duration::from_millis(millis_left);
+        let shown = Response::Value(left).display_seconds().expect("a value should have seconds to show");
+
+        assert_eq!(frame(left), expected);
+        assert_eq!(format!("{shown}\n"), expected);
+    }
+
+    #[cfg(feature = "runtime")]
+    #[tokio::test]
+    async fn should_keep_receiving_the_countdown_while_the_output_is_blocked() {
+        time::pause();
+        let timer = AsyncCountdown::try_new(Duration::from_millis(100)).expect("should have created timer");
+        let (output, open, written) = GatedWriter::new();
+
+        let guard = run_with_output(timer, Duration::from_millis(1000), output).await.expect("should have started the countdown");
+        time::sleep(Duration::from_secs(5)).await;
+        drop(open);
+
+        guard.wait().await.expect("the countdown should have completed without a timeout");
+        let written = written.lock().unwrap();
+        assert_eq!(written.last().map(String::as_str), Some("00:00\n"));
+        assert!(written.len() < 11, "expected intermediate updates to be dropped, but got {written:?}");
+    }
+
+    #[cfg(feature = "runtime")]
+    #[rstest]
+    #[case::never_blocked(Duration::ZERO, false)]
+    #[case::blocked_for_longer_than_allowed(Duration::from_millis(500), true)]
+    #[tokio::test]
+    async fn should_report_whether_the_output_stalled(#[case] blocked_for: Duration, #[case] expected: bool) {
+        // The writer thread measures how long a write blocks in real time, so this runs in real time too.
+        let timer = AsyncCountdown::try_new(Duration::from_millis(100)).expect("should have created timer");
+        let (output, open, written) = GatedWriter::new();
+
+        let guard = run_stalling_after(timer, Duration::from_millis(1000), output, Duration::from_millis(250)).await.expect("should have started the countdown");
+        time::sleep(blocked_for).await;
+        drop(open);
+
+        let outcome = guard.wait().await.expect("the countdown should have completed without a timeout");
+        assert_eq!(outcome.output_stalled, expected);
+        assert_eq!(written.lock().unwrap().last().map(String::as_str), Some("00:00\n"));
+    }
+
+    #[cfg(feature = "runtime")]
+    #[tokio::test]
+    async fn should_write_nothing_more_once_stopped() {
+        time::pause();
+        let timer = AsyncCountdown::try_new(Duration::from_millis(100)).expect("should have created timer");
+        let (output, open, written) = GatedWriter::new();
+        drop(open);
+
+        let guard = run_with_output(timer, Duration::from_millis(1000), output).await.expect("should have started the countdown");
+        time::sleep(Duration::from_millis(250)).await;
+
+        let outcome = guard.stop().await.expect("should have stopped the countdown");
+        assert_eq!(outcome, CountdownOutcome { reason: CloseReason::Cancelled, remaining: Duration::from_millis(800), lag_warning: None, output_stalled: false });
+
+        let stopped_with = written.lock().unwrap().clone();
+        assert_eq!(stopped_with.last().map(String::as_str), Some("00:01\n"));
+
+        time::sleep(Duration::from_secs(2)).await;
+        assert_eq!(*written.lock().unwrap(), stopped_with);
+    }
+
+    #[cfg(feature = "runtime")]
+    #[tokio::test]
+    async fn should_write_a_count_up_increasing() {
+        time::pause();
+        let timer = AsyncCountdown::builder()
+            .period(Duration::from_millis(1000))
+            .direction(Direction::Up)
+            .build()
+            .expect("should have created timer");
+        let (output, open, written) = GatedWriter::new();
+        drop(open);
+
+        let guard = run_with_output(timer, Duration::from_millis(3000), output).await.expect("should have started the count-up");
+        let outcome = guard.wait().await.expect("should have run the count-up");
+
+        assert_eq!(outcome, CountdownOutcome { reason: CloseReason::Completed, remaining: Duration::from_millis(3000), lag_warning: None, output_stalled: false });
+        // Frames the output was too slow for are dropped, but those written count up.
+        let written = written.lock().unwrap();
+        assert!(written.is_sorted(), "expected the frames to increase, but got {written:?}");
+        assert_eq!(written.last().map(String::as_str), Some("00:03\n"));
+    }
+
+    #[cfg(feature = "runtime")]
+    #[tokio::test]
+    async fn should_write_the_label_of_each_segment_of_a_sequence_with_its_first_update() {
+        // In real time, so that the output keeps up with every update rather than dropping some.
+        let timer = AsyncCountdown::try_new(Duration::from_millis(100)).expect("should have created timer");
+        let segments = [("work", Duration::from_millis(200)), ("break", Duration::from_millis(100))];
+        let sequence = Sequence::try_new(timer, segments).expect("should have created the sequence");
+        let (output, open, written) = GatedWriter::new();
+        drop(open);
+
+        let guard = run_with_output(sequence, Duration::from_millis(300), output).await.expect("should have started the sequence");
+        guard.wait().await.expect("should have run the sequence");
+
+        // The zero of a segment is replaced by the first update of the next when it is not written first.
+        let written = written.lock().unwrap();
+        assert_eq!(written.iter().filter(|frame| frame.contains("break")).collect::<Vec<_>>(), ["break\n00:01\n"]);
+        assert_eq!(written.last().map(String::as_str), Some("00:00\n"));
+        assert!(!written.iter().any(|frame| frame.contains("work")), "expected no label for the first segment, but got {written:?}");
+    }
+
+    #[cfg(feature = "runtime")]
+    #[tokio::test]
+    async fn should_run_to_completion_once_the_guard_is_dropped() {
+        time::pause();
+        let timer = AsyncCountdown::try_new(Duration::from_millis(100)).expect("should have created timer");
+        let (output, open, written) = GatedWriter::new();
+        drop(open);
+
+        drop(run_with_output(timer, Duration::from_millis(1000), output).await.expect("should have started the countdown"));
+        time::sleep(Duration::from_secs(2)).await;
+
+        wait_until(|| written.lock().unwrap().last().is_some_and(|frame| frame == "00:00\n"));
+    }
+
+    /// A countdown that panics when it is dropped, to stand in for a bug in the task running it.
+    #[cfg(feature = "runtime")]
+    struct PanicsOnDrop(AsyncCountdown);
+
+    #[cfg(feature = "runtime")]
+    impl Countdown<Duration> for PanicsOnDrop {
+        async fn start(&self, duration: Duration) -> std::result::Result<(ChannelReceiver<Duration>, CountdownHandle), StartError> {
+            self.0.start(duration).await
+        }
+    }
+
+    #[cfg(feature = "runtime")]
+    impl Drop for PanicsOnDrop {
+        fn drop(&mut self) {
+            panic!("injected panic");
+        }
+    }
+
+    #[cfg(feature = "runtime")]
+    #[tokio::test]
+    async fn should_return_the_panic_message_when_the_countdown_panics() {
+        time::pause();
+        let timer = PanicsOnDrop(AsyncCountdown::try_new(Duration::from_millis(100)).expect("should have created timer"));
+
+        let guard = run_with_output(timer, Duration::from_millis(300), std::io::sink()).await.expect("should have started the countdown");
+
+        assert_eq!(guard.wait().await, Err(TomatilloError::Panicked("injected panic".to_string())));
+    }
+
+    #[cfg(feature = "runtime")]
+    #[test]
+    fn should_return_cancelled_when_the_runtime_shuts_down_before_the_countdown_ends() {
+        let runtime = tokio::runtime::Builder::new_current_thread().enable_time().build().expect("should have built a runtime");
+        let guard = runtime.block_on(async {
+            let timer = AsyncCountdown::try_new(Duration::from_millis(100)).expect("should have created timer");
+            run_with_output(timer, Duration::from_secs(60), std::io::sink()).await.expect("should have started the countdown")
+        });
+        runtime.shutdown_background();
+
+        let waiter = tokio::runtime::Builder::new_current_thread().build().expect("should have built a runtime");
+        assert_eq!(waiter.block_on(guard.wait()), Err(TomatilloError::Cancelled));
+    }
+}
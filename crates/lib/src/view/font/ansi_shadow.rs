@@ -1,17 +1,28 @@
+//! The ANSI Shadow font, generated from `templates/ansi_shadow.txt` by `cargo xtask fonts`.
+//!
+//! Edit the template rather than this module, then run `cargo xtask fonts` to regenerate every font, or
+//! `cargo xtask fonts --check` to check that none is out of date. A template starts with a header of `key: value`
+//! lines, where `font` names the struct, `title` the font, `safe` lists `glyph=replacement` pairs for
+//! `Font::safe_glyphs`, `monospace` the characters that must be as wide as each other, and `test` the glyph the
+//! tests in `font/mod.rs` draw. Each glyph follows on the lines under a `== <character>` marker, drawn exactly as
+//! written, trailing spaces included. To add a font, add its template next to the others, regenerate, then give
+//! it a `mod`, a `pub use` and a constant in `font/mod.rs`.
+
 use std::ops::Range;
 
-use super::{Font, CompositeChar};
+use super::{CompositeChar, Font};
 
 const HEIGHT: usize = 6;
 
 const ZERO: CompositeChar<HEIGHT> = CompositeChar('0', [
-    " ██████╗ ",  
+    " ██████╗ ",
     "██╔═████╗",
     "██║██╔██║",
     "████╔╝██║",
     "╚██████╔╝",
     " ╚═════╝ ",
 ]);
+
 const ONE: CompositeChar<HEIGHT> = CompositeChar('1', [
     " ██╗",
     "███║",
@@ -92,7 +103,7 @@ const NINE: CompositeChar<HEIGHT> = CompositeChar('9', [
     " █████╔╝",
     " ╚════╝ ",
 ]);
- 
+
 const COLON: CompositeChar<HEIGHT> = CompositeChar(':', [
     "    ",
     " ██╗",
@@ -102,6 +113,7 @@ const COLON: CompositeChar<HEIGHT> = CompositeChar(':', [
     "    ",
 ]);
 
+/// The type of the [`ANSI_SHADOW`](super::ANSI_SHADOW) font.
 #[derive(Default)]
 pub struct AnsiShadow;
 
@@ -109,10 +121,10 @@ impl Font for AnsiShadow {
     type CHAR = CompositeChar<'static, HEIGHT>;
 
     fn height_range(&self) -> Range<usize> {
-        (0..HEIGHT).into()
+        0..HEIGHT
     }
 
-    fn get(&self, index: char) -> Option<CompositeChar<'static, HEIGHT>> {
+    fn get(&self, index: char) -> Option<Self::CHAR> {
         match index {
             '0' => Some(ZERO),
             '1' => Some(ONE),
@@ -131,14 +143,14 @@ impl Font for AnsiShadow {
 }
 
 #[cfg(test)]
-pub const TEST_FOUR: CompositeChar<HEIGHT> = FOUR;
+pub const TEST_FOUR: CompositeChar<'_, HEIGHT> = FOUR;
 
 #[cfg(test)]
 mod tests {
-    use super::*;
-
     use rstest::rstest;
 
+    use super::*;
+
     #[rstest]
     #[case::zero('0', ZERO)]
     #[case::one('1', ONE)]
@@ -151,21 +163,19 @@ mod tests {
     #[case::eight('8', EIGHT)]
     #[case::nine('9', NINE)]
     #[case::colon(':', COLON)]
-    fn test_should_return_correct_character<'a>(#[case] key: char, #[case] expected: CompositeChar<'a, HEIGHT>) {
+    fn test_should_return_correct_character(#[case] key: char, #[case] expected: CompositeChar<'_, HEIGHT>) {
         let font = AnsiShadow;
 
-        if let Some(actual) = font.get(key) {
-            assert_eq!(actual, expected, "expected {key} to map to {expected:?}, but got {actual:?}");
-        } else {
-            assert!(false, "value not found for {key}");
-        }
+        let actual = font.get(key).unwrap_or_else(|| panic!("value not found for {key}"));
+
+        assert_eq!(actual, expected, "expected {key} to map to {expected:?}, but got {actual:?}");
     }
-    
+
     #[test]
     fn test_should_return_height_range() {
         let font = AnsiShadow;
 
-        assert_eq!(font.height_range(), (0..HEIGHT).into());
+        assert_eq!(font.height_range(), 0..HEIGHT);
     }
 
     #[test]
@@ -174,4 +184,4 @@ mod tests {
 
         assert_eq!(font.get('a'), None);
     }
-}
\ No newline at end of file
+}
@@ -1,6 +1,16 @@
+//! The Electronic font, generated from `templates/electronic.txt` by `cargo xtask fonts`.
+//!
+//! Edit the template rather than this module, then run `cargo xtask fonts` to regenerate every font, or
+//! `cargo xtask fonts --check` to check that none is out of date. A template starts with a header of `key: value`
+//! lines, where `font` names the struct, `title` the font, `safe` lists `glyph=replacement` pairs for
+//! `Font::safe_glyphs`, `monospace` the characters that must be as wide as each other, and `test` the glyph the
+//! tests in `font/mod.rs` draw. Each glyph follows on the lines under a `== <character>` marker, drawn exactly as
+//! written, trailing spaces included. To add a font, add its template next to the others, regenerate, then give
+//! it a `mod`, a `pub use` and a constant in `font/mod.rs`.
+
 use std::ops::Range;
 
-use super::{Font, CompositeChar};
+use super::{CompositeChar, Font};
 
 const HEIGHT: usize = 11;
 
@@ -103,17 +113,17 @@ const SIX: CompositeChar<HEIGHT> = CompositeChar('6', [
 ]);
 
 const SEVEN: CompositeChar<HEIGHT> = CompositeChar('7', [
-    " ▄▄▄▄▄▄▄▄▄▄ ",
-    "▐░░░░░░░░░░▌",
-    " ▀▀▀▀▀▀▀█░░▌",
-    "       ▐░░▌ ",
-    "      ▐░░▌  ",
-    "     ▐░░▌   ",
-    "    ▐░░▌    ",
-    "   ▐░░▌     ",
-    "  ▐░░▌      ",
-    " ▐░░▌       ",
-    "  ▀▀        ",
+    " ▄▄▄▄▄▄▄▄▄▄  ",
+    "▐░░░░░░░░░░▌ ",
+    " ▀▀▀▀▀▀▀█░░▌ ",
+    "       ▐░░▌  ",
+    "      ▐░░▌   ",
+    "     ▐░░▌    ",
+    "    ▐░░▌     ",
+    "   ▐░░▌      ",
+    "  ▐░░▌       ",
+    " ▐░░▌        ",
+    "  ▀▀         ",
 ]);
 
 const EIGHT: CompositeChar<HEIGHT> = CompositeChar('8', [
@@ -158,18 +168,20 @@ const COLON: CompositeChar<HEIGHT> = CompositeChar(':', [
     "    ",
 ]);
 
+/// The type of the [`ELECTRONIC`](super::ELECTRONIC) font.
+#[derive(Default)]
 pub struct Electronic;
 
 impl Font for Electronic {
     type CHAR = CompositeChar<'static, HEIGHT>;
 
     fn height_range(&self) -> Range<usize> {
-        (0..HEIGHT).into()
+        0..HEIGHT
     }
 
     fn get(&self, index: char) -> Option<Self::CHAR> {
         match index {
-            '0' => Some(ZERO), 
+            '0' => Some(ZERO),
             '1' => Some(ONE),
             '2' => Some(TWO),
             '3' => Some(THREE),
@@ -183,18 +195,21 @@ impl Font for Electronic {
             _ => None,
         }
     }
-}
 
+    fn safe_glyphs(&self) -> &'static [(char, char)] {
+        &[('░', ':')]
+    }
+}
 
 #[cfg(test)]
 pub const TEST_NINE: CompositeChar<'_, HEIGHT> = NINE;
 
 #[cfg(test)]
 mod tests {
-    use super::*;
-
     use rstest::rstest;
 
+    use super::*;
+
     #[rstest]
     #[case::zero('0', ZERO)]
     #[case::one('1', ONE)]
@@ -207,21 +222,19 @@ mod tests {
     #[case::eight('8', EIGHT)]
     #[case::nine('9', NINE)]
     #[case::colon(':', COLON)]
-    fn test_should_return_correct_character<'a>(#[case] key: char, #[case] expected: CompositeChar<'a, HEIGHT>) {
+    fn test_should_return_correct_character(#[case] key: char, #[case] expected: CompositeChar<'_, HEIGHT>) {
         let font = Electronic;
 
-        if let Some(actual) = font.get(key) {
-            assert_eq!(actual, expected, "expected {key} to map to {expected:?}, but got {actual:?}");
-        } else {
-            assert!(false, "value not found for {key}");
-        }
+        let actual = font.get(key).unwrap_or_else(|| panic!("value not found for {key}"));
+
+        assert_eq!(actual, expected, "expected {key} to map to {expected:?}, but got {actual:?}");
     }
 
     #[test]
     fn test_should_return_height_range() {
         let font = Electronic;
 
-        assert_eq!(font.height_range(), (0..HEIGHT).into());
+        assert_eq!(font.height_range(), 0..HEIGHT);
     }
 
     #[test]
@@ -230,4 +243,4 @@ mod tests {
 
         assert_eq!(font.get('a'), None);
     }
-}
\ No newline at end of file
+}
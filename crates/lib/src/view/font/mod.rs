@@ -1,35 +1,199 @@
 use std::{fmt::{Debug, Write}, ops::Range};
 
-use ansi_shadow::AnsiShadow;
-use electronic::Electronic;
-use templar::Templar;
+use super::ViewError;
+
+pub use ansi_shadow::AnsiShadow;
+pub use electronic::Electronic;
+pub use specimen::specimen;
+pub use templar::Templar;
 
 mod ansi_shadow;
 mod electronic;
+mod specimen;
 mod templar;
 
+/// A font that draws every character as itself, on a single line.
+///
+/// # Examples
+///
+/// ```
+/// use libtomatillo::view::font::{Character, Font, NONE};
+///
+/// let mut out = String::new();
+/// NONE.get('7').expect("every character is supported").draw_line(&mut out, 0);
+///
+/// assert_eq!(out, "7");
+/// ```
 pub const NONE: NoopFont = NoopFont;
+
+/// The ANSI Shadow font, six lines high.
+///
+/// # Examples
+///
+/// ```
+/// use libtomatillo::view::font::{Font, ANSI_SHADOW};
+///
+/// assert_eq!(ANSI_SHADOW.height_range().len(), 6);
+/// assert!(ANSI_SHADOW.get(':').is_some());
+/// ```
 pub const ANSI_SHADOW: AnsiShadow = AnsiShadow;
+
+/// The Electronic font, eleven lines high.
+///
+/// # Examples
+///
+/// ```
+/// use libtomatillo::view::font::{Font, ELECTRONIC};
+///
+/// assert_eq!(ELECTRONIC.height_range().len(), 11);
+/// assert!(ELECTRONIC.get('a').is_none());
+/// ```
 pub const ELECTRONIC: Electronic = Electronic;
+
+/// The Templar font, three lines high.
+///
+/// # Examples
+///
+/// ```
+/// use libtomatillo::view::font::{Character, Font, TEMPLAR};
+///
+/// let zero = TEMPLAR.get('0').expect("digits are supported");
+/// let mut out = String::new();
+/// for line in TEMPLAR.height_range() {
+///     zero.draw_line(&mut out, line);
+/// }
+///
+/// assert_eq!(out, "┏┓\n┃┫\n┗┛");
+/// ```
 pub const TEMPLAR: Templar = Templar;
 
+/// A set of [`Character`]s that the time can be drawn with.
+///
+/// # Examples
+///
+/// ```
+/// use libtomatillo::view::font::{Font, TEMPLAR};
+///
+/// fn supports_time(font: &impl Font) -> bool {
+///     "0123456789:".chars().all(|c| font.get(c).is_some())
+/// }
+///
+/// assert!(supports_time(&TEMPLAR));
+/// ```
 pub trait Font {
     type CHAR: Character;
 
+    /// Returns the range of lines every character of the font is drawn over.
     fn height_range(&self) -> Range<usize>;
 
+    /// Returns the character drawn for `index`, or `None` if the font does not support it.
     fn get(&self, index: char) -> Option<Self::CHAR>;
+
+    /// Returns the glyphs of the font that common terminal fonts may lack, each paired with a safer glyph of the same
+    /// display width to draw instead.
+    fn safe_glyphs(&self) -> &'static [(char, char)] {
+        &[]
+    }
+
+    /// Returns how many columns `character` takes up when drawn, or `None` if the font does not support it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libtomatillo::view::font::{Font, TEMPLAR};
+    ///
+    /// assert_eq!(TEMPLAR.char_width(':'), Some(1));
+    /// assert_eq!(TEMPLAR.char_width('a'), None);
+    /// ```
+    fn char_width(&self, character: char) -> Option<usize> {
+        self.get(character).map(|drawn| drawn.width())
+    }
+
+    /// Returns how many columns the widest digit takes up when drawn, or zero if the font draws no digits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libtomatillo::view::font::{Font, ANSI_SHADOW};
+    ///
+    /// assert_eq!(ANSI_SHADOW.max_digit_width(), 9);
+    /// ```
+    fn max_digit_width(&self) -> usize {
+        ('0'..='9').filter_map(|digit| self.char_width(digit)).max().unwrap_or(0)
+    }
+}
+
+/// Returns how many columns `s` takes up when drawn with `font`, each character `spacing` columns from the next, without
+/// drawing it.
+///
+/// A [`View`](super::View) separates characters by a single column.
+///
+/// # Returns
+///
+/// A [`Result`] that is:
+///
+/// * `Ok(width)` - The width of the widest line `s` would be drawn over.
+/// * `Err(err)` - The font cannot draw one of the characters of `s`.
+///
+/// # Examples
+///
+/// ```
+/// use libtomatillo::view::font::{frame_width, TEMPLAR};
+///
+/// assert_eq!(frame_width(&TEMPLAR, "12:34", 1), Ok(13));
+/// ```
+pub fn frame_width<C: Character>(font: &dyn Font<CHAR = C>, s: &str, spacing: usize) -> Result<usize, ViewError> {
+    s.chars().enumerate().try_fold(0, |width, (position, character)| {
+        let drawn = font.char_width(character).ok_or(ViewError::UnsupportedCharacter { character, position })?;
+        Ok(width + drawn + if position > 0 { spacing } else { 0 })
+    })
 }
 
+/// A single character of a [`Font`], drawn one line at a time.
+///
+/// # Examples
+///
+/// ```
+/// use libtomatillo::view::font::{Character, Font, ANSI_SHADOW};
+///
+/// let colon = ANSI_SHADOW.get(':').expect("the colon is supported");
+/// let mut out = String::new();
+/// colon.draw_line(&mut out, 1);
+///
+/// assert_eq!(colon.height(), 6);
+/// assert_eq!(out, " ██╗\n");
+/// ```
 pub trait Character: Debug + Eq + PartialEq {
+    /// Returns how many columns the widest line of the character takes up.
     fn width(&self) -> usize;
     fn height(&self) -> usize;
     
+    /// Writes the given line of the character, followed by a newline unless it is the last line.
     fn draw_line(&self, writer: &mut impl Write, line: usize);
 }
 
+/// The type of the [`NONE`] font.
+///
+/// # Examples
+///
+/// ```
+/// use libtomatillo::view::font::{Font, NoopFont};
+///
+/// assert_eq!(NoopFont.get('x'), Some('x'));
+/// ```
 pub struct NoopFont;
 
+/// A [`Character`] drawn over several lines of text.
+///
+/// # Examples
+///
+/// ```
+/// use libtomatillo::view::font::{Character, Font, ELECTRONIC};
+///
+/// let nine = ELECTRONIC.get('9').expect("digits are supported");
+///
+/// assert_eq!(nine.height(), 11);
+/// ```
 #[derive(Debug, Eq, PartialEq, Clone)]
 pub struct CompositeChar<'a, const HEIGHT: usize>(char, [&'a str; HEIGHT]);
 
@@ -47,7 +211,7 @@ impl Font for NoopFont {
 
 impl<'a, const HEIGHT: usize> Character for CompositeChar<'a, HEIGHT> {
     fn width(&self) -> usize {
-        todo!()
+        self.1.iter().map(|line| line.chars().count()).max().unwrap_or(0)
     }
 
     fn height(&self) -> usize {
@@ -64,7 +228,7 @@ impl<'a, const HEIGHT: usize> Character for CompositeChar<'a, HEIGHT> {
 
 impl Character for char {
     fn width(&self) -> usize {
-        todo!()
+        1
     }
 
     fn height(&self) -> usize { 1 }
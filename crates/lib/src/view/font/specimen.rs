@@ -0,0 +1,218 @@
+use std::fmt::Write;
+
+use crate::view::{font::{Character, Font}, Frame, View};
+
+/// The characters a specimen shows, if the font supports them: those a countdown is drawn with.
+const CHARACTERS: &str = "0123456789:";
+/// The times a specimen draws, covering a countdown that has ended, one of minutes and one of hours.
+const SAMPLES: [&str; 3] = ["00:00", "19:28", "1:23:45"];
+
+/// Draws a specimen of `font`, to review it by eye: each character the font draws a time with, under its width, then
+/// a few times laid out as a [`View`] would draw them.
+///
+/// A character whose lines are not all the same width is flagged, since it would throw out the alignment of the
+/// characters drawn after it.
+///
+/// # Examples
+///
+/// ```
+/// use libtomatillo::view::font::{self, specimen};
+///
+/// let sheet = specimen(&font::TEMPLAR);
+///
+/// assert!(sheet.as_str().starts_with("'0' width 2\n┏┓\n┃┫\n┗┛\n"));
+/// ```
+pub fn specimen<C: Character>(font: &dyn Font<CHAR = C>) -> Frame {
+    let view = View::new(font);
+    let mut sheet = String::new();
+
+    for character in CHARACTERS.chars() {
+        let Some(drawn) = font.get(character) else {
+            continue;
+        };
+        let Ok(frame) = view.render_str(&character.to_string()) else {
+            continue;
+        };
+
+        let widths = frame.as_str().lines().map(|line| line.chars().count()).collect::<Vec<_>>();
+        let _ = write!(sheet, "{character:?} width {}", drawn.width());
+        if widths.windows(2).any(|pair| pair[0] != pair[1]) {
+            let widths = widths.iter().map(ToString::to_string).collect::<Vec<_>>().join(", ");
+            let _ = write!(sheet, " (inconsistent: lines are {widths} wide)");
+        }
+        let _ = writeln!(sheet);
+        sheet.push_str(frame.as_str());
+        sheet.push('\n');
+    }
+
+    for (index, sample) in SAMPLES.iter().enumerate() {
+        if index > 0 {
+            sheet.push('\n');
+        }
+
+        let _ = writeln!(sheet, "{sample:?}");
+        match view.render_str(sample) {
+            Ok(frame) => sheet.push_str(frame.as_str()),
+            Err(err) => {
+                let _ = writeln!(sheet, "{err}");
+            },
+        }
+    }
+
+    Frame(sheet)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ops::Range;
+
+    use indoc::indoc;
+
+    use crate::view::font::{self, CompositeChar};
+
+    use super::*;
+
+    /// A font with a single character, whose middle line is narrower than the others.
+    struct Ragged;
+
+    impl Font for Ragged {
+        type CHAR = CompositeChar<'static, 3>;
+
+        fn height_range(&self) -> Range<usize> {
+            0..3
+        }
+
+        fn get(&self, index: char) -> Option<Self::CHAR> {
+            (index == '0').then_some(CompositeChar('0', ["┏┓", "┃", "┗┛"]))
+        }
+    }
+
+    #[test]
+    fn should_draw_a_specimen_of_the_noop_font() {
+        assert_eq!(specimen(&font::NONE).as_str(), indoc!("
+            '0' width 1
+            0
+
+            '1' width 1
+            1
+
+            '2' width 1
+            2
+
+            '3' width 1
+            3
+
+            '4' width 1
+            4
+
+            '5' width 1
+            5
+
+            '6' width 1
+            6
+
+            '7' width 1
+            7
+
+            '8' width 1
+            8
+
+            '9' width 1
+            9
+
+            ':' width 1
+            :
+
+            \"00:00\"
+            0 0 : 0 0
+
+            \"19:28\"
+            1 9 : 2 8
+
+            \"1:23:45\"
+            1 : 2 3 : 4 5
+        "));
+    }
+
+    #[test]
+    fn should_draw_a_specimen_of_templar() {
+        assert_eq!(specimen(&font::TEMPLAR).as_str(), indoc!("
+            '0' width 2
+            ┏┓
+            ┃┫
+            ┗┛
+
+            '1' width 2
+            ┓\x20
+            ┃\x20
+            ┻\x20
+
+            '2' width 2
+            ┏┓
+            ┏┛
+            ┗━
+
+            '3' width 2
+            ┏┓
+             ┫
+            ┗┛
+
+            '4' width 2
+            ┏┓
+            ┃┃
+            ┗╋
+
+            '5' width 2
+            ┏━
+            ┗┓
+            ┗┛
+
+            '6' width 2
+            ┏┓
+            ┣┓
+            ┗┛
+
+            '7' width 2
+            ━┓
+             ┃
+             ╹
+
+            '8' width 2
+            ┏┓
+            ┣┫
+            ┗┛
+
+            '9' width 2
+            ┏┓
+            ┗┫
+            ┗┛
+
+            ':' width 1
+            \x20
+            •
+            •
+
+            \"00:00\"
+            ┏┓ ┏┓   ┏┓ ┏┓
+            ┃┫ ┃┫ • ┃┫ ┃┫
+            ┗┛ ┗┛ • ┗┛ ┗┛
+
+            \"19:28\"
+            ┓  ┏┓   ┏┓ ┏┓
+            ┃  ┗┫ • ┏┛ ┣┫
+            ┻  ┗┛ • ┗━ ┗┛
+
+            \"1:23:45\"
+            ┓    ┏┓ ┏┓   ┏┓ ┏━
+            ┃  • ┏┛  ┫ • ┃┃ ┗┓
+            ┻  • ┗━ ┗┛ • ┗╋ ┗┛
+        "));
+    }
+
+    #[test]
+    fn should_flag_a_character_whose_lines_differ_in_width() {
+        let sheet = specimen(&Ragged);
+
+        assert!(sheet.as_str().starts_with("'0' width 2 (inconsistent: lines are 2, 1, 2 wide)\n"), "{sheet}");
+    }
+}
@@ -1,7 +1,17 @@
-use crate::view::font::CompositeChar;
-use crate::view::font::Font;
+//! The Templar font, generated from `templates/templar.txt` by `cargo xtask fonts`.
+//!
+//! Edit the template rather than this module, then run `cargo xtask fonts` to regenerate every font, or
+//! `cargo xtask fonts --check` to check that none is out of date. A template starts with a header of `key: value`
+//! lines, where `font` names the struct, `title` the font, `safe` lists `glyph=replacement` pairs for
+//! `Font::safe_glyphs`, `monospace` the characters that must be as wide as each other, and `test` the glyph the
+//! tests in `font/mod.rs` draw. Each glyph follows on the lines under a `== <character>` marker, drawn exactly as
+//! written, trailing spaces included. To add a font, add its template next to the others, regenerate, then give
+//! it a `mod`, a `pub use` and a constant in `font/mod.rs`.
+
 use std::ops::Range;
 
+use super::{CompositeChar, Font};
+
 const HEIGHT: usize = 3;
 
 const ZERO: CompositeChar<HEIGHT> = CompositeChar('0', [
@@ -9,6 +19,7 @@ const ZERO: CompositeChar<HEIGHT> = CompositeChar('0', [
     "┃┫",
     "┗┛",
 ]);
+
 const ONE: CompositeChar<HEIGHT> = CompositeChar('1', [
     "┓ ",
     "┃ ",
@@ -69,16 +80,15 @@ const COLON: CompositeChar<HEIGHT> = CompositeChar(':', [
     "•",
 ]);
 
+/// The type of the [`TEMPLAR`](super::TEMPLAR) font.
+#[derive(Default)]
 pub struct Templar;
 
-#[cfg(test)]
-pub const TEST_COLON: CompositeChar<HEIGHT> = COLON;
-
 impl Font for Templar {
     type CHAR = CompositeChar<'static, HEIGHT>;
 
     fn height_range(&self) -> Range<usize> {
-        (0..HEIGHT).into()
+        0..HEIGHT
     }
 
     fn get(&self, index: char) -> Option<Self::CHAR> {
@@ -97,13 +107,21 @@ impl Font for Templar {
             _ => None,
         }
     }
+
+    fn safe_glyphs(&self) -> &'static [(char, char)] {
+        &[('╋', '+'), ('╹', '┃')]
+    }
 }
+
 #[cfg(test)]
-mod tests {
-    use super::*;
+pub const TEST_COLON: CompositeChar<'_, HEIGHT> = COLON;
 
+#[cfg(test)]
+mod tests {
     use rstest::rstest;
 
+    use super::*;
+
     #[rstest]
     #[case::zero('0', ZERO)]
     #[case::one('1', ONE)]
@@ -116,21 +134,19 @@ mod tests {
     #[case::eight('8', EIGHT)]
     #[case::nine('9', NINE)]
     #[case::colon(':', COLON)]
-    fn test_should_return_correct_character<'a>(#[case] key: char, #[case] expected: CompositeChar<'a, HEIGHT>) {
+    fn test_should_return_correct_character(#[case] key: char, #[case] expected: CompositeChar<'_, HEIGHT>) {
         let font = Templar;
 
-        if let Some(actual) = font.get(key) {
-            assert_eq!(actual, expected, "expected {key} to map to {expected:?}, but got {actual:?}");
-        } else {
-            assert!(false, "value not found for {key}");
-        }
+        let actual = font.get(key).unwrap_or_else(|| panic!("value not found for {key}"));
+
+        assert_eq!(actual, expected, "expected {key} to map to {expected:?}, but got {actual:?}");
     }
 
     #[test]
     fn test_should_return_height_range() {
         let font = Templar;
 
-        assert_eq!(font.height_range(), (0..HEIGHT).into());
+        assert_eq!(font.height_range(), 0..HEIGHT);
     }
 
     #[test]
@@ -139,4 +155,4 @@ mod tests {
 
         assert_eq!(font.get('a'), None);
     }
-}
\ No newline at end of file
+}
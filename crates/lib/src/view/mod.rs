@@ -1,24 +1,200 @@
-use crate::view::font::{Character, Font};
+#[cfg(feature = "view")]
+use std::fmt::{self, Display};
 
-mod font;
+#[cfg(feature = "view")]
+use thiserror::Error;
 
-pub struct View<'a, C: Character> {   
-    _font: &'a dyn Font<CHAR = C>,
+#[cfg(feature = "view")]
+use crate::{countdown::DisplaySeconds, view::font::{Character, Font}};
+
+#[cfg(feature = "view")]
+#[cfg_attr(docsrs, doc(cfg(feature = "view")))]
+pub mod font;
+
+#[cfg(feature = "runtime")]
+pub(crate) mod writer;
+
+#[cfg(feature = "runtime")]
+#[cfg_attr(docsrs, doc(cfg(feature = "runtime")))]
+pub use writer::FrameWriter;
+
+/// How many columns a [`View`] leaves between one character and the next.
+#[cfg(feature = "view")]
+const SPACING: usize = 1;
+
+/// Renders the remaining time of a countdown, or any other string the font supports, with a [`Font`].
+///
+/// # Examples
+///
+/// ```
+/// use libtomatillo::view::{font, View};
+///
+/// let view = View::new(&font::TEMPLAR);
+/// let frame = view.render_str("42").expect("the font supports digits");
+///
+/// assert_eq!(frame.to_string(), "┏┓ ┏┓\n┃┃ ┏┛\n┗╋ ┗━\n");
+/// ```
+#[cfg(feature = "view")]
+#[cfg_attr(docsrs, doc(cfg(feature = "view")))]
+pub struct View<'a, C: Character> {
+    font: &'a dyn Font<CHAR = C>,
+    safe_glyphs: bool,
+}
+
+/// The text drawn by a [`View`], one line per line of its [`Font`], each ending with a newline.
+#[cfg(feature = "view")]
+#[cfg_attr(docsrs, doc(cfg(feature = "view")))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Frame(String);
+
+/// An error raised when a [`View`] cannot render a string.
+///
+/// # Examples
+///
+/// ```
+/// use libtomatillo::view::{font, View, ViewError};
+///
+/// let error = View::new(&font::TEMPLAR).render_str("1a").expect_err("the font has no letters");
+///
+/// assert_eq!(error, ViewError::UnsupportedCharacter { character: 'a', position: 1 });
+/// ```
+#[cfg(feature = "view")]
+#[cfg_attr(docsrs, doc(cfg(feature = "view")))]
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum ViewError {
+    #[error("the font cannot draw {character:?} at position {position}")]
+    UnsupportedCharacter { character: char, position: usize },
 }
 
+#[cfg(feature = "view")]
 impl<'a, C: Character> View<'a, C> {
-    pub fn render(&self, _time: u32) -> String {
-        todo!()
-    }   
+    /// Creates a new [`View`] that renders with the given [`Font`].
+    pub fn new(font: &'a dyn Font<CHAR = C>) -> Self {
+        Self { font, safe_glyphs: false }
+    }
+
+    /// Draws the font's [`Font::safe_glyphs`] in place of the glyphs common terminal fonts may lack. Each substitute
+    /// has the same display width, so the frame keeps its dimensions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libtomatillo::view::{font, View};
+    ///
+    /// let frame = View::new(&font::TEMPLAR).with_safe_glyphs().render_str("4").expect("the font supports digits");
+    ///
+    /// assert_eq!(frame.to_string(), "┏┓\n┃┃\n┗+\n");
+    /// ```
+    #[must_use]
+    pub fn with_safe_glyphs(mut self) -> Self {
+        self.safe_glyphs = true;
+        self
+    }
+
+    /// Returns how many columns [`View::render_str`] would take up to draw `s`, without drawing it.
+    ///
+    /// # Returns
+    ///
+    /// A [`Result`] that is:
+    ///
+    /// * `Ok(width)` - The width of the widest line of the frame `s` would be drawn as.
+    /// * `Err(err)` - The font cannot draw one of the characters of `s`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libtomatillo::view::{font, View};
+    ///
+    /// let view = View::new(&font::TEMPLAR);
+    ///
+    /// assert_eq!(view.width("42"), Ok(5));
+    /// ```
+    pub fn width(&self, s: &str) -> Result<usize, ViewError> {
+        font::frame_width(self.font, s, SPACING)
+    }
+
+    /// Renders `s` one character after another, separated by a space.
+    ///
+    /// # Returns
+    ///
+    /// A [`Result`] that is:
+    ///
+    /// * `Ok(frame)` - Every character of `s` has been drawn.
+    /// * `Err(err)` - The font cannot draw one of the characters of `s`.
+    pub fn render_str(&self, s: &str) -> Result<Frame, ViewError> {
+        let characters = s.chars()
+            .enumerate()
+            .map(|(position, character)| {
+                self.font.get(character).ok_or(ViewError::UnsupportedCharacter { character, position })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let substitutes = if self.safe_glyphs { self.font.safe_glyphs() } else { &[] };
+
+        let mut frame = String::new();
+        let mut drawn = String::new();
+        for line in self.font.height_range() {
+            for (index, character) in characters.iter().enumerate() {
+                if index > 0 {
+                    frame.extend(std::iter::repeat_n(' ', SPACING));
+                }
+
+                drawn.clear();
+                character.draw_line(&mut drawn, line);
+                frame.extend(drawn.trim_end_matches('\n').chars().map(|glyph| substitute(substitutes, glyph)));
+            }
+            frame.push('\n');
+        }
+
+        Ok(Frame(frame))
+    }
+
+    /// Renders the time left on a countdown, formatted as its [`DisplaySeconds`].
+    ///
+    /// # Returns
+    ///
+    /// A [`Result`] that is:
+    ///
+    /// * `Ok(frame)` - The time has been drawn.
+    /// * `Err(err)` - The font cannot draw one of the characters of the time.
+    pub fn render_time(&self, remaining: DisplaySeconds) -> Result<Frame, ViewError> {
+        self.render_str(&remaining.to_string())
+    }
+}
+
+#[cfg(feature = "view")]
+fn substitute(substitutes: &[(char, char)], glyph: char) -> char {
+    substitutes.iter()
+        .find_map(|&(risky, safe)| (risky == glyph).then_some(safe))
+        .unwrap_or(glyph)
+}
+
+#[cfg(feature = "view")]
+impl Frame {
+    /// Returns the text of the frame.
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+#[cfg(feature = "view")]
+impl Display for Frame {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "view"))]
 mod tests {
     use super::font::{self, Character, Font};
+    use super::{View, ViewError};
 
     use rstest::rstest;
     use indoc::indoc;
 
+    use crate::countdown::DisplaySeconds;
+
     #[rstest]
     #[case::font_ansi_shadow(font::ANSI_SHADOW, ANSI_SHADOW_ZERO)]
     #[case::font_electronic(font::ELECTRONIC, ELECTRONIC_ZERO)]
@@ -31,6 +207,107 @@ mod tests {
         // assert_eq!(actual, expected); Skip this test for now
     }
 
+    #[test]
+    fn should_render_a_number_that_is_not_a_time() {
+        let frame = View::new(&font::TEMPLAR).render_str("42").expect("should have rendered the digits");
+
+        assert_eq!(frame.as_str(), indoc!("
+            ┏┓ ┏┓
+            ┃┃ ┏┛
+            ┗╋ ┗━
+        "));
+    }
+
+    #[test]
+    fn should_render_a_time_of_hours_minutes_and_seconds() {
+        let frame = View::new(&font::TEMPLAR).render_str("12:34:56").expect("should have rendered the time");
+
+        assert_eq!(frame.as_str(), indoc!("
+            ┓  ┏┓   ┏┓ ┏┓   ┏━ ┏┓
+            ┃  ┏┛ •  ┫ ┃┃ • ┗┓ ┣┓
+            ┻  ┗━ • ┗┛ ┗╋ • ┗┛ ┗┛
+        "));
+    }
+
+    #[test]
+    fn should_render_the_time_left_like_any_string() {
+        let view = View::new(&font::TEMPLAR);
+
+        assert_eq!(view.render_time(DisplaySeconds::from_millis(0)), view.render_str("00:00"));
+        assert_eq!(view.render_time(DisplaySeconds::from_millis(0)).expect("should have rendered the time").as_str(), TEMPLAR_ZERO);
+    }
+
+    #[test]
+    fn should_substitute_safe_glyphs_without_changing_the_dimensions() {
+        let fancy = View::new(&font::TEMPLAR).render_str("4").expect("should have rendered the digit");
+        let safe = View::new(&font::TEMPLAR).with_safe_glyphs().render_str("4").expect("should have rendered the digit");
+
+        let dimensions = |frame: &str| frame.lines().map(|line| line.chars().count()).collect::<Vec<_>>();
+        assert_eq!(dimensions(safe.as_str()), dimensions(fancy.as_str()));
+        assert_eq!(fancy.as_str(), "┏┓\n┃┃\n┗╋\n");
+        assert_eq!(safe.as_str(), "┏┓\n┃┃\n┗+\n");
+    }
+
+    #[rstest]
+    #[case::none(font::NONE)]
+    #[case::ansi_shadow(font::ANSI_SHADOW)]
+    fn should_draw_fonts_without_risky_glyphs_unchanged<C: Character>(#[case] font: impl Font<CHAR = C>) {
+        assert_eq!(View::new(&font).with_safe_glyphs().render_str("00:00"), View::new(&font).render_str("00:00"));
+    }
+
+    #[rstest]
+    #[case::none(&font::NONE)]
+    #[case::ansi_shadow(&font::ANSI_SHADOW)]
+    #[case::electronic(&font::ELECTRONIC)]
+    #[case::templar(&font::TEMPLAR)]
+    fn should_measure_the_width_of_a_frame_as_it_is_drawn<C: Character>(#[case] font: &dyn Font<CHAR = C>) {
+        let view = View::new(font);
+
+        for s in ["0", "00:00", "19:28", "1:23:45", "24:00:00"] {
+            let frame = view.render_str(s).expect("should have rendered the time");
+            let drawn = frame.as_str().lines().map(|line| line.chars().count()).max();
+
+            assert_eq!(view.width(s).ok(), drawn, "{s:?} measured differently from how it was drawn:\n{frame}");
+        }
+    }
+
+    #[rstest]
+    #[case::none(&font::NONE, 1)]
+    #[case::ansi_shadow(&font::ANSI_SHADOW, 9)]
+    #[case::electronic(&font::ELECTRONIC, 13)]
+    #[case::templar(&font::TEMPLAR, 2)]
+    fn should_measure_the_widest_digit_of_each_font<C: Character>(#[case] font: &dyn Font<CHAR = C>, #[case] expected: usize) {
+        let widest = ('0'..='9').map(|digit| View::new(font).render_str(&digit.to_string()).expect("should have rendered the digit"))
+            .flat_map(|frame| frame.as_str().lines().map(|line| line.chars().count()).collect::<Vec<_>>())
+            .max();
+
+        assert_eq!(font.max_digit_width(), expected);
+        assert_eq!(widest, Some(expected));
+    }
+
+    #[rstest]
+    #[case::no_spacing(0, 8)]
+    #[case::spacing(3, 17)]
+    fn should_measure_a_frame_with_any_spacing(#[case] spacing: usize, #[case] expected: usize) {
+        assert_eq!(font::frame_width(&font::TEMPLAR, "1234", spacing), Ok(expected));
+    }
+
+    #[test]
+    fn should_fail_to_measure_a_character_the_font_cannot_draw() {
+        assert_eq!(font::TEMPLAR.char_width('a'), None);
+        assert_eq!(View::new(&font::TEMPLAR).width("12:3a"), Err(ViewError::UnsupportedCharacter { character: 'a', position: 4 }));
+    }
+
+    #[rstest]
+    #[case::first("x1", 'x', 0)]
+    #[case::after_a_separator("12:3a", 'a', 4)]
+    #[case::multibyte("0é", 'é', 1)]
+    fn should_fail_on_the_first_character_the_font_cannot_draw(#[case] s: &str, #[case] character: char, #[case] position: usize) {
+        let error = View::new(&font::TEMPLAR).render_str(s).expect_err("should not have rendered the string");
+
+        assert_eq!(error, ViewError::UnsupportedCharacter { character, position });
+    }
+
     const ANSI_SHADOW_ZERO: &str = indoc!("
          ██████╗   ██████╗       ██████╗   ██████╗ 
         ██╔═████╗ ██╔═████╗ ██╗ ██╔═████╗ ██╔═████╗
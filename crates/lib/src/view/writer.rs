@@ -0,0 +1,269 @@
+use std::{
+    io::{self, Write},
+    sync::{Arc, Condvar, Mutex, MutexGuard, PoisonError},
+    thread,
+    time::{Duration, Instant},
+};
+
+use tokio::sync::oneshot;
+
+/// Writes frames from a dedicated thread, so that output which blocks (a stopped pager, a slow SSH session, Ctrl-S)
+/// never holds up whoever produces the frames.
+///
+/// Only the newest frame waits to be written: a frame that is still waiting when the next one arrives is dropped.
+///
+/// # Examples
+///
+/// ```
+/// use std::sync::{Arc, Mutex};
+///
+/// use libtomatillo::view::FrameWriter;
+///
+/// #[derive(Clone, Default)]
+/// struct Shared(Arc<Mutex<Vec<u8>>>);
+///
+/// impl std::io::Write for Shared {
+///     fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+///         self.0.lock().unwrap().write(buf)
+///     }
+///
+///     fn flush(&mut self) -> std::io::Result<()> {
+///         Ok(())
+///     }
+/// }
+///
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// let out = Shared::default();
+/// let frames = FrameWriter::spawn(out.clone());
+/// frames.write("00:01\n".to_string());
+/// frames.finish().await.expect("should have written the frames");
+///
+/// assert!(String::from_utf8(out.0.lock().unwrap().clone()).unwrap().ends_with("00:01\n"));
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct FrameWriter {
+    shared: Arc<Shared>,
+    done: Option<oneshot::Receiver<io::Result<()>>>,
+}
+
+#[derive(Debug, Default)]
+struct Shared {
+    slot: Mutex<Slot>,
+    frame_ready: Condvar,
+    writing_since: Mutex<Option<Instant>>,
+}
+
+#[derive(Debug, Default)]
+struct Slot {
+    frame: Option<String>,
+    finished: bool,
+    dropped: u64,
+}
+
+impl FrameWriter {
+    /// Starts writing frames to `writer` from a dedicated thread.
+    pub fn spawn(writer: impl Write + Send + 'static) -> Self {
+        let shared = Arc::new(Shared::default());
+        let (done_tx, done_rx) = oneshot::channel();
+
+        let thread_shared = Arc::clone(&shared);
+        thread::spawn(move || {
+            let _ = done_tx.send(write_frames(&thread_shared, writer));
+        });
+
+        Self { shared, done: Some(done_rx) }
+    }
+
+    /// Queues a frame to be written, replacing, and dropping, any frame still waiting to be written.
+    pub fn write(&self, frame: String) {
+        let mut slot = self.shared.slot();
+        if slot.frame.replace(frame).is_some() {
+            slot.dropped += 1;
+        }
+        self.shared.frame_ready.notify_one();
+    }
+
+    /// Returns the number of frames dropped because a newer frame replaced them before they were written.
+    pub fn frames_dropped(&self) -> u64 {
+        self.shared.slot().dropped
+    }
+
+    /// Returns how long the frame currently being written has been blocked, or `None` if no write is in progress.
+    pub fn stalled_for(&self) -> Option<Duration> {
+        self.shared.writing_since().map(|since| since.elapsed())
+    }
+
+    /// Waits for the last queued frame to be written, then stops the writer thread.
+    ///
+    /// # Returns
+    ///
+    /// A [`io::Result`] that is:
+    ///
+    /// * `Ok(())` - Every frame that was not dropped has been written and flushed.
+    /// * `Err(err)` - Writing a frame failed, and the frames after it were not written.
+    pub async fn finish(mut self) -> io::Result<()> {
+        self.shared.finish();
+
+        match self.done.take() {
+            Some(done) => done.await.unwrap_or_else(|_| Err(io::Error::other("frame writer thread panicked"))),
+            None => Ok(()),
+        }
+    }
+}
+
+impl Drop for FrameWriter {
+    fn drop(&mut self) {
+        self.shared.finish();
+    }
+}
+
+impl Shared {
+    fn slot(&self) -> MutexGuard<'_, Slot> {
+        self.slot.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn writing_since(&self) -> MutexGuard<'_, Option<Instant>> {
+        self.writing_since.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn finish(&self) {
+        self.slot().finished = true;
+        self.frame_ready.notify_one();
+    }
+
+    fn next_frame(&self) -> Option<String> {
+        let mut slot = self.frame_ready
+            .wait_while(self.slot(), |slot| slot.frame.is_none() && !slot.finished)
+            .unwrap_or_else(PoisonError::into_inner);
+
+        slot.frame.take()
+    }
+}
+
+fn write_frames(shared: &Shared, mut writer: impl Write) -> io::Result<()> {
+    while let Some(frame) = shared.next_frame() {
+        *shared.writing_since() = Some(Instant::now());
+        let written = writer.write_all(frame.as_bytes()).and_then(|()| writer.flush());
+        *shared.writing_since() = None;
+
+        written?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use std::sync::mpsc;
+
+    use super::*;
+
+    /// A writer that blocks on every write until the test lets it through, recording what it wrote.
+    pub(crate) struct GatedWriter {
+        gate: mpsc::Receiver<()>,
+        pub(crate) written: Arc<Mutex<Vec<String>>>,
+    }
+
+    impl GatedWriter {
+        pub(crate) fn new() -> (Self, mpsc::Sender<()>, Arc<Mutex<Vec<String>>>) {
+            let (open, gate) = mpsc::channel();
+            let written = Arc::new(Mutex::new(Vec::new()));
+
+            (Self { gate, written: Arc::clone(&written) }, open, written)
+        }
+    }
+
+    impl Write for GatedWriter {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            // A closed gate lets every write through.
+            let _ = self.gate.recv();
+            self.written.lock().unwrap().push(String::from_utf8_lossy(buf).into_owned());
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    struct FailingWriter;
+
+    impl Write for FailingWriter {
+        fn write(&mut self, _: &[u8]) -> io::Result<usize> {
+            Err(io::Error::from(io::ErrorKind::BrokenPipe))
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    pub(crate) fn wait_until(condition: impl Fn() -> bool) {
+        let deadline = Instant::now() + Duration::from_secs(5);
+        while !condition() {
+            assert!(Instant::now() < deadline, "condition not met within 5s");
+            thread::sleep(Duration::from_millis(1));
+        }
+    }
+
+    #[tokio::test]
+    async fn should_drop_intermediate_frames_while_a_write_is_blocked() {
+        let (writer, open, written) = GatedWriter::new();
+        let frames = FrameWriter::spawn(writer);
+
+        frames.write("first".to_string());
+        wait_until(|| frames.stalled_for().is_some());
+
+        for frame in ["second", "third", "last"] {
+            frames.write(frame.to_string());
+        }
+        drop(open);
+
+        frames.finish().await.expect("unexpected error writing frames");
+        assert_eq!(*written.lock().unwrap(), ["first", "last"]);
+    }
+
+    #[tokio::test]
+    async fn should_count_dropped_frames() {
+        let (writer, open, _) = GatedWriter::new();
+        let frames = FrameWriter::spawn(writer);
+
+        frames.write("first".to_string());
+        wait_until(|| frames.stalled_for().is_some());
+        for frame in ["second", "third", "last"] {
+            frames.write(frame.to_string());
+        }
+
+        assert_eq!(frames.frames_dropped(), 2);
+        drop(open);
+        frames.finish().await.expect("unexpected error writing frames");
+    }
+
+    #[tokio::test]
+    async fn should_report_how_long_a_write_has_been_blocked() {
+        let (writer, open, _) = GatedWriter::new();
+        let frames = FrameWriter::spawn(writer);
+        assert_eq!(frames.stalled_for(), None);
+
+        frames.write("first".to_string());
+        wait_until(|| frames.stalled_for().is_some());
+        thread::sleep(Duration::from_millis(20));
+
+        assert!(frames.stalled_for().expect("should be stalled") >= Duration::from_millis(20));
+
+        open.send(()).unwrap();
+        wait_until(|| frames.stalled_for().is_none());
+        frames.finish().await.expect("unexpected error writing frames");
+    }
+
+    #[tokio::test]
+    async fn should_return_the_write_error_when_finishing() {
+        let frames = FrameWriter::spawn(FailingWriter);
+        frames.write("frame".to_string());
+
+        let error = frames.finish().await.expect_err("should have failed to write");
+        assert_eq!(error.kind(), io::ErrorKind::BrokenPipe);
+    }
+}
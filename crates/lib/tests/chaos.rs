@@ -0,0 +1,255 @@
+//! Runs hundreds of short countdowns against a consumer that misbehaves at random: it is slow to receive, gives up on
+//! receives half way through, re-creates its receiver, cancels, adds time, and sometimes stops reading altogether.
+//! Whatever it does, every countdown must hold to the protocol.
+//!
+//! The consumer is seeded, and every failure names the seed of its run. Set `TOMATILLO_CHAOS_SEED` to replay the runs
+//! from that seed on:
+//!
+//! ```sh
+//! TOMATILLO_CHAOS_SEED=1234 cargo test -p libtomatillo --test chaos
+//! ```
+
+#![cfg(feature = "runtime")]
+
+use std::{env, time::Duration};
+
+use libtomatillo::countdown::{
+    AsyncCountdown, ChannelReceiver, CloseReason, Countdown, FailureCode, Receiver, Response, TaskError,
+};
+use tokio::time::{self, Instant};
+
+/// How many countdowns each test runs.
+const RUNS: u64 = 300;
+/// How many actions the consumer takes before it drains the countdown to the end.
+const MAX_ACTIONS: usize = 200;
+/// The seed of the first run, unless `TOMATILLO_CHAOS_SEED` is set.
+const DEFAULT_SEED: u64 = 0x746f_6d61_7469_6c6c;
+/// The periods the countdowns tick at, either side of the threshold at which receivers start acknowledging values.
+const PERIODS: [u64; 5] = [10, 20, 50, 100, 200];
+/// How long a countdown task can take before it counts as stuck: far beyond any countdown a run starts.
+const TASK_DEADLINE: Duration = Duration::from_secs(600);
+/// How long a receiver waits for a value by default, and so the shortest time a sender waits for an acknowledgement.
+const MIN_CHANNEL_TIMEOUT: Duration = Duration::from_secs(1);
+
+/// A SplitMix64 generator: enough randomness to shuffle a consumer's actions, and reproducible from its seed.
+struct Rng(u64);
+
+impl Rng {
+    fn next(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// Returns a number in `0..bound`.
+    fn below(&mut self, bound: u64) -> u64 {
+        self.next() % bound
+    }
+
+    /// Returns true `percent` times out of a hundred.
+    fn chance(&mut self, percent: u64) -> bool {
+        self.below(100) < percent
+    }
+}
+
+/// What the consumer saw of a countdown, and what it did to it.
+#[derive(Debug, Default)]
+struct Observed {
+    values: Vec<Duration>,
+    closed: Option<CloseReason>,
+    cancelled: bool,
+    added: Duration,
+}
+
+struct Run {
+    seed: u64,
+    rng: Rng,
+    period: u64,
+    duration: u64,
+}
+
+impl Run {
+    fn new(seed: u64) -> Self {
+        let mut rng = Rng(seed);
+        let period = PERIODS[usize::try_from(rng.below(PERIODS.len() as u64)).expect("index should fit")];
+        // Most durations are not a whole number of periods.
+        let duration = period * (1 + rng.below(8)) + rng.below(period);
+
+        Self { seed, rng, period, duration }
+    }
+
+    fn period(&self) -> Duration {
+        Duration::from_millis(self.period)
+    }
+
+    fn duration(&self) -> Duration {
+        Duration::from_millis(self.duration)
+    }
+
+    fn timeout(&self) -> Duration {
+        (self.period() * 3).max(MIN_CHANNEL_TIMEOUT)
+    }
+
+    /// Sleeps for up to `periods` periods.
+    async fn dawdle(&mut self, periods: u64) {
+        time::sleep(Duration::from_millis(self.rng.below(self.period * periods + 1))).await;
+    }
+
+    async fn run(mut self) {
+        let seed = self.seed;
+        let timer = AsyncCountdown::try_new(self.period()).expect("should have created the timer");
+        let started_at = Instant::now();
+        let (rx, handle) = timer.start(self.duration()).await.unwrap_or_else(|err| panic!("seed {seed}: failed to start: {err}"));
+
+        let finishing = async {
+            let finished = time::timeout(TASK_DEADLINE, handle.wait()).await;
+            (finished, started_at.elapsed())
+        };
+        let (observed, (finished, elapsed)) = tokio::join!(self.consume(rx), finishing);
+
+        let Ok(finished) = finished else {
+            panic!("seed {seed}: the countdown task did not end within {TASK_DEADLINE:?}: {observed:?}");
+        };
+        match finished {
+            Ok(()) => {},
+            Err(TaskError::Aborted) if observed.cancelled => {},
+            Err(TaskError::Failed(_)) if observed.closed == Some(CloseReason::Failed(FailureCode::Unacknowledged)) => {},
+            Err(err) => panic!("seed {seed}: the countdown task failed: {err}, having observed {observed:?}"),
+        }
+
+        // The countdown runs its course, then waits at most one timeout for the last value to be acknowledged.
+        let longest = self.duration() + observed.added + self.timeout() + self.period();
+        assert!(elapsed <= longest, "seed {seed}: the task took {elapsed:?}, over {longest:?}, having observed {observed:?}");
+
+        self.check(&observed);
+    }
+
+    async fn consume(&mut self, mut rx: ChannelReceiver<Duration>) -> Observed {
+        let seed = self.seed;
+        let mut observed = Observed::default();
+        let mut last_tick = None;
+
+        for _ in 0..MAX_ACTIONS {
+            if observed.closed.is_some() {
+                break;
+            }
+
+            match self.rng.below(100) {
+                0..30 => {
+                    let response = rx.recv().await.unwrap_or_else(|err| panic!("seed {seed}: failed to receive: {err}"));
+                    Self::record(&mut observed, response);
+                },
+                30..45 => {
+                    let progress = rx.recv_progress().await.unwrap_or_else(|err| panic!("seed {seed}: failed to receive progress: {err}"));
+                    let response = match progress {
+                        Response::Value(progress) => {
+                            assert!(
+                                last_tick.is_none_or(|last| progress.tick_index > last),
+                                "seed {seed}: tick {} after tick {last_tick:?}",
+                                progress.tick_index,
+                            );
+                            last_tick = Some(progress.tick_index);
+                            Response::Value(progress.remaining)
+                        },
+                        Response::CycleCompleted(cycles) => Response::CycleCompleted(cycles),
+                        Response::Closed(reason) => Response::Closed(reason),
+                    };
+                    Self::record(&mut observed, response);
+                },
+                45..60 => {
+                    // Gives up on the receive part way through, dropping it wherever it was.
+                    let patience = Duration::from_millis(self.rng.below(self.period * 2 + 1));
+                    if let Ok(response) = time::timeout(patience, rx.recv()).await {
+                        let response = response.unwrap_or_else(|err| panic!("seed {seed}: failed to receive: {err}"));
+                        Self::record(&mut observed, response);
+                    }
+                },
+                60..75 => self.dawdle(3).await,
+                75..83 => {
+                    let again = rx.clone();
+                    drop(rx);
+                    rx = again;
+                },
+                83..90 => {
+                    let _ = (rx.skipped_total(), rx.take_lag_warning(), rx.timeout_remaining(), rx.time_since_last_value());
+                },
+                90..94 => {
+                    let added = self.period() * (1 + u32::try_from(self.rng.below(3)).expect("should fit"));
+                    if rx.add(added).await.is_ok() {
+                        observed.added += added;
+                    }
+                },
+                94..97 => {
+                    // Stops reading for longer than the sender waits for an acknowledgement.
+                    time::sleep(self.timeout() * 2).await;
+                },
+                97..99 if self.rng.chance(50) => {
+                    rx.cancel();
+                    observed.cancelled = true;
+                },
+                _ => self.dawdle(1).await,
+            }
+        }
+
+        while observed.closed.is_none() {
+            let response = rx.recv_forever().await.unwrap_or_else(|err| panic!("seed {seed}: failed to drain: {err}"));
+            Self::record(&mut observed, response);
+        }
+
+        observed
+    }
+
+    fn record(observed: &mut Observed, response: Response<Duration>) {
+        match response {
+            Response::Value(left) => observed.values.push(left),
+            Response::CycleCompleted(cycles) => panic!("completed cycle {cycles} of a countdown that runs once: {observed:?}"),
+            Response::Closed(reason) => observed.closed = Some(reason),
+        }
+    }
+
+    fn check(&self, observed: &Observed) {
+        let seed = self.seed;
+        let Some(reason) = observed.closed else {
+            panic!("seed {seed}: never observed the countdown close: {observed:?}");
+        };
+
+        let longest = self.duration() + observed.added;
+        assert!(
+            observed.values.iter().all(|&left| left <= longest),
+            "seed {seed}: received more than the {longest:?} the countdown could have left: {observed:?}",
+        );
+        if observed.added.is_zero() {
+            assert!(
+                observed.values.windows(2).all(|pair| pair[1] < pair[0]),
+                "seed {seed}: received a value that was not smaller than the one before it: {observed:?}",
+            );
+        }
+
+        match reason {
+            CloseReason::Cancelled => assert!(observed.cancelled, "seed {seed}: cancelled without cancelling: {observed:?}"),
+            CloseReason::Skipped => panic!("seed {seed}: skipped without skipping: {observed:?}"),
+            // The consumer was too slow to acknowledge the last value, but still received it.
+            CloseReason::Completed | CloseReason::Failed(FailureCode::Unacknowledged) => assert_eq!(
+                observed.values.last(),
+                Some(&Duration::ZERO),
+                "seed {seed}: closed as {reason} before the last value: {observed:?}",
+            ),
+            CloseReason::Failed(code) => panic!("seed {seed}: failed: {code}, having observed {observed:?}"),
+        }
+    }
+}
+
+fn first_seed() -> u64 {
+    env::var("TOMATILLO_CHAOS_SEED").map_or(DEFAULT_SEED, |seed| seed.parse().expect("TOMATILLO_CHAOS_SEED should be a number"))
+}
+
+#[tokio::test(start_paused = true)]
+async fn should_keep_to_the_protocol_whatever_the_consumer_does() {
+    let first = first_seed();
+
+    for seed in first..first + RUNS {
+        Run::new(seed).run().await;
+    }
+}
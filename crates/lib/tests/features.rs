@@ -0,0 +1,87 @@
+//! Exercises the library through each important combination of features, so that a broken feature gate fails here
+//! rather than downstream:
+//!
+//! * core only: `cargo test -p libtomatillo --no-default-features`
+//! * core and serde: `cargo test -p libtomatillo --no-default-features --features serde`
+//! * core and runtime: `cargo test -p libtomatillo --no-default-features --features runtime`
+//! * core and blocking: `cargo test -p libtomatillo --no-default-features --features blocking`
+//! * core, runtime and demo controls: `cargo test -p libtomatillo --no-default-features --features demo_mode`
+//! * core, runtime and view: `cargo test -p libtomatillo`
+
+use libtomatillo::countdown::{CloseReason, CountdownError, InvalidCountdown, TimerError};
+
+#[test]
+fn should_describe_invalid_countdowns_with_core_features_only() {
+    let error = CountdownError::from(TimerError::from(InvalidCountdown::ZeroInterval));
+
+    assert_eq!(error.to_string(), "Interval cannot be zero");
+    assert_eq!(CloseReason::Completed.to_string(), "completed");
+}
+
+#[cfg(feature = "runtime")]
+#[tokio::test(start_paused = true)]
+async fn should_run_a_countdown_with_the_runtime() {
+    use std::time::Duration;
+
+    use libtomatillo::{countdown::AsyncCountdown, run_with_output, CountdownOutcome};
+
+    let timer = AsyncCountdown::try_new(Duration::from_millis(100)).expect("should have created the timer");
+    let guard = run_with_output(timer, Duration::from_millis(300), std::io::sink()).await.expect("should have started the countdown");
+
+    let outcome = guard.wait().await.expect("should have run the countdown");
+    assert_eq!(outcome, CountdownOutcome { reason: CloseReason::Completed, remaining: Duration::ZERO, lag_warning: None, output_stalled: false });
+}
+
+#[cfg(all(feature = "serde", feature = "runtime"))]
+#[test]
+fn should_serialize_how_a_countdown_ended() {
+    use std::time::Duration;
+
+    use libtomatillo::{countdown::FailureCode, CountdownOutcome};
+
+    fn serializable(_: &impl serde::Serialize) {}
+
+    serializable(&CountdownOutcome { reason: CloseReason::Failed(FailureCode::Unacknowledged), remaining: Duration::ZERO, lag_warning: None, output_stalled: false });
+}
+
+#[cfg(feature = "demo_mode")]
+#[tokio::test(start_paused = true)]
+async fn should_jump_a_countdown_with_the_demo_controls() {
+    use std::time::Duration;
+
+    use libtomatillo::countdown::{AsyncCountdown, Countdown, Receiver, Response};
+
+    let timer = AsyncCountdown::try_new(Duration::from_millis(100)).expect("should have created the timer");
+    let (rx, handle) = timer.start(Duration::from_millis(1000)).await.expect("should have started the countdown");
+    handle.set_speed(2.0).expect("should have sped the countdown up");
+    handle.set_remaining(Duration::from_millis(100)).expect("should have jumped the countdown");
+
+    while let Ok(Response::Value(_)) = rx.recv().await {}
+    assert_eq!(rx.recv().await, Ok(Response::Closed(CloseReason::Completed)));
+}
+
+#[cfg(feature = "view")]
+#[test]
+fn should_draw_the_countdown_with_a_font() {
+    use libtomatillo::view::{font::{self, Character, Font}, View};
+
+    let _view = View::new(&font::TEMPLAR);
+    let mut line = String::new();
+    font::TEMPLAR.get('0').expect("the font should support digits").draw_line(&mut line, 0);
+
+    assert_eq!(line, "┏┓\n");
+}
+
+#[cfg(feature = "blocking")]
+#[test]
+fn should_run_a_countdown_without_the_runtime() {
+    use std::time::Duration;
+
+    use libtomatillo::countdown::{blocking::BlockingCountdown, Response};
+
+    let timer = BlockingCountdown::try_new(Duration::from_millis(10)).expect("should have created the timer");
+    let rx = timer.start(Duration::from_millis(20)).expect("should have started the countdown");
+
+    while let Response::Value(_) = rx.recv() {}
+    assert_eq!(rx.recv(), Response::Closed(CloseReason::Completed));
+}
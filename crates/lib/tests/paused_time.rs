@@ -0,0 +1,45 @@
+//! Runs countdowns the way embedders test their own apps: inside `#[tokio::test(start_paused = true)]`, relying on
+//! tokio auto-advancing the paused clock rather than calling `advance` by hand.
+
+#![cfg(feature = "runtime")]
+
+use std::time::Duration;
+
+use libtomatillo::{
+    countdown::{AsyncCountdown, CloseReason, Countdown, Receiver, Response},
+    run_with_output,
+    CountdownOutcome,
+};
+use tokio::time::Instant;
+
+#[tokio::test(start_paused = true)]
+async fn should_count_down_on_auto_advanced_time() {
+    let started_at = Instant::now();
+    let timer = AsyncCountdown::try_new(Duration::from_millis(100)).expect("should have created the timer");
+    let (rx, _) = timer.start(Duration::from_millis(1000)).await.expect("should have started the countdown");
+
+    let mut values = Vec::new();
+    let reason = loop {
+        match rx.recv().await.expect("auto-advancing time should not time out a receive") {
+            Response::Value(left) => values.push(left),
+            Response::CycleCompleted(cycles) => panic!("completed cycle {cycles} of a countdown that runs once"),
+            Response::Closed(reason) => break reason,
+        }
+    };
+
+    assert_eq!(values, (0..=10).rev().map(|tick| Duration::from_millis(tick * 100)).collect::<Vec<_>>());
+    assert_eq!(reason, CloseReason::Completed);
+    assert_eq!(started_at.elapsed(), Duration::from_millis(1000));
+}
+
+#[tokio::test(start_paused = true)]
+async fn should_run_to_completion_on_auto_advanced_time() {
+    let started_at = Instant::now();
+    let timer = AsyncCountdown::try_new(Duration::from_millis(100)).expect("should have created the timer");
+
+    let guard = run_with_output(timer, Duration::from_millis(1000), std::io::sink()).await.expect("should have started the countdown");
+    let outcome = guard.wait().await.expect("should have run the countdown");
+
+    assert_eq!(outcome, CountdownOutcome { reason: CloseReason::Completed, remaining: Duration::ZERO, lag_warning: None, output_stalled: false });
+    assert_eq!(started_at.elapsed(), Duration::from_millis(1000));
+}
@@ -0,0 +1,175 @@
+//! Generates the Rust source of a font module from its [`Template`].
+
+use std::fmt::Write as _;
+
+use crate::template::Template;
+
+/// The characters the generated tests try in turn for one the font does not draw.
+const UNDRAWN_CANDIDATES: &str = "abcdefghijklmnopqrstuvwxyz?";
+
+/// Returns the source of the font module generated from `template`, read from `template_path` relative to the module.
+///
+/// The module defines a `CompositeChar` constant for each glyph, the struct named by the template implementing `Font`
+/// over them, and the tests every font has.
+#[must_use]
+pub fn generate(template: &Template, template_path: &str) -> String {
+    let mut out = String::new();
+    let font = &template.font;
+    let height = template.glyphs.first().map_or(0, |glyph| glyph.lines.len());
+
+    module_header(&mut out, template, template_path);
+    out.push_str("use std::ops::Range;\n\nuse super::{CompositeChar, Font};\n\n");
+    let _ = writeln!(out, "const HEIGHT: usize = {height};");
+    for glyph in &template.glyphs {
+        let _ = writeln!(out, "\nconst {}: CompositeChar<HEIGHT> = CompositeChar({:?}, [", const_name(glyph.character), glyph.character);
+        for line in &glyph.lines {
+            let _ = writeln!(out, "    \"{}\",", escaped(line));
+        }
+        out.push_str("]);\n");
+    }
+
+    let _ = write!(out, "\n/// The type of the [`{0}`](super::{0}) font.\n", screaming_snake(font));
+    let _ = writeln!(out, "#[derive(Default)]\npub struct {font};\n");
+    let _ = writeln!(out, "impl Font for {font} {{");
+    out.push_str("    type CHAR = CompositeChar<'static, HEIGHT>;\n\n");
+    out.push_str("    fn height_range(&self) -> Range<usize> {\n        0..HEIGHT\n    }\n\n");
+    out.push_str("    fn get(&self, index: char) -> Option<Self::CHAR> {\n        match index {\n");
+    for glyph in &template.glyphs {
+        let _ = writeln!(out, "            {:?} => Some({}),", glyph.character, const_name(glyph.character));
+    }
+    out.push_str("            _ => None,\n        }\n    }\n");
+    if !template.safe_glyphs.is_empty() {
+        let pairs = template.safe_glyphs.iter().map(|(glyph, safe)| format!("({glyph:?}, {safe:?})")).collect::<Vec<_>>();
+        let _ = write!(out, "\n    fn safe_glyphs(&self) -> &'static [(char, char)] {{\n        &[{}]\n    }}\n", pairs.join(", "));
+    }
+    out.push_str("}\n");
+
+    if let Some(test) = template.test {
+        let name = const_name(test);
+        let _ = write!(out, "\n#[cfg(test)]\npub const TEST_{name}: CompositeChar<'_, HEIGHT> = {name};\n");
+    }
+    tests(&mut out, template);
+
+    out
+}
+
+fn module_header(out: &mut String, template: &Template, template_path: &str) {
+    let _ = writeln!(out, "//! The {} font, generated from `{template_path}` by `cargo xtask fonts`.", template.title);
+    out.push_str(concat!(
+        "//!\n",
+        "//! Edit the template rather than this module, then run `cargo xtask fonts` to regenerate every font, or\n",
+        "//! `cargo xtask fonts --check` to check that none is out of date. A template starts with a header of `key: value`\n",
+        "//! lines, where `font` names the struct, `title` the font, `safe` lists `glyph=replacement` pairs for\n",
+        "//! `Font::safe_glyphs`, `monospace` the characters that must be as wide as each other, and `test` the glyph the\n",
+        "//! tests in `font/mod.rs` draw. Each glyph follows on the lines under a `== <character>` marker, drawn exactly as\n",
+        "//! written, trailing spaces included. To add a font, add its template next to the others, regenerate, then give\n",
+        "//! it a `mod`, a `pub use` and a constant in `font/mod.rs`.\n",
+        "\n",
+    ));
+}
+
+fn tests(out: &mut String, template: &Template) {
+    let font = &template.font;
+    let undrawn = UNDRAWN_CANDIDATES.chars()
+        .find(|candidate| template.glyphs.iter().all(|glyph| glyph.character != *candidate))
+        .unwrap_or(char::REPLACEMENT_CHARACTER);
+
+    out.push_str("\n#[cfg(test)]\nmod tests {\n    use rstest::rstest;\n\n    use super::*;\n\n    #[rstest]\n");
+    for glyph in &template.glyphs {
+        let name = const_name(glyph.character);
+        let _ = writeln!(out, "    #[case::{}({:?}, {name})]", name.to_lowercase(), glyph.character);
+    }
+    out.push_str("    fn test_should_return_correct_character(#[case] key: char, #[case] expected: CompositeChar<'_, HEIGHT>) {\n");
+    let _ = writeln!(out, "        let font = {font};\n");
+    out.push_str(concat!(
+        "        let actual = font.get(key).unwrap_or_else(|| panic!(\"value not found for {key}\"));\n",
+        "\n",
+        "        assert_eq!(actual, expected, \"expected {key} to map to {expected:?}, but got {actual:?}\");\n",
+        "    }\n",
+        "\n",
+        "    #[test]\n",
+        "    fn test_should_return_height_range() {\n",
+    ));
+    let _ = writeln!(out, "        let font = {font};\n");
+    out.push_str(concat!(
+        "        assert_eq!(font.height_range(), 0..HEIGHT);\n",
+        "    }\n",
+        "\n",
+        "    #[test]\n",
+        "    fn test_should_return_none_given_key_that_does_not_exist() {\n",
+    ));
+    let _ = writeln!(out, "        let font = {font};\n");
+    let _ = writeln!(out, "        assert_eq!(font.get({undrawn:?}), None);\n    }}\n}}");
+}
+
+/// Returns the name of the constant holding the glyph of `character`.
+fn const_name(character: char) -> String {
+    const DIGITS: [&str; 10] = ["ZERO", "ONE", "TWO", "THREE", "FOUR", "FIVE", "SIX", "SEVEN", "EIGHT", "NINE"];
+
+    match character {
+        '0'..='9' => DIGITS[usize::from(character as u8 - b'0')].to_owned(),
+        ':' => "COLON".to_owned(),
+        '.' => "PERIOD".to_owned(),
+        '-' => "HYPHEN".to_owned(),
+        ' ' => "SPACE".to_owned(),
+        'A'..='Z' => format!("CAPITAL_{character}"),
+        'a'..='z' => format!("SMALL_{}", character.to_ascii_uppercase()),
+        _ => format!("U{:04X}", u32::from(character)),
+    }
+}
+
+/// Returns `name`, written in `CamelCase`, in `SCREAMING_SNAKE_CASE`.
+fn screaming_snake(name: &str) -> String {
+    let mut snake = String::new();
+    for (index, character) in name.chars().enumerate() {
+        if index > 0 && character.is_ascii_uppercase() {
+            snake.push('_');
+        }
+        snake.push(character.to_ascii_uppercase());
+    }
+    snake
+}
+
+/// Returns `line` escaped to go between the quotes of a string literal, leaving every other character as it is.
+fn escaped(line: &str) -> String {
+    line.chars().fold(String::new(), |mut escaped, character| {
+        match character {
+            '"' | '\\' => {
+                escaped.push('\\');
+                escaped.push(character);
+            },
+            '\t' => escaped.push_str("\\t"),
+            _ => escaped.push(character),
+        }
+        escaped
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case::digit('7', "SEVEN")]
+    #[case::colon(':', "COLON")]
+    #[case::capital('A', "CAPITAL_A")]
+    #[case::small('a', "SMALL_A")]
+    #[case::anything_else('•', "U2022")]
+    fn should_name_the_constant_of_each_glyph(#[case] character: char, #[case] expected: &str) {
+        assert_eq!(const_name(character), expected);
+    }
+
+    #[rstest]
+    #[case::one_word("Templar", "TEMPLAR")]
+    #[case::several_words("AnsiShadow", "ANSI_SHADOW")]
+    fn should_name_the_font_constant_after_its_struct(#[case] name: &str, #[case] expected: &str) {
+        assert_eq!(screaming_snake(name), expected);
+    }
+
+    #[test]
+    fn should_escape_quotes_and_backslashes_only() {
+        assert_eq!(escaped("\"█\\\t"), "\\\"█\\\\\\t");
+    }
+}
@@ -0,0 +1,72 @@
+//! Development tasks for tomatillo, run with `cargo xtask <task>`.
+
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use thiserror::Error;
+
+pub mod generate;
+pub mod template;
+
+use template::TemplateError;
+
+/// A font module generated from its template.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Generated {
+    /// The path of the module the font goes in.
+    pub module: PathBuf,
+    /// The source of the module.
+    pub source: String,
+}
+
+/// An error raised while generating fonts.
+#[derive(Debug, Error)]
+pub enum FontsError {
+    #[error("{}: {source}", path.display())]
+    Io { path: PathBuf, #[source] source: io::Error },
+    #[error("{}: {source}", path.display())]
+    Template { path: PathBuf, #[source] source: TemplateError },
+}
+
+/// Returns the directory the library's fonts live in, with their templates in its `templates` directory.
+#[must_use]
+pub fn font_dir() -> PathBuf {
+    let crates = Path::new(env!("CARGO_MANIFEST_DIR")).parent().expect("the xtask crate should be in the crates directory");
+
+    crates.join("lib/src/view/font")
+}
+
+/// Generates a module in `font_dir` from each `.txt` template in its `templates` directory, named after the template,
+/// in the order of their file names.
+///
+/// # Errors
+///
+/// Returns the first template that could not be read or is invalid.
+pub fn generate_fonts(font_dir: &Path) -> Result<Vec<Generated>, FontsError> {
+    let templates = font_dir.join("templates");
+    let io_error = |path: &Path| {
+        let path = path.to_owned();
+        move |source| FontsError::Io { path, source }
+    };
+
+    let mut paths = fs::read_dir(&templates).map_err(io_error(&templates))?
+        .map(|entry| entry.map(|entry| entry.path()))
+        .collect::<io::Result<Vec<_>>>()
+        .map_err(io_error(&templates))?;
+    paths.retain(|path| path.extension().is_some_and(|extension| extension == "txt"));
+    paths.sort();
+
+    paths.into_iter().map(|path| {
+        let source = fs::read_to_string(&path).map_err(io_error(&path))?;
+        let template = template::parse(&source).map_err(|source| FontsError::Template { path: path.clone(), source })?;
+        let file_name = path.file_name().map(|name| name.to_string_lossy().into_owned()).unwrap_or_default();
+        let stem = path.file_stem().map(|stem| stem.to_string_lossy().into_owned()).unwrap_or_default();
+
+        Ok(Generated {
+            module: font_dir.join(format!("{stem}.rs")),
+            source: generate::generate(&template, &format!("templates/{file_name}")),
+        })
+    }).collect()
+}
@@ -0,0 +1,50 @@
+use std::{env, fs, process::ExitCode};
+
+use xtask::{font_dir, generate_fonts, Generated};
+
+const USAGE: &str = "usage: cargo xtask fonts [--check]
+
+    fonts          regenerate every font from its template
+    fonts --check  fail if any font is out of date with its template";
+
+fn main() -> ExitCode {
+    let args = env::args().skip(1).collect::<Vec<_>>();
+
+    match args.iter().map(String::as_str).collect::<Vec<_>>().as_slice() {
+        ["fonts"] => fonts(false),
+        ["fonts", "--check"] => fonts(true),
+        _ => {
+            eprintln!("{USAGE}");
+            ExitCode::from(2)
+        },
+    }
+}
+
+fn fonts(check: bool) -> ExitCode {
+    let generated = match generate_fonts(&font_dir()) {
+        Ok(generated) => generated,
+        Err(err) => {
+            eprintln!("error: {err}");
+            return ExitCode::FAILURE;
+        },
+    };
+
+    let mut outdated = 0;
+    for Generated { module, source } in generated {
+        if fs::read_to_string(&module).is_ok_and(|current| current == source) {
+            continue;
+        }
+
+        if check {
+            eprintln!("{} is out of date with its template, run `cargo xtask fonts`", module.display());
+            outdated += 1;
+        } else if let Err(err) = fs::write(&module, source) {
+            eprintln!("error: {}: {err}", module.display());
+            return ExitCode::FAILURE;
+        } else {
+            println!("generated {}", module.display());
+        }
+    }
+
+    if outdated > 0 { ExitCode::FAILURE } else { ExitCode::SUCCESS }
+}
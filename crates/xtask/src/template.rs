@@ -0,0 +1,360 @@
+//! Parses and validates font templates: plain text files that draw each glyph of a font literally.
+//!
+//! A template starts with a header of `key: value` lines, where blank lines and lines starting with `#` are ignored:
+//!
+//! * `font` - The name of the struct implementing `Font`. Required.
+//! * `title` - The name of the font, as its documentation gives it. Required.
+//! * `safe` - Space separated `glyph=replacement` pairs, returned by `Font::safe_glyphs`.
+//! * `monospace` - The characters that must all be drawn as wide as each other, such as the digits.
+//! * `test` - The character the font tests in `font/mod.rs` draw, exported to them as a `TEST_` constant.
+//!
+//! Each glyph then follows a [`MARKER`] line naming the character it draws, on as many lines as the font is high. Every
+//! line is drawn exactly as written, trailing spaces included, up to the next marker.
+
+use thiserror::Error;
+
+/// What the line starting each glyph starts with, followed by the character the glyph draws.
+pub const MARKER: &str = "== ";
+
+/// A font template, parsed and validated.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Template {
+    /// The name of the struct implementing `Font`.
+    pub font: String,
+    /// The name of the font.
+    pub title: String,
+    /// The glyphs that common terminal fonts may lack, each paired with the glyph to draw instead.
+    pub safe_glyphs: Vec<(char, char)>,
+    /// The character the font tests draw, if any.
+    pub test: Option<char>,
+    /// Every glyph of the font, in the order they were drawn.
+    pub glyphs: Vec<Glyph>,
+}
+
+/// A single glyph of a template.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Glyph {
+    /// The character the glyph draws.
+    pub character: char,
+    /// Each line the glyph is drawn over, from the top.
+    pub lines: Vec<String>,
+}
+
+/// The reason a template was rejected, and where in it.
+#[derive(Debug, Error, Clone, PartialEq, Eq)]
+pub enum TemplateError {
+    #[error("line {line}: expected a `key: value` header, got {text:?}")]
+    MalformedHeader { line: usize, text: String },
+    #[error("line {line}: unknown header {key:?}")]
+    UnknownHeader { line: usize, key: String },
+    #[error("line {line}: the {key:?} header is given twice")]
+    DuplicateHeader { line: usize, key: String },
+    #[error("the template has no {0:?} header")]
+    MissingHeader(&'static str),
+    #[error("line {line}: {name:?} is not a Rust type name")]
+    InvalidFontName { line: usize, name: String },
+    #[error("line {line}: expected a `glyph=replacement` pair, got {text:?}")]
+    MalformedSafeGlyph { line: usize, text: String },
+    #[error("line {line}: expected a single character to test, got {text:?}")]
+    MalformedTest { line: usize, text: String },
+    #[error("line {line}: a glyph marker must name a single character, got {text:?}")]
+    MalformedMarker { line: usize, text: String },
+    #[error("line {line}: {character:?} is drawn twice")]
+    DuplicateGlyph { line: usize, character: char },
+    #[error("the template draws no glyphs")]
+    NoGlyphs,
+    #[error("line {line}: {character:?} is not drawn on any lines")]
+    EmptyGlyph { line: usize, character: char },
+    #[error("line {line}: {character:?} is {height} lines high, but {first:?} is {expected}")]
+    UnevenHeight { line: usize, character: char, height: usize, first: char, expected: usize },
+    #[error("line {line}: this line of {character:?} is {width} columns wide, but its first line is {expected}")]
+    RaggedGlyph { line: usize, character: char, width: usize, expected: usize },
+    #[error("line {line}: {character:?} is {width} columns wide, but {first:?} is {expected}, and they must be as wide")]
+    UnevenMonospace { line: usize, character: char, width: usize, first: char, expected: usize },
+    #[error("the {header:?} header names {character:?}, which the template does not draw")]
+    UndrawnCharacter { header: &'static str, character: char },
+}
+
+/// The headers of a template, before its glyphs are known.
+#[derive(Debug, Default)]
+struct Header {
+    font: Option<String>,
+    title: Option<String>,
+    safe_glyphs: Vec<(char, char)>,
+    monospace: Vec<char>,
+    test: Option<char>,
+    seen: Vec<String>,
+}
+
+/// A glyph, with the line of the template its marker is on.
+#[derive(Debug)]
+struct Drawn {
+    marker: usize,
+    glyph: Glyph,
+}
+
+impl Glyph {
+    /// Returns how many columns the glyph takes up, that of its first line.
+    #[must_use]
+    pub fn width(&self) -> usize {
+        self.lines.first().map_or(0, |line| line.chars().count())
+    }
+}
+
+/// Parses `source` as a template, checking that every glyph is as high as the others and each of its lines as wide as
+/// the others.
+///
+/// # Errors
+///
+/// Returns the first [`TemplateError`] found, reading from the top.
+pub fn parse(source: &str) -> Result<Template, TemplateError> {
+    let mut lines = source.lines().enumerate().map(|(index, text)| (index + 1, text)).peekable();
+
+    let mut header = Header::default();
+    while let Some((line, text)) = lines.next_if(|(_, text)| !is_marker(text)) {
+        header.read(line, text)?;
+    }
+
+    let mut drawn: Vec<Drawn> = Vec::new();
+    while let Some((marker, text)) = lines.next() {
+        let mut characters = text[MARKER.len()..].chars();
+        let (Some(character), None) = (characters.next(), characters.next()) else {
+            return Err(TemplateError::MalformedMarker { line: marker, text: text.to_owned() });
+        };
+        if drawn.iter().any(|drawn| drawn.glyph.character == character) {
+            return Err(TemplateError::DuplicateGlyph { line: marker, character });
+        }
+
+        let mut glyph = Glyph { character, lines: Vec::new() };
+        while let Some((_, text)) = lines.next_if(|(_, text)| !is_marker(text)) {
+            glyph.lines.push(text.to_owned());
+        }
+        drawn.push(Drawn { marker, glyph });
+    }
+
+    validate(&header, &drawn)?;
+    Ok(Template {
+        font: header.font.ok_or(TemplateError::MissingHeader("font"))?,
+        title: header.title.ok_or(TemplateError::MissingHeader("title"))?,
+        safe_glyphs: header.safe_glyphs,
+        test: header.test,
+        glyphs: drawn.into_iter().map(|drawn| drawn.glyph).collect(),
+    })
+}
+
+impl Header {
+    fn read(&mut self, line: usize, text: &str) -> Result<(), TemplateError> {
+        if text.trim().is_empty() || text.starts_with('#') {
+            return Ok(());
+        }
+
+        let Some((key, value)) = text.split_once(':') else {
+            return Err(TemplateError::MalformedHeader { line, text: text.to_owned() });
+        };
+        let (key, value) = (key.trim(), value.trim());
+        if self.seen.iter().any(|seen| seen == key) {
+            return Err(TemplateError::DuplicateHeader { line, key: key.to_owned() });
+        }
+
+        match key {
+            "font" => {
+                if !is_type_name(value) {
+                    return Err(TemplateError::InvalidFontName { line, name: value.to_owned() });
+                }
+                self.font = Some(value.to_owned());
+            },
+            "title" => self.title = Some(value.to_owned()),
+            "safe" => {
+                for pair in value.split_whitespace() {
+                    let mut characters = pair.chars();
+                    let (Some(glyph), Some('='), Some(replacement), None) =
+                        (characters.next(), characters.next(), characters.next(), characters.next())
+                    else {
+                        return Err(TemplateError::MalformedSafeGlyph { line, text: pair.to_owned() });
+                    };
+                    self.safe_glyphs.push((glyph, replacement));
+                }
+            },
+            "monospace" => self.monospace = value.chars().filter(|character| !character.is_whitespace()).collect(),
+            "test" => {
+                let mut characters = value.chars();
+                let (Some(character), None) = (characters.next(), characters.next()) else {
+                    return Err(TemplateError::MalformedTest { line, text: value.to_owned() });
+                };
+                self.test = Some(character);
+            },
+            _ => return Err(TemplateError::UnknownHeader { line, key: key.to_owned() }),
+        }
+
+        self.seen.push(key.to_owned());
+        Ok(())
+    }
+}
+
+fn validate(header: &Header, drawn: &[Drawn]) -> Result<(), TemplateError> {
+    let Some(first) = drawn.first() else {
+        return Err(TemplateError::NoGlyphs);
+    };
+
+    for Drawn { marker, glyph } in drawn {
+        let (line, character) = (*marker, glyph.character);
+        if glyph.lines.is_empty() {
+            return Err(TemplateError::EmptyGlyph { line, character });
+        }
+        if glyph.lines.len() != first.glyph.lines.len() {
+            return Err(TemplateError::UnevenHeight {
+                line,
+                character,
+                height: glyph.lines.len(),
+                first: first.glyph.character,
+                expected: first.glyph.lines.len(),
+            });
+        }
+
+        let expected = glyph.width();
+        if let Some((offset, width)) = glyph.lines.iter()
+            .map(|line| line.chars().count())
+            .enumerate()
+            .find(|(_, width)| *width != expected)
+        {
+            return Err(TemplateError::RaggedGlyph { line: line + 1 + offset, character, width, expected });
+        }
+    }
+
+    let glyph = |header: &'static str, character: char| {
+        drawn.iter()
+            .find(|drawn| drawn.glyph.character == character)
+            .ok_or(TemplateError::UndrawnCharacter { header, character })
+    };
+    if let Some(&first) = header.monospace.first() {
+        let first = glyph("monospace", first)?;
+        for &character in &header.monospace {
+            let Drawn { marker, glyph } = glyph("monospace", character)?;
+            if glyph.width() != first.glyph.width() {
+                return Err(TemplateError::UnevenMonospace {
+                    line: *marker,
+                    character,
+                    width: glyph.width(),
+                    first: first.glyph.character,
+                    expected: first.glyph.width(),
+                });
+            }
+        }
+    }
+    if let Some(test) = header.test {
+        glyph("test", test)?;
+    }
+
+    Ok(())
+}
+
+fn is_marker(text: &str) -> bool {
+    text.starts_with(MARKER)
+}
+
+fn is_type_name(name: &str) -> bool {
+    let mut characters = name.chars();
+    characters.next().is_some_and(|first| first.is_ascii_uppercase()) && characters.all(|c| c.is_ascii_alphanumeric())
+}
+
+#[cfg(test)]
+mod tests {
+    use indoc::indoc;
+    use rstest::rstest;
+
+    use super::*;
+
+    const HEADER: &str = "font: Sample\ntitle: Sample\n";
+
+    fn glyph(character: char, lines: &[&str]) -> Glyph {
+        Glyph { character, lines: lines.iter().map(|&line| line.to_owned()).collect() }
+    }
+
+    #[test]
+    fn should_parse_every_header_and_glyph() {
+        let source = indoc! {"
+            # A font for the tests.
+            font: Sample
+            title: Sample Font
+
+            safe: ╋=+ ╹=|
+            monospace: 01
+            test: 1
+            == 0
+            ┏┓
+            ┗┛
+            == 1
+             ╹
+             ╋
+        "};
+
+        assert_eq!(parse(source), Ok(Template {
+            font: "Sample".to_owned(),
+            title: "Sample Font".to_owned(),
+            safe_glyphs: vec![('╋', '+'), ('╹', '|')],
+            test: Some('1'),
+            glyphs: vec![glyph('0', &["┏┓", "┗┛"]), glyph('1', &[" ╹", " ╋"])],
+        }));
+    }
+
+    #[test]
+    fn should_draw_every_line_of_a_glyph_as_written() {
+        let source = format!("{HEADER}== :\n  \n• \n  \n");
+
+        let template = parse(&source).expect("should have parsed the template");
+
+        assert_eq!(template.glyphs, [glyph(':', &["  ", "• ", "  "])]);
+    }
+
+    #[rstest]
+    #[case::malformed_header("font Sample\n", TemplateError::MalformedHeader { line: 1, text: "font Sample".to_owned() })]
+    #[case::unknown_header("font: Sample\nsize: 3\n", TemplateError::UnknownHeader { line: 2, key: "size".to_owned() })]
+    #[case::duplicate_header("font: Sample\nfont: Other\n", TemplateError::DuplicateHeader { line: 2, key: "font".to_owned() })]
+    #[case::invalid_font_name("font: sample font\n", TemplateError::InvalidFontName { line: 1, name: "sample font".to_owned() })]
+    #[case::malformed_safe_glyph("safe: ╋+\n", TemplateError::MalformedSafeGlyph { line: 1, text: "╋+".to_owned() })]
+    #[case::malformed_test("test: 10\n", TemplateError::MalformedTest { line: 1, text: "10".to_owned() })]
+    #[case::missing_font("title: Sample\n== 0\n0\n", TemplateError::MissingHeader("font"))]
+    #[case::missing_title("font: Sample\n== 0\n0\n", TemplateError::MissingHeader("title"))]
+    #[case::no_glyphs("font: Sample\ntitle: Sample\n", TemplateError::NoGlyphs)]
+    #[case::malformed_marker("font: Sample\ntitle: Sample\n== 10\n10\n", TemplateError::MalformedMarker { line: 3, text: "== 10".to_owned() })]
+    fn should_reject_a_malformed_template(#[case] source: &str, #[case] expected: TemplateError) {
+        assert_eq!(parse(source), Err(expected));
+    }
+
+    #[rstest]
+    #[case::duplicate_glyph("== 0\n0\n== 0\n0\n", TemplateError::DuplicateGlyph { line: 5, character: '0' })]
+    #[case::empty_glyph("== 0\n== 1\n1\n", TemplateError::EmptyGlyph { line: 3, character: '0' })]
+    #[case::uneven_height("== 0\n0\n0\n== 1\n1\n", TemplateError::UnevenHeight {
+        line: 6,
+        character: '1',
+        height: 1,
+        first: '0',
+        expected: 2,
+    })]
+    #[case::ragged_glyph("== 0\n┏┓\n┃\n┗┛\n", TemplateError::RaggedGlyph { line: 5, character: '0', width: 1, expected: 2 })]
+    #[case::ragged_trailing_space("== 0\n┏┓\n┃┃ \n", TemplateError::RaggedGlyph { line: 5, character: '0', width: 3, expected: 2 })]
+    fn should_reject_an_invalid_glyph(#[case] glyphs: &str, #[case] expected: TemplateError) {
+        assert_eq!(parse(&format!("{HEADER}{glyphs}")), Err(expected));
+    }
+
+    #[rstest]
+    #[case::uneven_monospace("monospace: 012\n== 0\n00\n== 1\n11\n== 2\n2\n", TemplateError::UnevenMonospace {
+        line: 8,
+        character: '2',
+        width: 1,
+        first: '0',
+        expected: 2,
+    })]
+    #[case::undrawn_monospace("monospace: 01\n== 0\n0\n", TemplateError::UndrawnCharacter { header: "monospace", character: '1' })]
+    #[case::undrawn_test("test: :\n== 0\n0\n", TemplateError::UndrawnCharacter { header: "test", character: ':' })]
+    fn should_reject_a_header_the_glyphs_break(#[case] source: &str, #[case] expected: TemplateError) {
+        assert_eq!(parse(&format!("{HEADER}{source}")), Err(expected));
+    }
+
+    #[test]
+    fn should_only_hold_the_characters_listed_as_monospace_to_the_same_width() {
+        let source = format!("{HEADER}monospace: 0\n== 0\n00\n== :\n:\n");
+
+        assert!(parse(&source).is_ok());
+    }
+}
@@ -0,0 +1,24 @@
+use std::fs;
+
+use xtask::{font_dir, generate::generate, generate_fonts, template};
+
+#[test]
+fn should_generate_the_same_module_as_the_golden_sample() {
+    let source = fs::read_to_string("tests/golden/sample.txt").expect("should have read the sample template");
+    let expected = fs::read_to_string("tests/golden/sample.rs").expect("should have read the sample module");
+
+    let template = template::parse(&source).expect("should have parsed the sample template");
+
+    assert_eq!(generate(&template, "templates/sample.txt"), expected);
+}
+
+#[test]
+fn should_have_generated_every_font_of_the_library_from_its_template() {
+    let generated = generate_fonts(&font_dir()).expect("should have generated the fonts");
+
+    assert!(!generated.is_empty(), "expected fonts in {}", font_dir().display());
+    for font in generated {
+        let current = fs::read_to_string(&font.module).expect("every template should have a module");
+        assert!(current == font.source, "{} is out of date with its template, run `cargo xtask fonts`", font.module.display());
+    }
+}
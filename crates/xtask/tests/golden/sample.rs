@@ -0,0 +1,91 @@
+//! The Sample Blocks font, generated from `templates/sample.txt` by `cargo xtask fonts`.
+//!
+//! Edit the template rather than this module, then run `cargo xtask fonts` to regenerate every font, or
+//! `cargo xtask fonts --check` to check that none is out of date. A template starts with a header of `key: value`
+//! lines, where `font` names the struct, `title` the font, `safe` lists `glyph=replacement` pairs for
+//! `Font::safe_glyphs`, `monospace` the characters that must be as wide as each other, and `test` the glyph the
+//! tests in `font/mod.rs` draw. Each glyph follows on the lines under a `== <character>` marker, drawn exactly as
+//! written, trailing spaces included. To add a font, add its template next to the others, regenerate, then give
+//! it a `mod`, a `pub use` and a constant in `font/mod.rs`.
+
+use std::ops::Range;
+
+use super::{CompositeChar, Font};
+
+const HEIGHT: usize = 2;
+
+const ZERO: CompositeChar<HEIGHT> = CompositeChar('0', [
+    "▛▜",
+    "▙▟",
+]);
+
+const ONE: CompositeChar<HEIGHT> = CompositeChar('1', [
+    " ▌",
+    " ▌",
+]);
+
+const SMALL_A: CompositeChar<HEIGHT> = CompositeChar('a', [
+    "\"\\",
+    "\\\"",
+]);
+
+/// The type of the [`SAMPLE_BLOCKS`](super::SAMPLE_BLOCKS) font.
+#[derive(Default)]
+pub struct SampleBlocks;
+
+impl Font for SampleBlocks {
+    type CHAR = CompositeChar<'static, HEIGHT>;
+
+    fn height_range(&self) -> Range<usize> {
+        0..HEIGHT
+    }
+
+    fn get(&self, index: char) -> Option<Self::CHAR> {
+        match index {
+            '0' => Some(ZERO),
+            '1' => Some(ONE),
+            'a' => Some(SMALL_A),
+            _ => None,
+        }
+    }
+
+    fn safe_glyphs(&self) -> &'static [(char, char)] {
+        &[('▌', '|')]
+    }
+}
+
+#[cfg(test)]
+pub const TEST_ONE: CompositeChar<'_, HEIGHT> = ONE;
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case::zero('0', ZERO)]
+    #[case::one('1', ONE)]
+    #[case::small_a('a', SMALL_A)]
+    fn test_should_return_correct_character(#[case] key: char, #[case] expected: CompositeChar<'_, HEIGHT>) {
+        let font = SampleBlocks;
+
+        let actual = font.get(key).unwrap_or_else(|| panic!("value not found for {key}"));
+
+        assert_eq!(actual, expected, "expected {key} to map to {expected:?}, but got {actual:?}");
+    }
+
+    #[test]
+    fn test_should_return_height_range() {
+        let font = SampleBlocks;
+
+        assert_eq!(font.height_range(), 0..HEIGHT);
+    }
+
+    #[test]
+    fn test_should_return_none_given_key_that_does_not_exist() {
+        let font = SampleBlocks;
+
+        assert_eq!(font.get('b'), None);
+    }
+}